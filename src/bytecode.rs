@@ -0,0 +1,913 @@
+// Copyright (c) 2025 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions.
+// For more details, see the LICENSE, LICENSE.additional, and CONTRIBUTING files.
+
+pub mod compact;
+pub mod diff;
+pub mod peephole;
+pub mod profile;
+pub mod statistics;
+
+use std::fmt::Display;
+
+use crate::{
+    endian::{read_u16, read_u32, write_u16, write_u32},
+    opcode::{InstructionFormat, Opcode},
+};
+
+// Immediate Numbers
+// -----------------
+//
+// The parameters of instructions are limited to i16 and i32, so the pseudo-instructions
+// `imm_i64` and `imm_f64` carry their immediate number as two i32 parameters:
+//
+// ```text
+//    MSB                                    LSB
+// 64 |---------------------------------------| 0
+//    |    number_high     |    number_low    |
+//    |---------------------------------------|
+//
+// imm_i64(number_low, number_high)
+// imm_f64(number_low, number_high)
+// ```
+//
+// The parameter of `imm_f32` is the raw bits of the f32 number (IEEE 754).
+//
+// When an f32 is placed on the operand stack, only the low 32 bits of the operand
+// are meaningful, the high part is undefined (see the section "Memory Representation
+// of Data Types" in the module `opcode`), so it must be ignored when reading
+// an f32 from an operand, see `endian::operand_to_f32`.
+
+/// Splits an i64 number into the parameters `(number_low:i32, number_high:i32)`
+/// of the instruction `imm_i64`.
+pub fn split_imm_i64(value: u64) -> (u32, u32) {
+    let low = (value & 0xffff_ffff) as u32;
+    let high = (value >> 32) as u32;
+    (low, high)
+}
+
+/// Joins the parameters `(number_low:i32, number_high:i32)` of the instruction `imm_i64`.
+pub fn join_imm_i64(low: u32, high: u32) -> u64 {
+    ((high as u64) << 32) | (low as u64)
+}
+
+/// Splits an f64 number into the parameters `(number_low:i32, number_high:i32)`
+/// of the instruction `imm_f64`.
+pub fn split_imm_f64(value: f64) -> (u32, u32) {
+    split_imm_i64(value.to_bits())
+}
+
+/// Joins the parameters `(number_low:i32, number_high:i32)` of the instruction `imm_f64`.
+pub fn join_imm_f64(low: u32, high: u32) -> f64 {
+    f64::from_bits(join_imm_i64(low, high))
+}
+
+/// Converts an f32 number into the parameter `number:i32` of the instruction `imm_f32`.
+pub fn imm_f32_to_param(value: f32) -> u32 {
+    value.to_bits()
+}
+
+/// Converts the parameter `number:i32` of the instruction `imm_f32` into an f32 number.
+pub fn param_to_imm_f32(param: u32) -> f32 {
+    f32::from_bits(param)
+}
+
+/// The parameters of an instruction.
+///
+/// Parameters of type "i16" and "i32" are stored as `u16` and `u32`,
+/// the same as the raw encoding.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum InstructionParams {
+    NoParams,
+    I16(u16),
+    I32(u32),
+    I16I32(u16, u32),
+    I32I32(u32, u32),
+    I32I32I32(u32, u32, u32),
+}
+
+impl InstructionParams {
+    pub fn get_format(&self) -> InstructionFormat {
+        match self {
+            InstructionParams::NoParams => InstructionFormat::NoParams,
+            InstructionParams::I16(_) => InstructionFormat::I16,
+            InstructionParams::I32(_) => InstructionFormat::I32,
+            InstructionParams::I16I32(_, _) => InstructionFormat::I16I32,
+            InstructionParams::I32I32(_, _) => InstructionFormat::I32I32,
+            InstructionParams::I32I32I32(_, _, _) => InstructionFormat::I32I32I32,
+        }
+    }
+}
+
+/// A decoded instruction.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Instruction {
+    pub opcode: Opcode,
+    pub params: InstructionParams,
+}
+
+impl Instruction {
+    pub fn new(opcode: Opcode, params: InstructionParams) -> Self {
+        assert_eq!(
+            opcode.get_format(),
+            params.get_format(),
+            "The parameters do not match the instruction \"{}\".",
+            opcode.get_name()
+        );
+        Self { opcode, params }
+    }
+
+    pub fn get_length_in_bytes(&self) -> usize {
+        self.opcode.get_format().get_length_in_bytes()
+    }
+}
+
+/// Generates instructions whose parameters always match the opcode.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Instruction {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let opcode: Opcode = u.arbitrary()?;
+        let params = match opcode.get_format() {
+            InstructionFormat::NoParams => InstructionParams::NoParams,
+            InstructionFormat::I16 => InstructionParams::I16(u.arbitrary()?),
+            InstructionFormat::I32 => InstructionParams::I32(u.arbitrary()?),
+            InstructionFormat::I16I32 => InstructionParams::I16I32(u.arbitrary()?, u.arbitrary()?),
+            InstructionFormat::I32I32 => InstructionParams::I32I32(u.arbitrary()?, u.arbitrary()?),
+            InstructionFormat::I32I32I32 => {
+                InstructionParams::I32I32I32(u.arbitrary()?, u.arbitrary()?, u.arbitrary()?)
+            }
+        };
+        Ok(Self { opcode, params })
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = self.opcode.get_name();
+        match self.params {
+            InstructionParams::NoParams => write!(f, "{}", name),
+            InstructionParams::I16(p0) => write!(f, "{}({})", name, p0),
+            InstructionParams::I32(p0) => write!(f, "{}({})", name, p0),
+            InstructionParams::I16I32(p0, p1) => write!(f, "{}({}, {})", name, p0, p1),
+            InstructionParams::I32I32(p0, p1) => write!(f, "{}({}, {})", name, p0, p1),
+            InstructionParams::I32I32I32(p0, p1, p2) => {
+                write!(f, "{}({}, {}, {})", name, p0, p1, p2)
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum BytecodeError {
+    UnknownOpcode { offset: usize, value: u16 },
+    UnexpectedEnd { offset: usize },
+    Misaligned { offset: usize, opcode: Opcode },
+    InvalidVarint { offset: usize },
+}
+
+impl Display for BytecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BytecodeError::UnknownOpcode { offset, value } => write!(
+                f,
+                "Unknown opcode 0x{:04x} at offset 0x{:04x}.",
+                value, offset
+            ),
+            BytecodeError::UnexpectedEnd { offset } => {
+                write!(f, "Incomplete instruction at offset 0x{:04x}.", offset)
+            }
+            BytecodeError::Misaligned { offset, opcode } => write!(
+                f,
+                "Instruction \"{}\" at offset 0x{:04x} is not aligned to 4 bytes.",
+                opcode.get_name(),
+                offset
+            ),
+            BytecodeError::InvalidVarint { offset } => {
+                write!(f, "Invalid varint at offset 0x{:04x}.", offset)
+            }
+        }
+    }
+}
+
+impl std::error::Error for BytecodeError {}
+
+/// Decodes the bytecode of a function one instruction at a time.
+///
+/// Each item is the offset (in bytes) of the instruction and the instruction itself.
+/// Padding `nop` instructions are also yielded since they are regular instructions.
+pub struct BytecodeReader<'a> {
+    code: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> BytecodeReader<'a> {
+    pub fn new(code: &'a [u8]) -> Self {
+        Self { code, offset: 0 }
+    }
+
+    fn read_u16(&self, offset: usize) -> Result<u16, BytecodeError> {
+        read_u16(self.code, offset).ok_or(BytecodeError::UnexpectedEnd {
+            offset: self.offset,
+        })
+    }
+
+    fn read_u32(&self, offset: usize) -> Result<u32, BytecodeError> {
+        read_u32(self.code, offset).ok_or(BytecodeError::UnexpectedEnd {
+            offset: self.offset,
+        })
+    }
+
+    fn read_instruction(&self) -> Result<Instruction, BytecodeError> {
+        let offset = self.offset;
+        let value = self.read_u16(offset)?;
+        let opcode =
+            Opcode::from_u16(value).ok_or(BytecodeError::UnknownOpcode { offset, value })?;
+
+        let format = opcode.get_format();
+        if format.requires_alignment() && !offset.is_multiple_of(4) {
+            return Err(BytecodeError::Misaligned { offset, opcode });
+        }
+
+        let params = match format {
+            InstructionFormat::NoParams => InstructionParams::NoParams,
+            InstructionFormat::I16 => InstructionParams::I16(self.read_u16(offset + 2)?),
+            InstructionFormat::I32 => InstructionParams::I32(self.read_u32(offset + 4)?),
+            InstructionFormat::I16I32 => {
+                InstructionParams::I16I32(self.read_u16(offset + 2)?, self.read_u32(offset + 4)?)
+            }
+            InstructionFormat::I32I32 => {
+                InstructionParams::I32I32(self.read_u32(offset + 4)?, self.read_u32(offset + 8)?)
+            }
+            InstructionFormat::I32I32I32 => InstructionParams::I32I32I32(
+                self.read_u32(offset + 4)?,
+                self.read_u32(offset + 8)?,
+                self.read_u32(offset + 12)?,
+            ),
+        };
+
+        Ok(Instruction { opcode, params })
+    }
+}
+
+impl Iterator for BytecodeReader<'_> {
+    type Item = Result<(usize, Instruction), BytecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset >= self.code.len() {
+            return None;
+        }
+
+        match self.read_instruction() {
+            Ok(instruction) => {
+                let offset = self.offset;
+                self.offset += instruction.get_length_in_bytes();
+                Some(Ok((offset, instruction)))
+            }
+            Err(e) => {
+                // stop reading
+                self.offset = self.code.len();
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+/// Decodes the bytecode of a function into a list of (offset, instruction).
+pub fn decode_instructions(code: &[u8]) -> Result<Vec<(usize, Instruction)>, BytecodeError> {
+    BytecodeReader::new(code).collect()
+}
+
+/// A padding `nop` is the `nop` which is located at the non-aligned position
+/// and followed by an instruction that requires alignment.
+pub(crate) fn is_padding(
+    offset: usize,
+    instruction: &Instruction,
+    next: Option<&(usize, Instruction)>,
+) -> bool {
+    instruction.opcode == Opcode::nop
+        && !offset.is_multiple_of(4)
+        && next.is_some_and(|(_, next_instruction)| {
+            next_instruction.opcode.get_format().requires_alignment()
+        })
+}
+
+// Labels
+// ------
+//
+// The parameters `next_inst_offset` and `start_inst_offset` of the control flow
+// instructions are byte offsets relative to the address of the instruction itself
+// (see the instructions `break`, `recur`, `block_alt`, `break_alt` and `block_nez`
+// in the module `opcode`). Instead of computing them by hand, a label can be
+// created with `new_label`, used by `append_break`, `append_recur`, etc., and
+// bound to an address later with `bind_label`. The offsets are patched in `to_bytes`.
+//
+// - `break`, `break_nez`, `break_alt`, `block_nez` and `break_table`: bind the label right after the "end" instruction.
+// - `block_alt`: bind the label at the first instruction of the 'else' part.
+// - `recur` and `recur_nez`: bind the label right after the "block" instruction.
+
+/// A symbolic address in the bytecode, see `BytecodeWriter::new_label`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Label(usize);
+
+#[derive(Debug)]
+struct LabelFixup {
+    label: Label,
+
+    /// The address of the instruction.
+    instruction_offset: usize,
+
+    /// The address of the parameter to be patched.
+    param_offset: usize,
+
+    /// `true` for `start_inst_offset` (i.e., jumping backward).
+    backward: bool,
+}
+
+/// Encodes instructions into bytecode.
+///
+/// A `nop` instruction is inserted automatically before the instructions
+/// that contain i32 parameters if alignment is required.
+#[derive(Debug, Default)]
+pub struct BytecodeWriter {
+    buffer: Vec<u8>,
+    labels: Vec<Option<usize>>,
+    fixups: Vec<LabelFixup>,
+}
+
+impl BytecodeWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The length of the bytecode written so far,
+    /// i.e., the offset of the next instruction (before alignment).
+    pub fn get_length(&self) -> usize {
+        self.buffer.len()
+    }
+
+    fn put_u16(&mut self, value: u16) {
+        write_u16(&mut self.buffer, value);
+    }
+
+    fn put_u32(&mut self, value: u32) {
+        write_u32(&mut self.buffer, value);
+    }
+
+    fn start_opcode(&mut self, opcode: Opcode, format: InstructionFormat) {
+        debug_assert_eq!(
+            opcode.get_format(),
+            format,
+            "The parameters do not match the instruction \"{}\".",
+            opcode.get_name()
+        );
+
+        if format.requires_alignment() && !self.buffer.len().is_multiple_of(4) {
+            self.put_u16(Opcode::nop as u16);
+        }
+
+        self.put_u16(opcode as u16);
+    }
+
+    pub fn append_opcode(&mut self, opcode: Opcode) -> &mut Self {
+        self.start_opcode(opcode, InstructionFormat::NoParams);
+        self
+    }
+
+    pub fn append_opcode_i16(&mut self, opcode: Opcode, param0: u16) -> &mut Self {
+        self.start_opcode(opcode, InstructionFormat::I16);
+        self.put_u16(param0);
+        self
+    }
+
+    pub fn append_opcode_i32(&mut self, opcode: Opcode, param0: u32) -> &mut Self {
+        self.start_opcode(opcode, InstructionFormat::I32);
+        self.put_u16(0); // padding
+        self.put_u32(param0);
+        self
+    }
+
+    pub fn append_opcode_i16_i32(&mut self, opcode: Opcode, param0: u16, param1: u32) -> &mut Self {
+        self.start_opcode(opcode, InstructionFormat::I16I32);
+        self.put_u16(param0);
+        self.put_u32(param1);
+        self
+    }
+
+    pub fn append_opcode_i32_i32(&mut self, opcode: Opcode, param0: u32, param1: u32) -> &mut Self {
+        self.start_opcode(opcode, InstructionFormat::I32I32);
+        self.put_u16(0); // padding
+        self.put_u32(param0);
+        self.put_u32(param1);
+        self
+    }
+
+    pub fn append_opcode_i32_i32_i32(
+        &mut self,
+        opcode: Opcode,
+        param0: u32,
+        param1: u32,
+        param2: u32,
+    ) -> &mut Self {
+        self.start_opcode(opcode, InstructionFormat::I32I32I32);
+        self.put_u16(0); // padding
+        self.put_u32(param0);
+        self.put_u32(param1);
+        self.put_u32(param2);
+        self
+    }
+
+    pub fn append_instruction(&mut self, instruction: &Instruction) -> &mut Self {
+        let opcode = instruction.opcode;
+        match instruction.params {
+            InstructionParams::NoParams => self.append_opcode(opcode),
+            InstructionParams::I16(p0) => self.append_opcode_i16(opcode, p0),
+            InstructionParams::I32(p0) => self.append_opcode_i32(opcode, p0),
+            InstructionParams::I16I32(p0, p1) => self.append_opcode_i16_i32(opcode, p0, p1),
+            InstructionParams::I32I32(p0, p1) => self.append_opcode_i32_i32(opcode, p0, p1),
+            InstructionParams::I32I32I32(p0, p1, p2) => {
+                self.append_opcode_i32_i32_i32(opcode, p0, p1, p2)
+            }
+        }
+    }
+
+    pub fn new_label(&mut self) -> Label {
+        self.labels.push(None);
+        Label(self.labels.len() - 1)
+    }
+
+    /// Binds the label to the current position, i.e., the address of
+    /// the next instruction (before alignment).
+    pub fn bind_label(&mut self, label: Label) -> &mut Self {
+        let position = &mut self.labels[label.0];
+        assert!(
+            position.is_none(),
+            "The label {} is already bound.",
+            label.0
+        );
+        *position = Some(self.buffer.len());
+        self
+    }
+
+    /// Returns the address of the label, or `None` if the label is not bound yet.
+    pub fn get_label_offset(&self, label: Label) -> Option<usize> {
+        self.labels[label.0]
+    }
+
+    /// Adds a fixup for the last i32 parameter of the instruction just appended.
+    fn add_fixup(&mut self, opcode: Opcode, label: Label, backward: bool) {
+        let length = opcode.get_format().get_length_in_bytes();
+        self.fixups.push(LabelFixup {
+            label,
+            instruction_offset: self.buffer.len() - length,
+            param_offset: self.buffer.len() - 4,
+            backward,
+        });
+    }
+
+    /// Appends `break(layers, next_inst_offset)`, the label should be bound right
+    /// after the "end" instruction of the target block.
+    pub fn append_break(&mut self, layers: u16, label: Label) -> &mut Self {
+        self.append_opcode_i16_i32(Opcode::break_, layers, 0);
+        self.add_fixup(Opcode::break_, label, false);
+        self
+    }
+
+    /// Appends `break_nez(layers, next_inst_offset)`, the label should be bound right
+    /// after the "end" instruction of the target block.
+    pub fn append_break_nez(&mut self, layers: u16, label: Label) -> &mut Self {
+        self.append_opcode_i16_i32(Opcode::break_nez, layers, 0);
+        self.add_fixup(Opcode::break_nez, label, false);
+        self
+    }
+
+    /// Appends `recur(layers, start_inst_offset)`, the label should be bound right
+    /// after the "block" instruction of the target block.
+    pub fn append_recur(&mut self, layers: u16, label: Label) -> &mut Self {
+        self.append_opcode_i16_i32(Opcode::recur, layers, 0);
+        self.add_fixup(Opcode::recur, label, true);
+        self
+    }
+
+    /// Appends `recur_nez(layers, start_inst_offset)`, the label should be bound right
+    /// after the "block" instruction of the target block.
+    pub fn append_recur_nez(&mut self, layers: u16, label: Label) -> &mut Self {
+        self.append_opcode_i16_i32(Opcode::recur_nez, layers, 0);
+        self.add_fixup(Opcode::recur_nez, label, true);
+        self
+    }
+
+    /// Appends `block_alt(type_index, local_variable_list_index, next_inst_offset)`,
+    /// the label should be bound at the first instruction of the 'else' part.
+    pub fn append_block_alt(
+        &mut self,
+        type_index: u32,
+        local_variable_list_index: u32,
+        label: Label,
+    ) -> &mut Self {
+        self.append_opcode_i32_i32_i32(Opcode::block_alt, type_index, local_variable_list_index, 0);
+        self.add_fixup(Opcode::block_alt, label, false);
+        self
+    }
+
+    /// Appends `break_alt(next_inst_offset)`, the label should be bound right
+    /// after the "end" instruction.
+    pub fn append_break_alt(&mut self, label: Label) -> &mut Self {
+        self.append_opcode_i32(Opcode::break_alt, 0);
+        self.add_fixup(Opcode::break_alt, label, false);
+        self
+    }
+
+    /// Appends `block_nez(local_variable_list_index, next_inst_offset)`,
+    /// the label should be bound right after the "end" instruction.
+    pub fn append_block_nez(&mut self, local_variable_list_index: u32, label: Label) -> &mut Self {
+        self.append_opcode_i32_i32(Opcode::block_nez, local_variable_list_index, 0);
+        self.add_fixup(Opcode::block_nez, label, false);
+        self
+    }
+
+    /// Appends `break_table(case_count)` followed by the table of "break" instructions,
+    /// the `default` case is appended last.
+    ///
+    /// Each case is a pair of `layers` and the label which should be bound right
+    /// after the "end" instruction of the target block.
+    pub fn append_break_table(
+        &mut self,
+        cases: &[(u16, Label)],
+        default: (u16, Label),
+    ) -> &mut Self {
+        self.append_opcode_i32(Opcode::break_table, cases.len() as u32);
+        for (layers, label) in cases.iter().chain(std::iter::once(&default)) {
+            self.append_break(*layers, *label);
+        }
+        self
+    }
+
+    /// Returns the bytecode, the offsets of labels are patched.
+    ///
+    /// Panics if a label is not bound, or the label is in the wrong direction.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buffer = self.buffer.clone();
+
+        for fixup in &self.fixups {
+            let target = self.labels[fixup.label.0]
+                .unwrap_or_else(|| panic!("The label {} is not bound.", fixup.label.0));
+
+            let offset = if fixup.backward {
+                assert!(
+                    target <= fixup.instruction_offset,
+                    "The label {} should be bound before the instruction at {}.",
+                    fixup.label.0,
+                    fixup.instruction_offset
+                );
+                fixup.instruction_offset - target
+            } else {
+                assert!(
+                    target > fixup.instruction_offset,
+                    "The label {} should be bound after the instruction at {}.",
+                    fixup.label.0,
+                    fixup.instruction_offset
+                );
+                target - fixup.instruction_offset
+            };
+
+            buffer[fixup.param_offset..fixup.param_offset + 4]
+                .copy_from_slice(&(offset as u32).to_le_bytes());
+        }
+
+        buffer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        bytecode::{
+            decode_instructions, imm_f32_to_param, join_imm_f64, join_imm_i64, param_to_imm_f32,
+            split_imm_f64, split_imm_i64, BytecodeError, BytecodeWriter, Instruction,
+            InstructionParams,
+        },
+        edition::Edition,
+        opcode::Opcode,
+        IMAGE_FORMAT_MAJOR_VERSION, IMAGE_FORMAT_MINOR_VERSION, RUNTIME_EDITION,
+        RUNTIME_EDITION_STRING,
+    };
+
+    #[test]
+    fn test_imm_i64() {
+        let (low, high) = split_imm_i64(0x1122_3344_5566_7788);
+        assert_eq!(low, 0x5566_7788);
+        assert_eq!(high, 0x1122_3344);
+        assert_eq!(join_imm_i64(low, high), 0x1122_3344_5566_7788);
+
+        // negative number
+        let (low, high) = split_imm_i64(-2i64 as u64);
+        assert_eq!(low, 0xffff_fffe);
+        assert_eq!(high, 0xffff_ffff);
+        assert_eq!(join_imm_i64(low, high) as i64, -2);
+    }
+
+    #[test]
+    fn test_imm_f64() {
+        let (low, high) = split_imm_f64(std::f64::consts::PI);
+        assert_eq!(join_imm_i64(low, high), std::f64::consts::PI.to_bits());
+        assert_eq!(join_imm_f64(low, high), std::f64::consts::PI);
+
+        let (low, high) = split_imm_f64(-0.0);
+        assert_eq!(low, 0);
+        assert_eq!(high, 0x8000_0000);
+    }
+
+    #[test]
+    fn test_imm_f32() {
+        assert_eq!(imm_f32_to_param(-5.0), 0xc0a0_0000);
+        assert_eq!(param_to_imm_f32(0x3e20_0000), 0.15625);
+    }
+
+    #[test]
+    fn test_opcode_from_u16() {
+        for value in 0..=u16::MAX {
+            if let Some(opcode) = Opcode::from_u16(value) {
+                assert_eq!(opcode as u16, value);
+                assert_eq!(Opcode::from_name(opcode.get_name()), opcode);
+            }
+        }
+
+        assert_eq!(Opcode::from_u16(0x0100), Some(Opcode::nop));
+        assert_eq!(Opcode::from_u16(0x0C00), Some(Opcode::terminate));
+        assert_eq!(Opcode::from_u16(0x0000), None);
+        assert_eq!(Opcode::from_u16(0x01ff), None);
+    }
+
+    #[test]
+    fn test_opcode_since() {
+        // all opcodes must be supported by the current runtime.
+        for value in 0..=u16::MAX {
+            if let Some(opcode) = Opcode::from_u16(value) {
+                assert!(opcode.since().is_supported_by(
+                    RUNTIME_EDITION_STRING,
+                    IMAGE_FORMAT_MAJOR_VERSION,
+                    IMAGE_FORMAT_MINOR_VERSION
+                ));
+            }
+        }
+
+        let since = Opcode::add_i32.since();
+        assert!(since.is_supported_by("2025", 1, 0));
+        assert!(!since.is_supported_by("2028", 1, 0));
+        assert!(!since.is_supported_by("2025", 2, 0));
+
+        let since = Opcode::atomic_load_i32.since();
+        assert!(since.is_supported_by("2025", 1, 1));
+        assert!(!since.is_supported_by("2025", 1, 0));
+    }
+
+    #[test]
+    fn test_opcode_available_in() {
+        for value in 0..=u16::MAX {
+            if let Some(opcode) = Opcode::from_u16(value) {
+                assert!(opcode.available_in(&RUNTIME_EDITION));
+            }
+        }
+
+        let unknown = Edition::from_edition_string("1999").unwrap();
+        assert!(!Opcode::add_i32.available_in(&unknown));
+    }
+
+    #[test]
+    fn test_write_and_read_bytecode() {
+        let code = BytecodeWriter::new()
+            .append_opcode_i16(Opcode::add_imm_i32, 0x11)
+            .append_opcode_i32(Opcode::imm_i32, 0x1317)
+            .append_opcode(Opcode::add_i32)
+            .append_opcode_i16_i32(Opcode::local_store_i32, 0, 1)
+            .append_opcode_i32_i32(Opcode::block, 0x19, 0x23)
+            .append_opcode_i32_i32_i32(Opcode::block_alt, 0x29, 0x31, 0x37)
+            .append_opcode(Opcode::end)
+            .append_opcode(Opcode::end)
+            .to_bytes();
+
+        assert_eq!(
+            code,
+            vec![
+                0x02, 0x04, 0x11, 0x00, // add_imm_i32
+                0x01, 0x01, 0x00, 0x00, 0x17, 0x13, 0x00, 0x00, // imm_i32
+                0x00, 0x04, // add_i32
+                0x00, 0x01, // padding nop
+                0x0a, 0x02, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, // local_store_i32
+                0x01, 0x09, 0x00, 0x00, 0x19, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00,
+                0x00, // block
+                0x04, 0x09, 0x00, 0x00, 0x29, 0x00, 0x00, 0x00, 0x31, 0x00, 0x00, 0x00, 0x37, 0x00,
+                0x00, 0x00, // block_alt
+                0x00, 0x09, // end
+                0x00, 0x09, // end
+            ]
+        );
+
+        let instructions = decode_instructions(&code).unwrap();
+        assert_eq!(
+            instructions,
+            vec![
+                (
+                    0,
+                    Instruction::new(Opcode::add_imm_i32, InstructionParams::I16(0x11))
+                ),
+                (
+                    4,
+                    Instruction::new(Opcode::imm_i32, InstructionParams::I32(0x1317))
+                ),
+                (
+                    12,
+                    Instruction::new(Opcode::add_i32, InstructionParams::NoParams)
+                ),
+                (
+                    14,
+                    Instruction::new(Opcode::nop, InstructionParams::NoParams)
+                ),
+                (
+                    16,
+                    Instruction::new(Opcode::local_store_i32, InstructionParams::I16I32(0, 1))
+                ),
+                (
+                    24,
+                    Instruction::new(Opcode::block, InstructionParams::I32I32(0x19, 0x23))
+                ),
+                (
+                    36,
+                    Instruction::new(
+                        Opcode::block_alt,
+                        InstructionParams::I32I32I32(0x29, 0x31, 0x37)
+                    )
+                ),
+                (
+                    52,
+                    Instruction::new(Opcode::end, InstructionParams::NoParams)
+                ),
+                (
+                    54,
+                    Instruction::new(Opcode::end, InstructionParams::NoParams)
+                ),
+            ]
+        );
+
+        assert_eq!(instructions[1].1.to_string(), "imm_i32(4887)");
+    }
+
+    #[test]
+    fn test_write_labels() {
+        let mut writer = BytecodeWriter::new();
+        let start = writer.new_label();
+        writer
+            .append_opcode_i32_i32(Opcode::block, 0, 0)
+            .bind_label(start)
+            .append_opcode_i32(Opcode::imm_i32, 11)
+            .append_opcode_i32(Opcode::imm_i32, 13)
+            .append_opcode_i32(Opcode::imm_i32, 17)
+            .append_recur(0, start)
+            .append_opcode(Opcode::end);
+
+        let else_part = writer.new_label();
+        let exit = writer.new_label();
+        writer
+            .append_opcode_i32(Opcode::imm_i32, 1)
+            .append_block_alt(0, 0, else_part)
+            .append_opcode(Opcode::nop)
+            .append_break_alt(exit)
+            .bind_label(else_part)
+            .append_opcode(Opcode::nop)
+            .append_opcode(Opcode::end)
+            .bind_label(exit)
+            .append_opcode(Opcode::end);
+
+        let code = writer.to_bytes();
+        let instructions = decode_instructions(&code).unwrap();
+
+        assert_eq!(writer.get_label_offset(start), Some(12));
+        assert_eq!(writer.get_label_offset(else_part), Some(84));
+        assert_eq!(writer.get_label_offset(exit), Some(88));
+        assert_eq!(
+            instructions
+                .iter()
+                .filter(|(_, instruction)| matches!(
+                    instruction.opcode,
+                    Opcode::recur | Opcode::block_alt | Opcode::break_alt
+                ))
+                .map(|(offset, instruction)| (*offset, instruction.params))
+                .collect::<Vec<_>>(),
+            vec![
+                (36, InstructionParams::I16I32(0, 24)),       // 24 = 36 - 12
+                (56, InstructionParams::I32I32I32(0, 0, 28)), // 28 = 84 - 56
+                (76, InstructionParams::I32(12)),             // 12 = 88 - 76
+            ]
+        );
+    }
+
+    #[test]
+    fn test_write_break_table() {
+        let mut writer = BytecodeWriter::new();
+        let case0 = writer.new_label();
+        let default = writer.new_label();
+        writer
+            .append_opcode_i32_i32(Opcode::block, 0, 0)
+            .append_opcode_i32_i32(Opcode::block, 0, 0)
+            .append_opcode_i32(Opcode::imm_i32, 0)
+            .append_break_table(&[(0, case0)], (1, default))
+            .append_opcode(Opcode::end)
+            .bind_label(case0)
+            .append_opcode(Opcode::nop)
+            .append_opcode(Opcode::end)
+            .bind_label(default)
+            .append_opcode(Opcode::end);
+
+        let code = writer.to_bytes();
+        let instructions = decode_instructions(&code).unwrap();
+
+        assert_eq!(
+            instructions
+                .iter()
+                .filter(|(_, instruction)| matches!(
+                    instruction.opcode,
+                    Opcode::break_table | Opcode::break_
+                ))
+                .map(|(offset, instruction)| (*offset, instruction.params))
+                .collect::<Vec<_>>(),
+            vec![
+                (32, InstructionParams::I32(1)),
+                (40, InstructionParams::I16I32(0, 18)), // 18 = 58 - 40
+                (48, InstructionParams::I16I32(1, 14)), // 14 = 62 - 48
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_write_unbound_label() {
+        let mut writer = BytecodeWriter::new();
+        let label = writer.new_label();
+        writer.append_break(0, label);
+        writer.to_bytes();
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn test_arbitrary_instruction() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let data = (0..=255u8).cycle().take(4096).collect::<Vec<_>>();
+        let mut u = Unstructured::new(&data);
+        let mut writer = BytecodeWriter::new();
+        let mut instructions = vec![];
+
+        while let Ok(instruction) = Instruction::arbitrary(&mut u) {
+            if u.is_empty() {
+                break;
+            }
+            writer.append_instruction(&instruction);
+            instructions.push(instruction);
+        }
+
+        // the generated instructions can be encoded and decoded.
+        let code = writer.to_bytes();
+        let decoded = decode_instructions(&code)
+            .unwrap()
+            .into_iter()
+            .map(|(_, instruction)| instruction)
+            .filter(|instruction| instruction.opcode != Opcode::nop)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            decoded,
+            instructions
+                .into_iter()
+                .filter(|instruction| instruction.opcode != Opcode::nop)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_read_invalid_bytecode() {
+        assert_eq!(
+            decode_instructions(&[0x00, 0x01, 0xff, 0x01]),
+            Err(BytecodeError::UnknownOpcode {
+                offset: 2,
+                value: 0x01ff
+            })
+        );
+
+        assert_eq!(
+            decode_instructions(&[0x01, 0x01, 0x00, 0x00, 0x17]),
+            Err(BytecodeError::UnexpectedEnd { offset: 0 })
+        );
+
+        assert_eq!(
+            decode_instructions(&[0x00, 0x04, 0x01, 0x01, 0x00, 0x00, 0x17, 0x13, 0x00, 0x00]),
+            Err(BytecodeError::Misaligned {
+                offset: 2,
+                opcode: Opcode::imm_i32
+            })
+        );
+    }
+}
@@ -0,0 +1,308 @@
+// Copyright (c) 2025 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions.
+// For more details, see the LICENSE, LICENSE.additional, and CONTRIBUTING files.
+
+// Compact Encoding
+// ----------------
+//
+// The executable encoding of instructions is fixed-length and aligned (see the section
+// "Instruction Encoding" in the module `opcode`), which is fast for the interpreter but
+// wastes space, e.g., `imm_i32(1)` occupies 8 bytes and there are padding `nop`
+// instructions and padding bytes.
+//
+// The compact encoding is an optional, lossless re-encoding for storing bytecode
+// on disk or transferring it over the network:
+//
+// - The opcode is encoded as an unsigned LEB128 varint.
+// - Parameters are encoded as signed LEB128 varints (the i16/i32 value is
+//   sign-extended first), so small positive and negative numbers occupy 1 byte.
+// - The `number_high` parameter of `imm_i64` is encoded as the delta to the
+//   sign-extension of `number_low`, so it occupies 1 byte for numbers in the i32 range.
+// - Padding `nop` instructions are removed, they are re-inserted when converting
+//   back to the executable encoding.
+//
+// ```text
+// | executable encoding                       | compact encoding |
+// |-------------------------------------------|------------------|
+// | 00 01                                     |                  | <-- padding nop
+// | 01 01 00 00 - 0b 00 00 00                 | 81 02 - 0b       | <-- imm_i32(11)
+// ```
+//
+// The bytecode converted back from the compact encoding is byte-for-byte identical to
+// the original, as long as the original is the canonical encoding (i.e., produced by
+// the `BytecodeWriter`, with zero padding bytes).
+
+use crate::opcode::{InstructionFormat, Opcode};
+
+use super::{
+    is_padding, BytecodeError, BytecodeReader, BytecodeWriter, Instruction, InstructionParams,
+};
+
+/// Converts bytecode from the executable encoding to the compact encoding.
+pub fn encode_compact(code: &[u8]) -> Result<Vec<u8>, BytecodeError> {
+    let instructions = BytecodeReader::new(code).collect::<Result<Vec<_>, _>>()?;
+    let mut buffer: Vec<u8> = vec![];
+
+    for (index, (offset, instruction)) in instructions.iter().enumerate() {
+        if is_padding(*offset, instruction, instructions.get(index + 1)) {
+            continue;
+        }
+
+        write_unsigned(&mut buffer, instruction.opcode as u64);
+
+        match instruction.params {
+            InstructionParams::NoParams => {}
+            InstructionParams::I16(p0) => {
+                write_signed(&mut buffer, p0 as i16 as i64);
+            }
+            InstructionParams::I32(p0) => {
+                write_signed(&mut buffer, p0 as i32 as i64);
+            }
+            InstructionParams::I16I32(p0, p1) => {
+                write_signed(&mut buffer, p0 as i16 as i64);
+                write_signed(&mut buffer, p1 as i32 as i64);
+            }
+            InstructionParams::I32I32(p0, p1) => {
+                write_signed(&mut buffer, p0 as i32 as i64);
+                if instruction.opcode == Opcode::imm_i64 {
+                    write_signed(&mut buffer, high_delta(p0, p1));
+                } else {
+                    write_signed(&mut buffer, p1 as i32 as i64);
+                }
+            }
+            InstructionParams::I32I32I32(p0, p1, p2) => {
+                write_signed(&mut buffer, p0 as i32 as i64);
+                write_signed(&mut buffer, p1 as i32 as i64);
+                write_signed(&mut buffer, p2 as i32 as i64);
+            }
+        }
+    }
+
+    Ok(buffer)
+}
+
+/// Converts bytecode from the compact encoding back to the executable encoding.
+pub fn decode_compact(data: &[u8]) -> Result<Vec<u8>, BytecodeError> {
+    let mut writer = BytecodeWriter::new();
+    let mut offset = 0;
+
+    while offset < data.len() {
+        let instruction_offset = offset;
+        let value = read_unsigned(data, &mut offset)?;
+        let opcode = u16::try_from(value).ok().and_then(Opcode::from_u16).ok_or(
+            BytecodeError::UnknownOpcode {
+                offset: instruction_offset,
+                value: value as u16,
+            },
+        )?;
+
+        let params = match opcode.get_format() {
+            InstructionFormat::NoParams => InstructionParams::NoParams,
+            InstructionFormat::I16 => InstructionParams::I16(read_i16(data, &mut offset)?),
+            InstructionFormat::I32 => InstructionParams::I32(read_i32(data, &mut offset)?),
+            InstructionFormat::I16I32 => InstructionParams::I16I32(
+                read_i16(data, &mut offset)?,
+                read_i32(data, &mut offset)?,
+            ),
+            InstructionFormat::I32I32 => {
+                let p0 = read_i32(data, &mut offset)?;
+                let p1 = if opcode == Opcode::imm_i64 {
+                    let delta = read_signed(data, &mut offset)?;
+                    (p0 as i32 as i64 >> 32).wrapping_add(delta) as u32
+                } else {
+                    read_i32(data, &mut offset)?
+                };
+                InstructionParams::I32I32(p0, p1)
+            }
+            InstructionFormat::I32I32I32 => InstructionParams::I32I32I32(
+                read_i32(data, &mut offset)?,
+                read_i32(data, &mut offset)?,
+                read_i32(data, &mut offset)?,
+            ),
+        };
+
+        writer.append_instruction(&Instruction { opcode, params });
+    }
+
+    Ok(writer.to_bytes())
+}
+
+/// The delta between the `number_high` and the sign-extension of `number_low`.
+fn high_delta(low: u32, high: u32) -> i64 {
+    let predicted = (low as i32 as i64 >> 32) as i32;
+    (high as i32).wrapping_sub(predicted) as i64
+}
+
+fn write_unsigned(buffer: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            buffer.push(byte);
+            break;
+        } else {
+            buffer.push(byte | 0x80);
+        }
+    }
+}
+
+fn write_signed(buffer: &mut Vec<u8>, mut value: i64) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        let done = (value == 0 && byte & 0x40 == 0) || (value == -1 && byte & 0x40 != 0);
+        if done {
+            buffer.push(byte);
+            break;
+        } else {
+            buffer.push(byte | 0x80);
+        }
+    }
+}
+
+fn read_unsigned(data: &[u8], offset: &mut usize) -> Result<u64, BytecodeError> {
+    let start = *offset;
+    let mut value: u64 = 0;
+    let mut shift = 0;
+    loop {
+        let byte = *data
+            .get(*offset)
+            .ok_or(BytecodeError::UnexpectedEnd { offset: start })?;
+        *offset += 1;
+
+        if shift >= 64 {
+            return Err(BytecodeError::InvalidVarint { offset: start });
+        }
+
+        value |= ((byte & 0x7f) as u64) << shift;
+        shift += 7;
+
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+}
+
+fn read_signed(data: &[u8], offset: &mut usize) -> Result<i64, BytecodeError> {
+    let start = *offset;
+    let mut value: i64 = 0;
+    let mut shift = 0;
+    loop {
+        let byte = *data
+            .get(*offset)
+            .ok_or(BytecodeError::UnexpectedEnd { offset: start })?;
+        *offset += 1;
+
+        if shift >= 64 {
+            return Err(BytecodeError::InvalidVarint { offset: start });
+        }
+
+        value |= ((byte & 0x7f) as i64) << shift;
+        shift += 7;
+
+        if byte & 0x80 == 0 {
+            if shift < 64 && byte & 0x40 != 0 {
+                // sign-extend
+                value |= -1i64 << shift;
+            }
+            return Ok(value);
+        }
+    }
+}
+
+fn read_i16(data: &[u8], offset: &mut usize) -> Result<u16, BytecodeError> {
+    let start = *offset;
+    let value = read_signed(data, offset)?;
+    i16::try_from(value)
+        .map(|v| v as u16)
+        .map_err(|_| BytecodeError::InvalidVarint { offset: start })
+}
+
+fn read_i32(data: &[u8], offset: &mut usize) -> Result<u32, BytecodeError> {
+    let start = *offset;
+    let value = read_signed(data, offset)?;
+    i32::try_from(value)
+        .map(|v| v as u32)
+        .map_err(|_| BytecodeError::InvalidVarint { offset: start })
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        bytecode::{
+            compact::{decode_compact, encode_compact},
+            split_imm_f64, split_imm_i64, BytecodeError, BytecodeWriter,
+        },
+        opcode::Opcode,
+    };
+
+    #[test]
+    fn test_compact_encoding() {
+        let (i64_low, i64_high) = split_imm_i64(-3i64 as u64);
+        let (f64_low, f64_high) = split_imm_f64(1.5);
+
+        let code = BytecodeWriter::new()
+            .append_opcode(Opcode::nop)
+            .append_opcode_i32(Opcode::imm_i32, 11)
+            .append_opcode(Opcode::nop)
+            .append_opcode_i32_i32(Opcode::imm_i64, i64_low, i64_high)
+            .append_opcode_i32_i32(Opcode::imm_f64, f64_low, f64_high)
+            .append_opcode_i16(Opcode::add_imm_i32, 0xffff)
+            .append_opcode_i16_i32(Opcode::local_store_i64, 0, 0x1234_5678)
+            .append_opcode_i32_i32_i32(Opcode::block_alt, 0, 1, 0x100)
+            .append_opcode(Opcode::end)
+            .to_bytes();
+
+        let data = encode_compact(&code).unwrap();
+        assert!(data.len() < code.len());
+        assert_eq!(decode_compact(&data).unwrap(), code);
+
+        // the explicit `nop` is kept, the padding `nop` is removed.
+        assert_eq!(
+            encode_compact(
+                &BytecodeWriter::new()
+                    .append_opcode(Opcode::nop)
+                    .append_opcode_i32(Opcode::imm_i32, 11)
+                    .to_bytes()
+            )
+            .unwrap(),
+            vec![0x80, 0x02, 0x81, 0x02, 0x0b]
+        );
+
+        assert_eq!(
+            encode_compact(
+                &BytecodeWriter::new()
+                    .append_opcode_i32_i32(Opcode::imm_i64, i64_low, i64_high)
+                    .to_bytes()
+            )
+            .unwrap(),
+            vec![0x82, 0x02, 0x7d, 0x00]
+        );
+    }
+
+    #[test]
+    fn test_decode_invalid_compact_encoding() {
+        assert_eq!(
+            decode_compact(&[0x81, 0x02, 0x8b]),
+            Err(BytecodeError::UnexpectedEnd { offset: 2 })
+        );
+
+        assert_eq!(
+            decode_compact(&[0xff, 0x03]),
+            Err(BytecodeError::UnknownOpcode {
+                offset: 0,
+                value: 0x01ff
+            })
+        );
+
+        // `add_imm_i32` with a parameter out of the i16 range
+        assert_eq!(
+            decode_compact(&[0x82, 0x08, 0x80, 0x80, 0x04]),
+            Err(BytecodeError::InvalidVarint { offset: 2 })
+        );
+    }
+}
@@ -0,0 +1,324 @@
+// Copyright (c) 2025 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions.
+// For more details, see the LICENSE, LICENSE.additional, and CONTRIBUTING files.
+
+// Bytecode Diff
+// -------------
+//
+// Compares two instruction streams (e.g., the code generated before and after
+// a change of the compiler) and reports the inserted, removed and changed instructions.
+//
+// - Padding `nop` instructions are ignored, so the insertion of an instruction
+//   does not produce a cascade of padding differences.
+// - Instructions are compared by opcode and parameters, the offsets are
+//   reported but not compared.
+// - An instruction is reported as "changed" if it is removed and an instruction
+//   with the same opcode is inserted at the same position, i.e., only the
+//   parameters are different.
+
+use super::{is_padding, BytecodeError, BytecodeReader, Instruction};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum DiffItem {
+    Removed {
+        old_offset: usize,
+        instruction: Instruction,
+    },
+    Inserted {
+        new_offset: usize,
+        instruction: Instruction,
+    },
+    Changed {
+        old_offset: usize,
+        new_offset: usize,
+        old_instruction: Instruction,
+        new_instruction: Instruction,
+    },
+}
+
+/// Compares the bytecode of two functions.
+///
+/// Returns an empty list if the two functions are identical (ignoring padding).
+pub fn diff_bytecode(old_code: &[u8], new_code: &[u8]) -> Result<Vec<DiffItem>, BytecodeError> {
+    let old = decode_without_padding(old_code)?;
+    let new = decode_without_padding(new_code)?;
+    Ok(diff_instructions(&old, &new))
+}
+
+/// Compares two lists of (offset, instruction).
+///
+/// The common prefix and suffix are skipped, and the rest is compared by the
+/// Hirschberg's algorithm, which finds the longest common subsequence in linear space.
+pub fn diff_instructions(
+    old: &[(usize, Instruction)],
+    new: &[(usize, Instruction)],
+) -> Vec<DiffItem> {
+    let prefix = old
+        .iter()
+        .zip(new)
+        .take_while(|(left, right)| left.1 == right.1)
+        .count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(left, right)| left.1 == right.1)
+        .count();
+
+    let mut edits = vec![];
+    append_edits(
+        &old[prefix..old.len() - suffix],
+        &new[prefix..new.len() - suffix],
+        &mut edits,
+    );
+
+    let mut items = vec![];
+    let mut removed: Vec<(usize, Instruction)> = vec![];
+    let mut inserted: Vec<(usize, Instruction)> = vec![];
+
+    for edit in edits {
+        match edit {
+            Edit::Same => flush_hunk(&mut items, &mut removed, &mut inserted),
+            Edit::Removed(item) => removed.push(item),
+            Edit::Inserted(item) => inserted.push(item),
+        }
+    }
+
+    flush_hunk(&mut items, &mut removed, &mut inserted);
+    items
+}
+
+enum Edit {
+    Same,
+    Removed((usize, Instruction)),
+    Inserted((usize, Instruction)),
+}
+
+/// Returns the lengths of the LCS of `old` and each prefix of `new`, i.e., `lengths[j]`
+/// is the length of the LCS of `old` and `new[..j]`.
+/// If `reverse` is `true`, both lists are read backwards, i.e., `lengths[j]` is the
+/// length of the LCS of `old` and the last `j` instructions of `new`.
+fn get_lcs_lengths(
+    old: &[(usize, Instruction)],
+    new: &[(usize, Instruction)],
+    reverse: bool,
+) -> Vec<usize> {
+    let get = |items: &[(usize, Instruction)], index: usize| {
+        if reverse {
+            items[items.len() - 1 - index].1
+        } else {
+            items[index].1
+        }
+    };
+
+    let mut previous = vec![0usize; new.len() + 1];
+    let mut current = vec![0usize; new.len() + 1];
+    for i in 0..old.len() {
+        let instruction = get(old, i);
+        for j in 0..new.len() {
+            current[j + 1] = if instruction == get(new, j) {
+                previous[j] + 1
+            } else {
+                previous[j + 1].max(current[j])
+            };
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous
+}
+
+/// Appends the edits which transform `old` into `new` (the Hirschberg's algorithm).
+fn append_edits(old: &[(usize, Instruction)], new: &[(usize, Instruction)], edits: &mut Vec<Edit>) {
+    if old.is_empty() {
+        edits.extend(new.iter().map(|item| Edit::Inserted(*item)));
+    } else if new.is_empty() {
+        edits.extend(old.iter().map(|item| Edit::Removed(*item)));
+    } else if old.len() == 1 {
+        match new.iter().position(|item| item.1 == old[0].1) {
+            Some(index) => {
+                edits.extend(new[..index].iter().map(|item| Edit::Inserted(*item)));
+                edits.push(Edit::Same);
+                edits.extend(new[index + 1..].iter().map(|item| Edit::Inserted(*item)));
+            }
+            None => {
+                edits.push(Edit::Removed(old[0]));
+                edits.extend(new.iter().map(|item| Edit::Inserted(*item)));
+            }
+        }
+    } else {
+        // splits `new` at the position where the LCS of the two halves of `old` is the longest.
+        let middle = old.len() / 2;
+        let forward = get_lcs_lengths(&old[..middle], new, false);
+        let backward = get_lcs_lengths(&old[middle..], new, true);
+        let split = (0..=new.len())
+            .max_by_key(|j| (forward[*j] + backward[new.len() - j], std::cmp::Reverse(*j)))
+            .unwrap();
+
+        append_edits(&old[..middle], &new[..split], edits);
+        append_edits(&old[middle..], &new[split..], edits);
+    }
+}
+
+fn decode_without_padding(code: &[u8]) -> Result<Vec<(usize, Instruction)>, BytecodeError> {
+    let instructions = BytecodeReader::new(code).collect::<Result<Vec<_>, _>>()?;
+    Ok(instructions
+        .iter()
+        .enumerate()
+        .filter(|(index, (offset, instruction))| {
+            !is_padding(*offset, instruction, instructions.get(index + 1))
+        })
+        .map(|(_, item)| *item)
+        .collect())
+}
+
+/// Converts a hunk (the consecutive removed and inserted instructions) into diff items.
+///
+/// A removed instruction is paired with the first inserted instruction with the same opcode.
+fn flush_hunk(
+    items: &mut Vec<DiffItem>,
+    removed: &mut Vec<(usize, Instruction)>,
+    inserted: &mut Vec<(usize, Instruction)>,
+) {
+    let (mut i, mut j) = (0, 0);
+
+    while i < removed.len() || j < inserted.len() {
+        match (removed.get(i), inserted.get(j)) {
+            (Some((old_offset, old_instruction)), Some((new_offset, new_instruction)))
+                if old_instruction.opcode == new_instruction.opcode =>
+            {
+                items.push(DiffItem::Changed {
+                    old_offset: *old_offset,
+                    new_offset: *new_offset,
+                    old_instruction: *old_instruction,
+                    new_instruction: *new_instruction,
+                });
+                i += 1;
+                j += 1;
+            }
+            // the removed instruction may be changed to a later inserted instruction.
+            (Some((_, old_instruction)), Some((new_offset, instruction)))
+                if inserted[j..]
+                    .iter()
+                    .any(|(_, item)| item.opcode == old_instruction.opcode) =>
+            {
+                items.push(DiffItem::Inserted {
+                    new_offset: *new_offset,
+                    instruction: *instruction,
+                });
+                j += 1;
+            }
+            (Some((old_offset, instruction)), _) => {
+                items.push(DiffItem::Removed {
+                    old_offset: *old_offset,
+                    instruction: *instruction,
+                });
+                i += 1;
+            }
+            (None, Some((new_offset, instruction))) => {
+                items.push(DiffItem::Inserted {
+                    new_offset: *new_offset,
+                    instruction: *instruction,
+                });
+                j += 1;
+            }
+            (None, None) => unreachable!(),
+        }
+    }
+
+    removed.clear();
+    inserted.clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        bytecode::{
+            diff::{diff_bytecode, DiffItem},
+            BytecodeWriter, Instruction, InstructionParams,
+        },
+        opcode::Opcode,
+    };
+
+    #[test]
+    fn test_diff_bytecode() {
+        let old_code = BytecodeWriter::new()
+            .append_opcode_i32(Opcode::imm_i32, 11)
+            .append_opcode_i32(Opcode::imm_i32, 13)
+            .append_opcode(Opcode::add_i32)
+            .append_opcode(Opcode::eqz_i32)
+            .append_opcode(Opcode::end)
+            .to_bytes();
+
+        // identical, ignoring padding.
+        assert_eq!(diff_bytecode(&old_code, &old_code).unwrap(), vec![]);
+
+        let new_code = BytecodeWriter::new()
+            .append_opcode_i32(Opcode::imm_i32, 11)
+            .append_opcode(Opcode::nop) // inserted, causes different padding
+            .append_opcode_i32(Opcode::imm_i32, 17) // changed
+            .append_opcode(Opcode::add_i32)
+            .append_opcode(Opcode::end) // `eqz_i32` removed
+            .to_bytes();
+
+        assert_eq!(
+            diff_bytecode(&old_code, &new_code).unwrap(),
+            vec![
+                DiffItem::Inserted {
+                    new_offset: 8,
+                    instruction: Instruction::new(Opcode::nop, InstructionParams::NoParams)
+                },
+                DiffItem::Changed {
+                    old_offset: 8,
+                    new_offset: 12,
+                    old_instruction: Instruction::new(Opcode::imm_i32, InstructionParams::I32(13)),
+                    new_instruction: Instruction::new(Opcode::imm_i32, InstructionParams::I32(17))
+                },
+                DiffItem::Removed {
+                    old_offset: 18,
+                    instruction: Instruction::new(Opcode::eqz_i32, InstructionParams::NoParams)
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_long_instructions() {
+        let code = |values: &[u32]| {
+            let mut writer = BytecodeWriter::new();
+            for value in values {
+                writer.append_opcode_i32(Opcode::imm_i32, *value);
+            }
+            writer.to_bytes()
+        };
+
+        // the first and the last instructions are changed, so the common
+        // prefix and suffix are empty.
+        let old_values = (0..3000).collect::<Vec<_>>();
+        let mut new_values = old_values.clone();
+        new_values[0] = 10000;
+        new_values[1500] = 10001;
+        new_values[2999] = 10002;
+
+        let changed = |index: usize, value: u32| DiffItem::Changed {
+            old_offset: index * 8,
+            new_offset: index * 8,
+            old_instruction: Instruction::new(
+                Opcode::imm_i32,
+                InstructionParams::I32(index as u32),
+            ),
+            new_instruction: Instruction::new(Opcode::imm_i32, InstructionParams::I32(value)),
+        };
+        assert_eq!(
+            diff_bytecode(&code(&old_values), &code(&new_values)).unwrap(),
+            vec![
+                changed(0, 10000),
+                changed(1500, 10001),
+                changed(2999, 10002)
+            ]
+        );
+    }
+}
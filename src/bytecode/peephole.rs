@@ -0,0 +1,360 @@
+// Copyright (c) 2025 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions.
+// For more details, see the LICENSE, LICENSE.additional, and CONTRIBUTING files.
+
+// Peephole Canonicalization
+// -------------------------
+//
+// A rule matches a short sequence of opcodes and rewrites it into a shorter
+// (or canonical) sequence, e.g.:
+//
+// ```text
+// imm_i32(3)
+// add_i32          ->  add_imm_i32(3)
+// ```
+//
+// The rules are shared by all backends, so the same source code produces
+// the same bytecode regardless of the backend.
+//
+// Note that the rules change the size of the code, they should be applied
+// to the instruction sequences BEFORE the branch offsets (e.g., the `next_inst_offset`
+// of `block_alt`) are computed.
+//
+// The parameter `imm:i16` of `add_imm_xxx` and `sub_imm_xxx` is only fused when
+// the number is in the range [0, 0x7fff], a negative number is fused into the
+// opposite instruction, e.g., `imm_i32(-3); add_i32` -> `sub_imm_i32(3)`.
+
+use crate::opcode::Opcode;
+
+use super::{join_imm_i64, Instruction, InstructionParams};
+
+pub struct PeepholeRule {
+    pub name: &'static str,
+
+    /// The opcodes of the instruction sequence to match.
+    pub pattern: &'static [Opcode],
+
+    /// Returns the replacement of the matched instructions, or `None` if
+    /// the parameters of the instructions are not applicable.
+    pub rewrite: fn(&[Instruction]) -> Option<Vec<Instruction>>,
+}
+
+/// The built-in canonicalization rules.
+pub const PEEPHOLE_RULES: &[PeepholeRule] = &[
+    PeepholeRule {
+        name: "remove_nop",
+        pattern: &[Opcode::nop],
+        rewrite: rewrite_remove,
+    },
+    PeepholeRule {
+        name: "remove_dup_drop",
+        pattern: &[Opcode::dup, Opcode::drop],
+        rewrite: rewrite_remove,
+    },
+    PeepholeRule {
+        name: "remove_imm_drop",
+        pattern: &[Opcode::imm_i32, Opcode::drop],
+        rewrite: rewrite_remove,
+    },
+    PeepholeRule {
+        name: "remove_imm_drop",
+        pattern: &[Opcode::imm_i64, Opcode::drop],
+        rewrite: rewrite_remove,
+    },
+    PeepholeRule {
+        name: "remove_imm_drop",
+        pattern: &[Opcode::imm_f32, Opcode::drop],
+        rewrite: rewrite_remove,
+    },
+    PeepholeRule {
+        name: "remove_imm_drop",
+        pattern: &[Opcode::imm_f64, Opcode::drop],
+        rewrite: rewrite_remove,
+    },
+    PeepholeRule {
+        name: "fuse_add_imm_i32",
+        pattern: &[Opcode::imm_i32, Opcode::add_i32],
+        rewrite: rewrite_fuse_imm_i32,
+    },
+    PeepholeRule {
+        name: "fuse_sub_imm_i32",
+        pattern: &[Opcode::imm_i32, Opcode::sub_i32],
+        rewrite: rewrite_fuse_imm_i32,
+    },
+    PeepholeRule {
+        name: "fuse_add_imm_i64",
+        pattern: &[Opcode::imm_i64, Opcode::add_i64],
+        rewrite: rewrite_fuse_imm_i64,
+    },
+    PeepholeRule {
+        name: "fuse_sub_imm_i64",
+        pattern: &[Opcode::imm_i64, Opcode::sub_i64],
+        rewrite: rewrite_fuse_imm_i64,
+    },
+    PeepholeRule {
+        name: "fuse_shift_left_imm_i32",
+        pattern: &[Opcode::imm_i32, Opcode::shift_left_i32],
+        rewrite: rewrite_fuse_shift_imm,
+    },
+    PeepholeRule {
+        name: "fuse_shift_left_imm_i64",
+        pattern: &[Opcode::imm_i32, Opcode::shift_left_i64],
+        rewrite: rewrite_fuse_shift_imm,
+    },
+    PeepholeRule {
+        name: "fuse_shift_right_imm_i32_s",
+        pattern: &[Opcode::imm_i32, Opcode::shift_right_i32_s],
+        rewrite: rewrite_fuse_shift_imm,
+    },
+    PeepholeRule {
+        name: "fuse_shift_right_imm_i64_s",
+        pattern: &[Opcode::imm_i32, Opcode::shift_right_i64_s],
+        rewrite: rewrite_fuse_shift_imm,
+    },
+    PeepholeRule {
+        name: "fuse_shift_right_imm_i32_u",
+        pattern: &[Opcode::imm_i32, Opcode::shift_right_i32_u],
+        rewrite: rewrite_fuse_shift_imm,
+    },
+    PeepholeRule {
+        name: "fuse_shift_right_imm_i64_u",
+        pattern: &[Opcode::imm_i32, Opcode::shift_right_i64_u],
+        rewrite: rewrite_fuse_shift_imm,
+    },
+    PeepholeRule {
+        name: "fuse_rotate_left_imm_i32",
+        pattern: &[Opcode::imm_i32, Opcode::rotate_left_i32],
+        rewrite: rewrite_fuse_shift_imm,
+    },
+    PeepholeRule {
+        name: "fuse_rotate_left_imm_i64",
+        pattern: &[Opcode::imm_i32, Opcode::rotate_left_i64],
+        rewrite: rewrite_fuse_shift_imm,
+    },
+    PeepholeRule {
+        name: "fuse_rotate_right_imm_i32",
+        pattern: &[Opcode::imm_i32, Opcode::rotate_right_i32],
+        rewrite: rewrite_fuse_shift_imm,
+    },
+    PeepholeRule {
+        name: "fuse_rotate_right_imm_i64",
+        pattern: &[Opcode::imm_i32, Opcode::rotate_right_i64],
+        rewrite: rewrite_fuse_shift_imm,
+    },
+    PeepholeRule {
+        name: "remove_zero_imm",
+        pattern: &[Opcode::add_imm_i32],
+        rewrite: rewrite_remove_zero_imm,
+    },
+    PeepholeRule {
+        name: "remove_zero_imm",
+        pattern: &[Opcode::sub_imm_i32],
+        rewrite: rewrite_remove_zero_imm,
+    },
+    PeepholeRule {
+        name: "remove_zero_imm",
+        pattern: &[Opcode::add_imm_i64],
+        rewrite: rewrite_remove_zero_imm,
+    },
+    PeepholeRule {
+        name: "remove_zero_imm",
+        pattern: &[Opcode::sub_imm_i64],
+        rewrite: rewrite_remove_zero_imm,
+    },
+];
+
+/// Applies the rules to the instruction sequence repeatedly until
+/// no more rules can be applied.
+///
+/// The rules are tried in order at each position, the first applicable rule wins.
+pub fn apply_peephole_rules(
+    instructions: &[Instruction],
+    rules: &[PeepholeRule],
+) -> Vec<Instruction> {
+    let mut current = instructions.to_vec();
+
+    loop {
+        let mut changed = false;
+        let mut output = Vec::with_capacity(current.len());
+        let mut position = 0;
+
+        'outer: while position < current.len() {
+            for rule in rules {
+                let length = rule.pattern.len();
+                let Some(window) = current.get(position..position + length) else {
+                    continue;
+                };
+
+                if window
+                    .iter()
+                    .zip(rule.pattern)
+                    .all(|(instruction, opcode)| instruction.opcode == *opcode)
+                {
+                    if let Some(replacement) = (rule.rewrite)(window) {
+                        output.extend(replacement);
+                        position += length;
+                        changed = true;
+                        continue 'outer;
+                    }
+                }
+            }
+
+            output.push(current[position]);
+            position += 1;
+        }
+
+        current = output;
+        if !changed {
+            return current;
+        }
+    }
+}
+
+fn rewrite_remove(_instructions: &[Instruction]) -> Option<Vec<Instruction>> {
+    Some(vec![])
+}
+
+fn rewrite_remove_zero_imm(instructions: &[Instruction]) -> Option<Vec<Instruction>> {
+    match instructions[0].params {
+        InstructionParams::I16(0) => Some(vec![]),
+        _ => None,
+    }
+}
+
+fn rewrite_fuse_imm_i32(instructions: &[Instruction]) -> Option<Vec<Instruction>> {
+    let InstructionParams::I32(value) = instructions[0].params else {
+        return None;
+    };
+
+    let (add, sub) = (Opcode::add_imm_i32, Opcode::sub_imm_i32);
+    fuse_imm(
+        value as i32 as i64,
+        instructions[1].opcode == Opcode::add_i32,
+        add,
+        sub,
+    )
+}
+
+fn rewrite_fuse_imm_i64(instructions: &[Instruction]) -> Option<Vec<Instruction>> {
+    let InstructionParams::I32I32(low, high) = instructions[0].params else {
+        return None;
+    };
+
+    let (add, sub) = (Opcode::add_imm_i64, Opcode::sub_imm_i64);
+    fuse_imm(
+        join_imm_i64(low, high) as i64,
+        instructions[1].opcode == Opcode::add_i64,
+        add,
+        sub,
+    )
+}
+
+fn rewrite_fuse_shift_imm(instructions: &[Instruction]) -> Option<Vec<Instruction>> {
+    let InstructionParams::I32(move_bits) = instructions[0].params else {
+        return None;
+    };
+
+    let opcode = match instructions[1].opcode {
+        Opcode::shift_left_i32 => Opcode::shift_left_imm_i32,
+        Opcode::shift_right_i32_s => Opcode::shift_right_imm_i32_s,
+        Opcode::shift_right_i32_u => Opcode::shift_right_imm_i32_u,
+        Opcode::rotate_left_i32 => Opcode::rotate_left_imm_i32,
+        Opcode::rotate_right_i32 => Opcode::rotate_right_imm_i32,
+        Opcode::shift_left_i64 => Opcode::shift_left_imm_i64,
+        Opcode::shift_right_i64_s => Opcode::shift_right_imm_i64_s,
+        Opcode::shift_right_i64_u => Opcode::shift_right_imm_i64_u,
+        Opcode::rotate_left_i64 => Opcode::rotate_left_imm_i64,
+        Opcode::rotate_right_i64 => Opcode::rotate_right_imm_i64,
+        _ => return None,
+    };
+
+    // the valid range of `move_bits` is [0, 64).
+    if move_bits >= 64 {
+        return None;
+    }
+
+    Some(vec![Instruction::new(
+        opcode,
+        InstructionParams::I16(move_bits as u16),
+    )])
+}
+
+fn fuse_imm(value: i64, is_add: bool, add: Opcode, sub: Opcode) -> Option<Vec<Instruction>> {
+    const MAX: i64 = i16::MAX as i64;
+    const MIN: i64 = -MAX;
+
+    // `x - (-n)` is `x + n`, and `x + (-n)` is `x - n`.
+    let (opcode, imm) = match (is_add, value) {
+        (true, 0..=MAX) => (add, value),
+        (true, MIN..=-1) => (sub, -value),
+        (false, 0..=MAX) => (sub, value),
+        (false, MIN..=-1) => (add, -value),
+        _ => return None,
+    };
+
+    Some(vec![Instruction::new(
+        opcode,
+        InstructionParams::I16(imm as u16),
+    )])
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        bytecode::{
+            decode_instructions,
+            peephole::{apply_peephole_rules, PEEPHOLE_RULES},
+            split_imm_i64, BytecodeWriter, Instruction, InstructionParams,
+        },
+        opcode::Opcode,
+    };
+
+    #[test]
+    fn test_apply_peephole_rules() {
+        let (low, high) = split_imm_i64(-5i64 as u64);
+        let code = BytecodeWriter::new()
+            .append_opcode_i16_i32(Opcode::local_load_i32_u, 0, 0)
+            .append_opcode_i32(Opcode::imm_i32, 3)
+            .append_opcode(Opcode::add_i32)
+            .append_opcode_i32(Opcode::imm_i32, -7i32 as u32)
+            .append_opcode(Opcode::sub_i32)
+            .append_opcode_i32(Opcode::imm_i32, 0x1_0000) // too large to fuse
+            .append_opcode(Opcode::add_i32)
+            .append_opcode_i32(Opcode::imm_i32, 0)
+            .append_opcode(Opcode::add_i32) // removed
+            .append_opcode_i32(Opcode::imm_f32, 0)
+            .append_opcode(Opcode::drop) // removed
+            .append_opcode_i32(Opcode::imm_i32, 4)
+            .append_opcode(Opcode::shift_right_i32_u)
+            .append_opcode_i16_i32(Opcode::local_load_i64, 0, 1)
+            .append_opcode_i32_i32(Opcode::imm_i64, low, high)
+            .append_opcode(Opcode::add_i64)
+            .append_opcode(Opcode::end)
+            .to_bytes();
+
+        let instructions = decode_instructions(&code)
+            .unwrap()
+            .into_iter()
+            .map(|(_, instruction)| instruction)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            apply_peephole_rules(&instructions, PEEPHOLE_RULES),
+            vec![
+                Instruction::new(Opcode::local_load_i32_u, InstructionParams::I16I32(0, 0)),
+                Instruction::new(Opcode::add_imm_i32, InstructionParams::I16(3)),
+                Instruction::new(Opcode::add_imm_i32, InstructionParams::I16(7)),
+                Instruction::new(Opcode::imm_i32, InstructionParams::I32(0x1_0000)),
+                Instruction::new(Opcode::add_i32, InstructionParams::NoParams),
+                Instruction::new(Opcode::shift_right_imm_i32_u, InstructionParams::I16(4)),
+                Instruction::new(Opcode::local_load_i64, InstructionParams::I16I32(0, 1)),
+                Instruction::new(Opcode::sub_imm_i64, InstructionParams::I16(5)),
+                Instruction::new(Opcode::end, InstructionParams::NoParams),
+            ]
+        );
+    }
+}
@@ -0,0 +1,215 @@
+// Copyright (c) 2025 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions.
+// For more details, see the LICENSE, LICENSE.additional, and CONTRIBUTING files.
+
+// Execution Profiles
+// ------------------
+//
+// A profile is a named subset of the instruction set. Embedders that run
+// untrusted modules (e.g., plugins, smart contracts and scripts) can restrict
+// the ISA surface by checking the bytecode against a profile before loading it.
+//
+// - `Full`: all instructions.
+// - `Deterministic`: excludes the instructions whose result depends on the host,
+//   i.e., `syscall`, `extcall`, `host_addr_*` and `host_copy_*`, and the instructions
+//   whose result depends on the thread scheduling and the wall clock, i.e.,
+//   `memory_wait_i32`, `memory_wait_i64` and `memory_notify`.
+// - `Pure`: excludes the instructions of `Deterministic`, and the memory
+//   instructions (`memory_*` and `atomic_*`).
+//
+// Note that `envcall` is allowed by all profiles, the environment calls are
+// expected to be restricted by the runtime itself.
+
+use std::fmt::Display;
+
+use crate::opcode::{Opcode, OpcodeCategory};
+
+use super::{BytecodeError, BytecodeReader};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Profile {
+    Full,
+    Deterministic,
+    Pure,
+}
+
+/// An instruction which is not allowed by the profile.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ProfileViolation {
+    pub offset: usize,
+    pub opcode: Opcode,
+}
+
+impl Profile {
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Profile::Full => "full",
+            Profile::Deterministic => "deterministic",
+            Profile::Pure => "pure",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "full" => Some(Profile::Full),
+            "deterministic" => Some(Profile::Deterministic),
+            "pure" => Some(Profile::Pure),
+            _ => None,
+        }
+    }
+
+    /// Checks whether the opcode is allowed by the profile.
+    pub fn allows(&self, opcode: Opcode) -> bool {
+        match self {
+            Profile::Full => true,
+            Profile::Deterministic => !is_nondeterministic(opcode),
+            Profile::Pure => {
+                !is_nondeterministic(opcode)
+                    && !matches!(
+                        opcode.get_category(),
+                        OpcodeCategory::Memory | OpcodeCategory::Atomic
+                    )
+            }
+        }
+    }
+
+    /// Scans the bytecode of a function and returns all instructions
+    /// which are not allowed by the profile.
+    pub fn check(&self, code: &[u8]) -> Result<Vec<ProfileViolation>, BytecodeError> {
+        let mut violations = vec![];
+        for item in BytecodeReader::new(code) {
+            let (offset, instruction) = item?;
+            if !self.allows(instruction.opcode) {
+                violations.push(ProfileViolation {
+                    offset,
+                    opcode: instruction.opcode,
+                });
+            }
+        }
+        Ok(violations)
+    }
+}
+
+fn is_nondeterministic(opcode: Opcode) -> bool {
+    matches!(
+        opcode,
+        Opcode::syscall
+            | Opcode::extcall
+            | Opcode::host_addr_function
+            | Opcode::host_addr_function_dynamic
+            | Opcode::host_addr_data
+            | Opcode::host_addr_data_extend
+            | Opcode::host_addr_data_dynamic
+            | Opcode::host_copy_to_data
+            | Opcode::host_copy_from_data
+            | Opcode::memory_wait_i32
+            | Opcode::memory_wait_i64
+            | Opcode::memory_notify
+    )
+}
+
+impl Display for Profile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.get_name())
+    }
+}
+
+impl Display for ProfileViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Instruction \"{}\" at offset {} is not allowed.",
+            self.opcode.get_name(),
+            self.offset
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        bytecode::{
+            profile::{Profile, ProfileViolation},
+            BytecodeWriter,
+        },
+        opcode::Opcode,
+    };
+
+    #[test]
+    fn test_check_profile() {
+        let code = BytecodeWriter::new()
+            .append_opcode_i32(Opcode::imm_i32, 0x10)
+            .append_opcode(Opcode::memory_allocate)
+            .append_opcode_i16_i32(Opcode::host_addr_data, 0, 1)
+            .append_opcode_i32(Opcode::extcall, 2)
+            .append_opcode_i32(Opcode::envcall, 3)
+            .append_opcode(Opcode::end)
+            .to_bytes();
+
+        assert_eq!(Profile::Full.check(&code).unwrap(), vec![]);
+
+        assert_eq!(
+            Profile::Deterministic.check(&code).unwrap(),
+            vec![
+                ProfileViolation {
+                    offset: 12,
+                    opcode: Opcode::host_addr_data
+                },
+                ProfileViolation {
+                    offset: 20,
+                    opcode: Opcode::extcall
+                },
+            ]
+        );
+
+        assert_eq!(
+            Profile::Pure.check(&code).unwrap(),
+            vec![
+                ProfileViolation {
+                    offset: 8,
+                    opcode: Opcode::memory_allocate
+                },
+                ProfileViolation {
+                    offset: 12,
+                    opcode: Opcode::host_addr_data
+                },
+                ProfileViolation {
+                    offset: 20,
+                    opcode: Opcode::extcall
+                },
+            ]
+        );
+
+        let code = BytecodeWriter::new()
+            .append_opcode(Opcode::memory_wait_i32)
+            .append_opcode(Opcode::memory_wait_i64)
+            .append_opcode(Opcode::memory_notify)
+            .append_opcode(Opcode::end)
+            .to_bytes();
+
+        assert_eq!(Profile::Full.check(&code).unwrap(), vec![]);
+        for profile in [Profile::Deterministic, Profile::Pure] {
+            assert_eq!(
+                profile.check(&code).unwrap(),
+                vec![
+                    ProfileViolation {
+                        offset: 0,
+                        opcode: Opcode::memory_wait_i32
+                    },
+                    ProfileViolation {
+                        offset: 2,
+                        opcode: Opcode::memory_wait_i64
+                    },
+                    ProfileViolation {
+                        offset: 4,
+                        opcode: Opcode::memory_notify
+                    },
+                ]
+            );
+        }
+    }
+}
@@ -0,0 +1,289 @@
+// Copyright (c) 2025 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions.
+// For more details, see the LICENSE, LICENSE.additional, and CONTRIBUTING files.
+
+// Bytecode Statistics
+// -------------------
+//
+// The statistics are used to evaluate the quality of the code generated by compilers, e.g.:
+//
+// - the number of instructions of each category,
+// - the total size of bytecode,
+// - the overhead of `nop` instructions which are inserted for alignment,
+// - the number of frequently used constructs, such as blocks, branches, loops and calls.
+//
+// Statistics of multiple functions can be merged into the statistics of a whole module.
+
+use std::collections::BTreeMap;
+
+use crate::opcode::{Opcode, OpcodeCategory};
+
+use super::{is_padding, BytecodeError, BytecodeReader};
+
+/// The number of frequently used constructs.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct ConstructCounts {
+    /// Instructions: `block`.
+    pub blocks: usize,
+
+    /// Instructions: `block_alt` and `block_nez`.
+    pub branches: usize,
+
+    /// Instructions: `recur` and `recur_nez`.
+    pub loops: usize,
+
+    /// Instructions: `break`, `break_nez`, `break_alt` and `break_table`.
+    pub breaks: usize,
+
+    /// Instructions: `call`, `call_dynamic`, `call_indirect`, `closure_call` and the tail calls.
+    pub calls: usize,
+
+    /// Instructions: `envcall`, `syscall` and `extcall`.
+    pub host_calls: usize,
+
+    /// Instructions: `imm_xxx` (including `imm_i32_small`) and `push_zero`.
+    pub immediates: usize,
+
+    /// Instructions: `local_load_xxx` and `local_store_xxx`.
+    pub local_accesses: usize,
+
+    /// Instructions: `data_load_xxx` and `data_store_xxx`.
+    pub data_accesses: usize,
+}
+
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct BytecodeStatistics {
+    /// The number of instructions, including `nop`.
+    pub instruction_count: usize,
+
+    /// The total size of the bytecode.
+    pub total_size_in_bytes: usize,
+
+    /// The number of all `nop` instructions, including the padding instructions.
+    pub nop_count: usize,
+
+    /// The number of `nop` instructions inserted for 4-byte alignment, i.e.,
+    /// the `nop` which is located at the non-aligned position and followed by
+    /// an instruction that requires alignment, see `bytecode::is_padding`.
+    pub padding_count: usize,
+
+    pub category_histogram: BTreeMap<OpcodeCategory, usize>,
+    pub opcode_histogram: BTreeMap<Opcode, usize>,
+    pub constructs: ConstructCounts,
+}
+
+impl BytecodeStatistics {
+    /// Analyzes the bytecode of a function.
+    pub fn analyze(code: &[u8]) -> Result<Self, BytecodeError> {
+        let mut statistics = BytecodeStatistics::default();
+        let mut reader = BytecodeReader::new(code).peekable();
+
+        while let Some(item) = reader.next() {
+            let (offset, instruction) = item?;
+            let opcode = instruction.opcode;
+
+            statistics.instruction_count += 1;
+            statistics.total_size_in_bytes = offset + instruction.get_length_in_bytes();
+            *statistics
+                .category_histogram
+                .entry(opcode.get_category())
+                .or_default() += 1;
+            *statistics.opcode_histogram.entry(opcode).or_default() += 1;
+
+            if opcode == Opcode::nop {
+                statistics.nop_count += 1;
+
+                // the decoding error of the next instruction is returned by the next iteration.
+                let next = match reader.peek() {
+                    Some(Ok(next)) => Some(next),
+                    _ => None,
+                };
+                if is_padding(offset, &instruction, next) {
+                    statistics.padding_count += 1;
+                }
+                continue;
+            }
+
+            statistics.constructs.count(opcode);
+        }
+
+        Ok(statistics)
+    }
+
+    /// Analyzes the bytecode of all functions of a module.
+    pub fn analyze_module<'a>(
+        functions: impl IntoIterator<Item = &'a [u8]>,
+    ) -> Result<Self, BytecodeError> {
+        let mut statistics = BytecodeStatistics::default();
+        for code in functions {
+            statistics.merge(&BytecodeStatistics::analyze(code)?);
+        }
+        Ok(statistics)
+    }
+
+    pub fn merge(&mut self, other: &BytecodeStatistics) {
+        self.instruction_count += other.instruction_count;
+        self.total_size_in_bytes += other.total_size_in_bytes;
+        self.nop_count += other.nop_count;
+        self.padding_count += other.padding_count;
+
+        for (category, count) in &other.category_histogram {
+            *self.category_histogram.entry(*category).or_default() += count;
+        }
+
+        for (opcode, count) in &other.opcode_histogram {
+            *self.opcode_histogram.entry(*opcode).or_default() += count;
+        }
+
+        self.constructs.merge(&other.constructs);
+    }
+
+    /// The size of the padding `nop` instructions.
+    pub fn get_padding_size_in_bytes(&self) -> usize {
+        self.padding_count * Opcode::nop.get_format().get_length_in_bytes()
+    }
+
+    /// The ratio of the padding size to the total size, in the range [0, 1].
+    pub fn get_padding_overhead(&self) -> f64 {
+        if self.total_size_in_bytes == 0 {
+            0.0
+        } else {
+            self.get_padding_size_in_bytes() as f64 / self.total_size_in_bytes as f64
+        }
+    }
+}
+
+impl ConstructCounts {
+    fn count(&mut self, opcode: Opcode) {
+        match opcode {
+            Opcode::block => self.blocks += 1,
+            Opcode::block_alt | Opcode::block_nez => self.branches += 1,
+            Opcode::recur | Opcode::recur_nez => self.loops += 1,
+            Opcode::break_ | Opcode::break_nez | Opcode::break_alt | Opcode::break_table => {
+                self.breaks += 1
+            }
+            Opcode::call
+            | Opcode::call_dynamic
+            | Opcode::call_indirect
+            | Opcode::call_tail
+            | Opcode::call_dynamic_tail
+            | Opcode::closure_call => self.calls += 1,
+            Opcode::envcall | Opcode::syscall | Opcode::extcall => self.host_calls += 1,
+            Opcode::imm_i32
+            | Opcode::imm_i64
+            | Opcode::imm_f32
+            | Opcode::imm_f64
+            | Opcode::imm_i32_small
+            | Opcode::push_zero => self.immediates += 1,
+            _ => match opcode.get_category() {
+                OpcodeCategory::LocalVariable => self.local_accesses += 1,
+                OpcodeCategory::Data => self.data_accesses += 1,
+                _ => {}
+            },
+        }
+    }
+
+    fn merge(&mut self, other: &ConstructCounts) {
+        self.blocks += other.blocks;
+        self.branches += other.branches;
+        self.loops += other.loops;
+        self.breaks += other.breaks;
+        self.calls += other.calls;
+        self.host_calls += other.host_calls;
+        self.immediates += other.immediates;
+        self.local_accesses += other.local_accesses;
+        self.data_accesses += other.data_accesses;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        bytecode::{statistics::BytecodeStatistics, BytecodeWriter},
+        opcode::{Opcode, OpcodeCategory},
+    };
+
+    #[test]
+    fn test_analyze() {
+        let code = BytecodeWriter::new()
+            .append_opcode_i16_i32(Opcode::local_load_i32_s, 0, 0)
+            .append_opcode_i16(Opcode::add_imm_i32, 1)
+            .append_opcode(Opcode::add_i32)
+            .append_opcode_i32(Opcode::imm_i32, 11) // a padding `nop` is inserted before
+            .append_opcode(Opcode::add_i32)
+            .append_opcode_i32_i32(Opcode::block_nez, 0, 20) // a padding `nop` is inserted before
+            .append_opcode_i32(Opcode::call, 0)
+            .append_opcode(Opcode::nop)
+            .append_opcode(Opcode::end)
+            .append_opcode(Opcode::end)
+            .to_bytes();
+
+        let statistics = BytecodeStatistics::analyze(&code).unwrap();
+        assert_eq!(statistics.instruction_count, 12);
+        assert_eq!(statistics.total_size_in_bytes, code.len());
+        assert_eq!(statistics.nop_count, 3);
+        assert_eq!(statistics.padding_count, 2);
+        assert_eq!(statistics.get_padding_size_in_bytes(), 4);
+
+        assert_eq!(
+            statistics.category_histogram[&OpcodeCategory::Fundamental],
+            4
+        );
+        assert_eq!(
+            statistics.category_histogram[&OpcodeCategory::Arithmetic],
+            3
+        );
+        assert_eq!(
+            statistics.category_histogram[&OpcodeCategory::ControlFlow],
+            3
+        );
+        assert_eq!(statistics.opcode_histogram[&Opcode::end], 2);
+
+        assert_eq!(statistics.constructs.branches, 1);
+        assert_eq!(statistics.constructs.calls, 1);
+        assert_eq!(statistics.constructs.immediates, 1);
+        assert_eq!(statistics.constructs.local_accesses, 1);
+
+        let module_statistics =
+            BytecodeStatistics::analyze_module([code.as_slice(), code.as_slice()]).unwrap();
+        assert_eq!(module_statistics.instruction_count, 24);
+        assert_eq!(module_statistics.total_size_in_bytes, code.len() * 2);
+        assert_eq!(module_statistics.padding_count, 4);
+        assert_eq!(module_statistics.opcode_histogram[&Opcode::end], 4);
+        assert_eq!(module_statistics.constructs.calls, 2);
+        assert_eq!(
+            module_statistics.get_padding_overhead(),
+            statistics.get_padding_overhead()
+        );
+    }
+
+    #[test]
+    fn test_count_immediates() {
+        let code = BytecodeWriter::new()
+            .append_opcode_i32(Opcode::imm_i32, 11)
+            .append_opcode_i16(Opcode::imm_i32_small, 13)
+            .append_opcode(Opcode::push_zero)
+            .append_opcode(Opcode::end)
+            .to_bytes();
+
+        let statistics = BytecodeStatistics::analyze(&code).unwrap();
+        assert_eq!(statistics.constructs.immediates, 3);
+    }
+
+    #[test]
+    fn test_count_breaks() {
+        let code = BytecodeWriter::new()
+            .append_opcode_i32(Opcode::break_table, 1)
+            .append_opcode_i16_i32(Opcode::break_, 0, 16)
+            .append_opcode_i16_i32(Opcode::break_, 0, 8)
+            .append_opcode(Opcode::end)
+            .to_bytes();
+
+        let statistics = BytecodeStatistics::analyze(&code).unwrap();
+        assert_eq!(statistics.constructs.breaks, 3);
+    }
+}
@@ -0,0 +1,343 @@
+// Copyright (c) 2025 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions.
+// For more details, see the LICENSE, LICENSE.additional, and CONTRIBUTING files.
+
+// Module Cache
+// ------------
+//
+// The fetched modules (i.e., the remote modules and the shared modules) are
+// stored in the cache directory, and each of them is described by a `CachedModule`
+// in the cache index, e.g.:
+//
+// ```ason
+// [
+//     {
+//         name: "sha2"
+//         version: Option::Some("1.2.3")
+//         origin: module::share({
+//             version: "1.2"
+//         })
+//         source_hash: "sha256:e3b0c442..."
+//         fetched_at: 1735689600
+//         verified: true
+//     }
+// ]
+// ```
+//
+// Layout
+// ------
+//
+// The paths are relative to the cache directory:
+//
+// - Shared modules: "share/MODULE_NAME/VERSION", e.g. "share/sha2/1.2.3".
+// - Remote modules: "remote/MODULE_NAME/ID", the ID is the first 16 hexadecimal
+//   digits of the SHA-256 checksum of the URL, the Git reference and the directory,
+//   so the different sources of the same module do not overwrite each other.
+//
+// Offline Mode
+// ------------
+//
+// In the offline mode the resolver consults only the cached modules, see `OfflineProvider`:
+//
+// - The available versions of a shared module are the cached versions.
+// - A remote or shared module which is not cached is not found.
+// - The modules which are not fetched (e.g., local and vendored modules)
+//   are provided as usual.
+// - The modules which are not verified (i.e., the source hash has not been
+//   checked against the checksum or the signature) are ignored.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    checksum::{encode_hex, Checksum, ChecksumAlgorithm},
+    resolver::{ModuleManifest, ModuleProvider},
+    signing::IndexEntry,
+    DependencyRemote, EffectiveVersion, ModuleDependency,
+};
+
+pub const CACHE_SHARE_DIRECTORY: &str = "share";
+pub const CACHE_REMOTE_DIRECTORY: &str = "remote";
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename = "cached_module")]
+pub struct CachedModule {
+    pub name: String,
+
+    /// The version of the shared module, or `None` for the remote module.
+    pub version: Option<EffectiveVersion>,
+
+    /// The dependency from which the module was fetched.
+    pub origin: ModuleDependency,
+
+    /// The checksum of the fetched module source.
+    pub source_hash: Checksum,
+
+    /// The fetch time, in seconds since the Unix epoch.
+    pub fetched_at: u64,
+
+    /// Whether the source hash has been checked against the checksum
+    /// (or the signature) of the dependency.
+    pub verified: bool,
+}
+
+/// It is serialized as a list of `CachedModule`.
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct CacheIndex {
+    pub modules: Vec<CachedModule>,
+}
+
+fn get_remote_id(remote: &DependencyRemote) -> String {
+    let text = format!(
+        "{}\n{}\n{}",
+        remote.url,
+        remote.reference,
+        remote.dir.as_deref().unwrap_or_default()
+    );
+
+    encode_hex(&Checksum::compute(ChecksumAlgorithm::Sha256, text.as_bytes()).get_digest()[..8])
+}
+
+/// Returns the path of the cached module relative to the cache directory,
+/// see the section "Layout".
+/// Returns `None` if the module is not fetched (e.g., local modules), or the
+/// version of the shared module is absent.
+pub fn get_cache_path(
+    module_name: &str,
+    origin: &ModuleDependency,
+    version: Option<&EffectiveVersion>,
+) -> Option<String> {
+    match (origin, version) {
+        (ModuleDependency::Share(_), Some(version)) => Some(format!(
+            "{}/{}/{}",
+            CACHE_SHARE_DIRECTORY, module_name, version
+        )),
+        (ModuleDependency::Remote(remote), _) => Some(format!(
+            "{}/{}/{}",
+            CACHE_REMOTE_DIRECTORY,
+            module_name,
+            get_remote_id(remote)
+        )),
+        _ => None,
+    }
+}
+
+impl CachedModule {
+    pub fn get_path(&self) -> Option<String> {
+        get_cache_path(&self.name, &self.origin, self.version.as_ref())
+    }
+}
+
+impl CacheIndex {
+    /// Finds the cached module, the version is used for shared modules only.
+    pub fn find(
+        &self,
+        module_name: &str,
+        source: &ModuleDependency,
+        version: Option<&EffectiveVersion>,
+    ) -> Option<&CachedModule> {
+        self.modules.iter().find(|item| {
+            item.name == module_name
+                && item.origin.is_same_source(source)
+                && (!matches!(source, ModuleDependency::Share(_))
+                    || item.version.as_ref() == version)
+        })
+    }
+
+    /// Returns the cached versions of the shared module in ascending order.
+    pub fn get_cached_versions(&self, module_name: &str) -> Vec<EffectiveVersion> {
+        let mut versions = self
+            .modules
+            .iter()
+            .filter(|item| {
+                item.name == module_name && matches!(item.origin, ModuleDependency::Share(_))
+            })
+            .filter_map(|item| item.version)
+            .collect::<Vec<_>>();
+        versions.sort();
+        versions.dedup();
+        versions
+    }
+
+    /// Returns a cache index containing only the verified modules.
+    pub fn get_verified(&self) -> CacheIndex {
+        CacheIndex {
+            modules: self
+                .modules
+                .iter()
+                .filter(|item| item.verified)
+                .cloned()
+                .collect(),
+        }
+    }
+}
+
+/// Wraps a provider for the offline mode, see the section "Offline Mode".
+pub struct OfflineProvider<'a, P: ModuleProvider> {
+    cache: CacheIndex,
+    provider: &'a P,
+}
+
+impl<'a, P: ModuleProvider> OfflineProvider<'a, P> {
+    pub fn new(cache: &CacheIndex, provider: &'a P) -> Self {
+        Self {
+            cache: cache.get_verified(),
+            provider,
+        }
+    }
+}
+
+impl<P: ModuleProvider> ModuleProvider for OfflineProvider<'_, P> {
+    fn get_available_versions(&self, module_name: &str) -> Vec<EffectiveVersion> {
+        self.cache.get_cached_versions(module_name)
+    }
+
+    fn get_manifest(
+        &self,
+        module_name: &str,
+        source: &ModuleDependency,
+        version: Option<&EffectiveVersion>,
+    ) -> Option<ModuleManifest> {
+        match source {
+            ModuleDependency::Share(_) | ModuleDependency::Remote(_) => {
+                self.cache.find(module_name, source, version)?;
+            }
+            _ => {}
+        }
+
+        self.provider.get_manifest(module_name, source, version)
+    }
+
+    fn get_index_entry(&self, module_name: &str, version: &EffectiveVersion) -> Option<IndexEntry> {
+        self.provider.get_index_entry(module_name, version)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        cache::{get_cache_path, CacheIndex, CachedModule, OfflineProvider},
+        checksum::{Checksum, ChecksumAlgorithm},
+        resolver::{ModuleManifest, ModuleProvider},
+        sbom::ModuleMetadata,
+        DependencyCondition, DependencyKind, DependencyRemote, DependencyShare, EffectiveVersion,
+        FeatureActivation, GitReference, ModuleDependency,
+    };
+
+    fn share() -> ModuleDependency {
+        ModuleDependency::Share(Box::new(DependencyShare {
+            version: "1.2".parse().unwrap(),
+            checksum: None,
+            parameters: HashMap::new(),
+            condition: DependencyCondition::True,
+            kind: DependencyKind::Normal,
+            optional: false,
+        }))
+    }
+
+    fn remote(tag: &str) -> ModuleDependency {
+        ModuleDependency::Remote(Box::new(DependencyRemote {
+            url: "https://github.com/foo/sha2.git".to_owned(),
+            reference: GitReference::Tag(tag.to_owned()),
+            dir: None,
+            checksum: None,
+            parameters: HashMap::new(),
+            condition: DependencyCondition::True,
+            kind: DependencyKind::Normal,
+            optional: false,
+        }))
+    }
+
+    fn cached(version: Option<&str>, origin: ModuleDependency, verified: bool) -> CachedModule {
+        CachedModule {
+            name: "sha2".to_owned(),
+            version: version.map(|item| EffectiveVersion::from_version_string(item).unwrap()),
+            origin,
+            source_hash: Checksum::compute(ChecksumAlgorithm::Sha256, b""),
+            fetched_at: 1735689600,
+            verified,
+        }
+    }
+
+    struct Provider;
+
+    impl ModuleProvider for Provider {
+        fn get_available_versions(&self, _module_name: &str) -> Vec<EffectiveVersion> {
+            unreachable!()
+        }
+
+        fn get_manifest(
+            &self,
+            module_name: &str,
+            _source: &ModuleDependency,
+            _version: Option<&EffectiveVersion>,
+        ) -> Option<ModuleManifest> {
+            Some(ModuleManifest {
+                name: module_name.to_owned(),
+                properties: HashMap::new(),
+                dependencies: HashMap::new(),
+                features: FeatureActivation::default(),
+                metadata: ModuleMetadata::default(),
+            })
+        }
+    }
+
+    #[test]
+    fn test_cache_index() {
+        let v120 = EffectiveVersion::from_version_string("1.2.0").unwrap();
+        let v123 = EffectiveVersion::from_version_string("1.2.3").unwrap();
+        let v124 = EffectiveVersion::from_version_string("1.2.4").unwrap();
+
+        let index = CacheIndex {
+            modules: vec![
+                cached(Some("1.2.3"), share(), true),
+                cached(Some("1.2.0"), share(), true),
+                cached(Some("1.2.4"), share(), false),
+                cached(None, remote("v1.0.0"), true),
+            ],
+        };
+
+        assert_eq!(index.get_cached_versions("sha2"), vec![v120, v123, v124]);
+        assert!(index.find("sha2", &share(), Some(&v123)).is_some());
+        assert!(index.find("md5", &share(), Some(&v123)).is_none());
+        assert!(index.find("sha2", &remote("v1.0.0"), None).is_some());
+        assert!(index.find("sha2", &remote("v2.0.0"), None).is_none());
+
+        assert_eq!(
+            get_cache_path("sha2", &share(), Some(&v123)),
+            Some("share/sha2/1.2.3".to_owned())
+        );
+        let remote_path = index.modules[3].get_path().unwrap();
+        assert!(remote_path.starts_with("remote/sha2/"));
+        assert_eq!(remote_path.len(), "remote/sha2/".len() + 16);
+        assert_ne!(
+            get_cache_path("sha2", &remote("v2.0.0"), None),
+            Some(remote_path)
+        );
+        assert_eq!(
+            get_cache_path("sha2", &ModuleDependency::Runtime, None),
+            None
+        );
+
+        let provider = OfflineProvider::new(&index, &Provider);
+        assert_eq!(provider.get_available_versions("sha2"), vec![v120, v123]);
+        assert!(provider
+            .get_manifest("sha2", &share(), Some(&v123))
+            .is_some());
+        assert!(provider
+            .get_manifest("sha2", &share(), Some(&v124))
+            .is_none());
+        assert!(provider
+            .get_manifest("sha2", &remote("v2.0.0"), None)
+            .is_none());
+        assert!(provider
+            .get_manifest("app", &ModuleDependency::Current, None)
+            .is_some());
+    }
+}
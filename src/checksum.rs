@@ -0,0 +1,304 @@
+// Copyright (c) 2025 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions.
+// For more details, see the LICENSE, LICENSE.additional, and CONTRIBUTING files.
+
+// Checksum
+// --------
+//
+// The content checksum of a dependency (e.g., the archive of the module source),
+// declared in the manifest or lockfile, is used to check the integrity
+// of the downloaded sources.
+//
+// A checksum is written as "ALGORITHM:DIGEST", where the digest is a lowercase
+// hexadecimal string, e.g. "sha256:e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855".
+//
+// Currently only SHA-256 is supported.
+
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum ChecksumAlgorithm {
+    Sha256,
+}
+
+/// It is serialized as a string, e.g. "sha256:e3b0c442...".
+///
+/// The length of the digest always matches the algorithm, use `Checksum::new`
+/// to create a checksum from a digest.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub struct Checksum {
+    algorithm: ChecksumAlgorithm,
+    digest: Vec<u8>,
+}
+
+impl ChecksumAlgorithm {
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            ChecksumAlgorithm::Sha256 => "sha256",
+        }
+    }
+
+    /// Returns `None` if the name is unknown.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "sha256" => Some(ChecksumAlgorithm::Sha256),
+            _ => None,
+        }
+    }
+
+    pub fn get_digest_length_in_bytes(&self) -> usize {
+        match self {
+            ChecksumAlgorithm::Sha256 => 32,
+        }
+    }
+}
+
+impl Checksum {
+    /// Returns an error if the length of the digest does not match the algorithm.
+    pub fn new(algorithm: ChecksumAlgorithm, digest: &[u8]) -> Result<Self, ChecksumError> {
+        if digest.len() != algorithm.get_digest_length_in_bytes() {
+            return Err(ChecksumError::InvalidDigestLength {
+                algorithm,
+                length: digest.len(),
+            });
+        }
+
+        Ok(Self {
+            algorithm,
+            digest: digest.to_vec(),
+        })
+    }
+
+    pub fn get_algorithm(&self) -> ChecksumAlgorithm {
+        self.algorithm
+    }
+
+    pub fn get_digest(&self) -> &[u8] {
+        &self.digest
+    }
+
+    /// Computes the checksum of the data.
+    pub fn compute(algorithm: ChecksumAlgorithm, data: &[u8]) -> Self {
+        let digest = match algorithm {
+            ChecksumAlgorithm::Sha256 => Sha256::digest(data).to_vec(),
+        };
+        Self { algorithm, digest }
+    }
+
+    /// Checks whether the data matches the checksum.
+    pub fn verify(&self, data: &[u8]) -> Result<(), ChecksumError> {
+        let actual = Checksum::compute(self.algorithm, data);
+        if actual == *self {
+            Ok(())
+        } else {
+            Err(ChecksumError::Mismatch {
+                expected: self.clone(),
+                actual,
+            })
+        }
+    }
+
+    pub fn from_checksum_string(checksum: &str) -> Result<Self, ChecksumError> {
+        let malformed = || ChecksumError::Malformed {
+            checksum: checksum.to_owned(),
+        };
+
+        let (name, hex) = checksum.split_once(':').ok_or_else(malformed)?;
+
+        let algorithm = ChecksumAlgorithm::from_name(name).ok_or_else(|| {
+            ChecksumError::UnsupportedAlgorithm {
+                algorithm: name.to_owned(),
+            }
+        })?;
+
+        match decode_hex(hex) {
+            Some(digest) if digest.len() == algorithm.get_digest_length_in_bytes() => {
+                Ok(Self { algorithm, digest })
+            }
+            _ => Err(malformed()),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ChecksumError {
+    /// The checksum string is not in the format "ALGORITHM:DIGEST",
+    /// or the length of the digest does not match the algorithm.
+    Malformed {
+        checksum: String,
+    },
+
+    UnsupportedAlgorithm {
+        algorithm: String,
+    },
+
+    /// The length (in bytes) of the digest does not match the algorithm.
+    InvalidDigestLength {
+        algorithm: ChecksumAlgorithm,
+        length: usize,
+    },
+
+    Mismatch {
+        expected: Checksum,
+        actual: Checksum,
+    },
+}
+
+impl Display for ChecksumError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChecksumError::Malformed { checksum } => {
+                write!(f, "Malformed checksum \"{}\".", checksum)
+            }
+            ChecksumError::UnsupportedAlgorithm { algorithm } => {
+                write!(f, "Unsupported checksum algorithm \"{}\".", algorithm)
+            }
+            ChecksumError::InvalidDigestLength { algorithm, length } => write!(
+                f,
+                "The digest of {} must be {} bytes, but found {} bytes.",
+                algorithm.get_name(),
+                algorithm.get_digest_length_in_bytes(),
+                length
+            ),
+            ChecksumError::Mismatch { expected, actual } => write!(
+                f,
+                "Checksum mismatch, expected \"{}\", actual \"{}\".",
+                expected, actual
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ChecksumError {}
+
+impl From<Checksum> for String {
+    fn from(value: Checksum) -> Self {
+        value.to_string()
+    }
+}
+
+impl TryFrom<String> for Checksum {
+    type Error = ChecksumError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Checksum::from_checksum_string(&value)
+    }
+}
+
+impl Display for Checksum {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}:{}",
+            self.algorithm.get_name(),
+            encode_hex(&self.digest)
+        )
+    }
+}
+
+/// Encodes the bytes as a lowercase hexadecimal string.
+pub(crate) fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Decodes the hexadecimal string (either case),
+/// returns `None` if it contains non-hexadecimal characters or its length is odd.
+pub(crate) fn decode_hex(text: &str) -> Option<Vec<u8>> {
+    if !text.len().is_multiple_of(2) || !text.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    (0..text.len())
+        .step_by(2)
+        .map(|index| u8::from_str_radix(&text[index..index + 2], 16).ok())
+        .collect()
+}
+
+/// Generates the checksums with the digest of the correct length.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Checksum {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let algorithm: ChecksumAlgorithm = u.arbitrary()?;
+        let digest = u.bytes(algorithm.get_digest_length_in_bytes())?;
+        Ok(Self {
+            algorithm,
+            digest: digest.to_vec(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::checksum::{decode_hex, encode_hex, Checksum, ChecksumAlgorithm, ChecksumError};
+
+    #[test]
+    fn test_checksum() {
+        assert_eq!(
+            Checksum::compute(ChecksumAlgorithm::Sha256, b"").to_string(),
+            "sha256:e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+
+        let checksum = Checksum::from_checksum_string(
+            "sha256:ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+        )
+        .unwrap();
+        assert_eq!(
+            checksum,
+            Checksum::compute(ChecksumAlgorithm::Sha256, b"abc")
+        );
+        assert_eq!(checksum.verify(b"abc"), Ok(()));
+        assert!(matches!(
+            checksum.verify(b"abd"),
+            Err(ChecksumError::Mismatch { .. })
+        ));
+
+        // multiple blocks
+        assert_eq!(
+            Checksum::compute(
+                ChecksumAlgorithm::Sha256,
+                b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"
+            )
+            .to_string(),
+            "sha256:248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1"
+        );
+
+        assert_eq!(
+            Checksum::from_checksum_string("md5:d41d8cd98f00b204e9800998ecf8427e"),
+            Err(ChecksumError::UnsupportedAlgorithm {
+                algorithm: "md5".to_owned()
+            })
+        );
+        assert!(matches!(
+            Checksum::from_checksum_string("sha256:e3b0"),
+            Err(ChecksumError::Malformed { .. })
+        ));
+
+        assert_eq!(encode_hex(&[0x01, 0xab, 0xff]), "01abff");
+        assert_eq!(decode_hex("01ABff"), Some(vec![0x01, 0xab, 0xff]));
+        assert_eq!(decode_hex(""), Some(vec![]));
+        assert_eq!(decode_hex("abc"), None);
+        assert_eq!(decode_hex("+1"), None);
+
+        let checksum = Checksum::new(ChecksumAlgorithm::Sha256, &[0xab; 32]).unwrap();
+        assert_eq!(
+            Checksum::from_checksum_string(&checksum.to_string()),
+            Ok(checksum)
+        );
+        assert_eq!(
+            Checksum::new(ChecksumAlgorithm::Sha256, &[0xab; 31]),
+            Err(ChecksumError::InvalidDigestLength {
+                algorithm: ChecksumAlgorithm::Sha256,
+                length: 31
+            })
+        );
+    }
+}
@@ -0,0 +1,913 @@
+// Copyright (c) 2025 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions.
+// For more details, see the LICENSE, LICENSE.additional, and CONTRIBUTING files.
+
+// Condition Expressions
+// ---------------------
+//
+// A compact textual syntax of `DependencyCondition`, which is used in command lines
+// and manifests instead of writing nested ASON variants by hand, e.g.:
+//
+// `all(os = "linux", not(flag(enable_x)))`
+//
+// The syntax:
+//
+// - `true` and `false`: `cond::true` and `cond::false`.
+// - `any(...)`, `all(...)` and `not(...)`: `cond::any`, `cond::all` and `cond::not`,
+//   the items are separated by commas.
+// - `flag(NAME)`: `check::true(NAME)`, and `not(flag(NAME))` is `check::false(NAME)`.
+// - `NAME = "STRING"` and `NAME = NUMBER`: `check::string` and `check::number`.
+// - `os = "..."`, `arch = "..."`, `pointer_width = NUMBER` and `edition = "..."`: the
+//   target checks, i.e., these property names are reserved.
+// - A property name which is reserved or is not an identifier (i.e., letters, digits
+//   and underscores, not starting with a digit) is quoted, e.g. `"os" = "linux"` is
+//   `check::string("os", "linux")`, and `flag("enable-x")`.
+// - The escape sequences of strings are `\"`, `\\`, `\n`, `\r`, `\t` and `\u{HEX}`.
+//
+// A single check at the top level (e.g. `os = "linux"`) is the same as `all(os = "linux")`.
+//
+// Evaluation
+// ----------
+//
+// - `True` and `False` are constants.
+// - `Any` is `true` if any of the checks is `true`, an empty `Any` is `false`.
+// - `All` is `true` if all of the checks are `true`, an empty `All` is `true`.
+// - `check::string` and `check::number` compare the property with the expected value,
+//   `check::string` also compares the selected value of a `prop::choice`.
+// - `check::true` and `check::false` test a flag (i.e., `prop::bool`) or
+//   the checked state of a group option (i.e., `prop::group`).
+// - The target checks compare with `TargetInfo`.
+//
+// All checks are evaluated (i.e., no short-circuit), so that an undefined property
+// or a type mismatch is reported regardless of the values of other properties.
+
+use std::{collections::HashMap, fmt::Display};
+
+use crate::{DependencyCondition, DependencyConditionCheck, PropertyValue, TargetInfo};
+
+/// The property names of the target checks.
+const RESERVED_PROPERTY_NAMES: [&str; 4] = ["os", "arch", "pointer_width", "edition"];
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ConditionParseError {
+    /// The position is the byte offset in the text.
+    UnexpectedCharacter {
+        position: usize,
+        character: char,
+    },
+    UnexpectedToken {
+        position: usize,
+        found: String,
+    },
+    UnexpectedEnd,
+    UnterminatedString {
+        position: usize,
+    },
+    InvalidNumber {
+        position: usize,
+        text: String,
+    },
+    UnknownFunction {
+        position: usize,
+        name: String,
+    },
+}
+
+impl Display for ConditionParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConditionParseError::UnexpectedCharacter {
+                position,
+                character,
+            } => write!(
+                f,
+                "Unexpected character '{}' at position {}.",
+                character, position
+            ),
+            ConditionParseError::UnexpectedToken { position, found } => {
+                write!(f, "Unexpected \"{}\" at position {}.", found, position)
+            }
+            ConditionParseError::UnexpectedEnd => f.write_str("Unexpected end of condition."),
+            ConditionParseError::UnterminatedString { position } => {
+                write!(f, "Unterminated string at position {}.", position)
+            }
+            ConditionParseError::InvalidNumber { position, text } => {
+                write!(f, "Invalid number \"{}\" at position {}.", text, position)
+            }
+            ConditionParseError::UnknownFunction { position, name } => {
+                write!(f, "Unknown function \"{}\" at position {}.", name, position)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConditionParseError {}
+
+#[derive(Debug, PartialEq, Clone)]
+enum Token {
+    Identifier(String),
+    String(String),
+    Number(i64),
+    LeftParen,
+    RightParen,
+    Comma,
+    Equal,
+}
+
+impl Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::Identifier(name) => f.write_str(name),
+            Token::String(value) => f.write_str(&quote(value)),
+            Token::Number(value) => write!(f, "{}", value),
+            Token::LeftParen => f.write_str("("),
+            Token::RightParen => f.write_str(")"),
+            Token::Comma => f.write_str(","),
+            Token::Equal => f.write_str("="),
+        }
+    }
+}
+
+fn tokenize(text: &str) -> Result<Vec<(usize, Token)>, ConditionParseError> {
+    let mut tokens = vec![];
+    let mut chars = text.char_indices().peekable();
+
+    while let Some((position, c)) = chars.next() {
+        let token = match c {
+            _ if c.is_whitespace() => continue,
+            '(' => Token::LeftParen,
+            ')' => Token::RightParen,
+            ',' => Token::Comma,
+            '=' => Token::Equal,
+            '"' => {
+                let mut value = String::new();
+                loop {
+                    match chars.next() {
+                        Some((_, '"')) => break,
+                        Some((_, '\\')) => match chars.next() {
+                            Some((_, escaped @ ('"' | '\\'))) => value.push(escaped),
+                            Some((_, 'n')) => value.push('\n'),
+                            Some((_, 'r')) => value.push('\r'),
+                            Some((_, 't')) => value.push('\t'),
+                            Some((index, 'u')) => {
+                                let code = chars.next_if(|(_, next)| *next == '{').and_then(|_| {
+                                    let mut hex = String::new();
+                                    while let Some((_, digit)) =
+                                        chars.next_if(|(_, next)| next.is_ascii_hexdigit())
+                                    {
+                                        hex.push(digit);
+                                    }
+                                    chars.next_if(|(_, next)| *next == '}')?;
+                                    u32::from_str_radix(&hex, 16).ok()
+                                });
+                                value.push(code.and_then(char::from_u32).ok_or(
+                                    ConditionParseError::UnexpectedCharacter {
+                                        position: index,
+                                        character: 'u',
+                                    },
+                                )?)
+                            }
+                            Some((index, other)) => {
+                                return Err(ConditionParseError::UnexpectedCharacter {
+                                    position: index,
+                                    character: other,
+                                })
+                            }
+                            None => {
+                                return Err(ConditionParseError::UnterminatedString { position })
+                            }
+                        },
+                        Some((_, other)) => value.push(other),
+                        None => return Err(ConditionParseError::UnterminatedString { position }),
+                    }
+                }
+                Token::String(value)
+            }
+            _ if c == '-' || c.is_ascii_digit() => {
+                let mut number = c.to_string();
+                while let Some((_, next)) = chars.next_if(|(_, next)| next.is_ascii_alphanumeric())
+                {
+                    number.push(next);
+                }
+                let value =
+                    number
+                        .parse::<i64>()
+                        .map_err(|_| ConditionParseError::InvalidNumber {
+                            position,
+                            text: number.clone(),
+                        })?;
+                Token::Number(value)
+            }
+            _ if c.is_ascii_alphabetic() || c == '_' => {
+                let mut name = c.to_string();
+                while let Some((_, next)) =
+                    chars.next_if(|(_, next)| next.is_ascii_alphanumeric() || *next == '_')
+                {
+                    name.push(next);
+                }
+                Token::Identifier(name)
+            }
+            _ => {
+                return Err(ConditionParseError::UnexpectedCharacter {
+                    position,
+                    character: c,
+                })
+            }
+        };
+        tokens.push((position, token));
+    }
+
+    Ok(tokens)
+}
+
+/// A parsed item, which is either a single check or a condition.
+enum Expression {
+    Check(DependencyConditionCheck),
+    Condition(DependencyCondition),
+}
+
+impl Expression {
+    fn into_condition(self) -> DependencyCondition {
+        match self {
+            Expression::Check(check) => DependencyCondition::All(vec![check]),
+            Expression::Condition(condition) => condition,
+        }
+    }
+
+    fn into_check(self) -> DependencyConditionCheck {
+        match self {
+            Expression::Check(check) => check,
+            Expression::Condition(condition) => {
+                DependencyConditionCheck::Condition(Box::new(condition))
+            }
+        }
+    }
+}
+
+struct Parser {
+    tokens: Vec<(usize, Token)>,
+    index: usize,
+}
+
+impl Parser {
+    fn next(&mut self) -> Result<(usize, Token), ConditionParseError> {
+        let item = self
+            .tokens
+            .get(self.index)
+            .cloned()
+            .ok_or(ConditionParseError::UnexpectedEnd)?;
+        self.index += 1;
+        Ok(item)
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.index).map(|(_, token)| token)
+    }
+
+    fn expect(&mut self, expected: Token) -> Result<(), ConditionParseError> {
+        let (position, token) = self.next()?;
+        if token == expected {
+            Ok(())
+        } else {
+            Err(ConditionParseError::UnexpectedToken {
+                position,
+                found: token.to_string(),
+            })
+        }
+    }
+
+    fn parse_expression(&mut self) -> Result<Expression, ConditionParseError> {
+        let (position, token) = self.next()?;
+
+        // a quoted property name is never a reserved name.
+        if let Token::String(name) = token {
+            self.expect(Token::Equal)?;
+            return self.parse_property_comparison(name);
+        }
+
+        let Token::Identifier(name) = token else {
+            return Err(ConditionParseError::UnexpectedToken {
+                position,
+                found: token.to_string(),
+            });
+        };
+
+        if self.peek() == Some(&Token::Equal) {
+            self.index += 1;
+            return self.parse_comparison(name);
+        }
+
+        let expression = match name.as_str() {
+            "true" => Expression::Condition(DependencyCondition::True),
+            "false" => Expression::Condition(DependencyCondition::False),
+            "any" | "all" => {
+                self.expect(Token::LeftParen)?;
+                let mut checks = vec![];
+                if self.peek() != Some(&Token::RightParen) {
+                    loop {
+                        checks.push(self.parse_expression()?.into_check());
+                        if self.peek() == Some(&Token::Comma) {
+                            self.index += 1;
+                        } else {
+                            break;
+                        }
+                    }
+                }
+                self.expect(Token::RightParen)?;
+
+                if name == "any" {
+                    Expression::Condition(DependencyCondition::Any(checks))
+                } else {
+                    Expression::Condition(DependencyCondition::All(checks))
+                }
+            }
+            "not" => {
+                self.expect(Token::LeftParen)?;
+                let operand = self.parse_expression()?;
+                self.expect(Token::RightParen)?;
+
+                match operand {
+                    Expression::Check(DependencyConditionCheck::True(flag)) => {
+                        Expression::Check(DependencyConditionCheck::False(flag))
+                    }
+                    _ => Expression::Condition(DependencyCondition::Not(Box::new(
+                        operand.into_condition(),
+                    ))),
+                }
+            }
+            "flag" => {
+                self.expect(Token::LeftParen)?;
+                let (position, token) = self.next()?;
+                let (Token::Identifier(flag) | Token::String(flag)) = token else {
+                    return Err(ConditionParseError::UnexpectedToken {
+                        position,
+                        found: token.to_string(),
+                    });
+                };
+                self.expect(Token::RightParen)?;
+                Expression::Check(DependencyConditionCheck::True(flag))
+            }
+            _ => return Err(ConditionParseError::UnknownFunction { position, name }),
+        };
+
+        Ok(expression)
+    }
+
+    fn parse_comparison(&mut self, name: String) -> Result<Expression, ConditionParseError> {
+        if !RESERVED_PROPERTY_NAMES.contains(&name.as_str()) {
+            return self.parse_property_comparison(name);
+        }
+
+        let (position, token) = self.next()?;
+        let check = match (name.as_str(), token) {
+            ("os", Token::String(value)) => DependencyConditionCheck::TargetOs(value),
+            ("arch", Token::String(value)) => DependencyConditionCheck::TargetArch(value),
+            ("edition", Token::String(value)) => DependencyConditionCheck::Edition(value),
+            ("pointer_width", Token::Number(value)) => {
+                DependencyConditionCheck::PointerWidth(u32::try_from(value).map_err(|_| {
+                    ConditionParseError::InvalidNumber {
+                        position,
+                        text: value.to_string(),
+                    }
+                })?)
+            }
+            (_, token) => {
+                return Err(ConditionParseError::UnexpectedToken {
+                    position,
+                    found: token.to_string(),
+                })
+            }
+        };
+
+        Ok(Expression::Check(check))
+    }
+
+    /// Parses the value of `check::string` or `check::number`.
+    fn parse_property_comparison(
+        &mut self,
+        name: String,
+    ) -> Result<Expression, ConditionParseError> {
+        let (position, token) = self.next()?;
+        let check = match token {
+            Token::String(value) => DependencyConditionCheck::String(name, value),
+            Token::Number(value) => DependencyConditionCheck::Number(
+                name,
+                i32::try_from(value).map_err(|_| ConditionParseError::InvalidNumber {
+                    position,
+                    text: value.to_string(),
+                })?,
+            ),
+            token => {
+                return Err(ConditionParseError::UnexpectedToken {
+                    position,
+                    found: token.to_string(),
+                })
+            }
+        };
+
+        Ok(Expression::Check(check))
+    }
+}
+
+impl DependencyCondition {
+    /// Parses the condition expression, see the section "Condition Expressions".
+    pub fn from_expression(text: &str) -> Result<Self, ConditionParseError> {
+        let mut parser = Parser {
+            tokens: tokenize(text)?,
+            index: 0,
+        };
+
+        let condition = parser.parse_expression()?.into_condition();
+
+        match parser.tokens.get(parser.index) {
+            Some((position, token)) => Err(ConditionParseError::UnexpectedToken {
+                position: *position,
+                found: token.to_string(),
+            }),
+            None => Ok(condition),
+        }
+    }
+}
+
+/// Quotes the string, the control characters are escaped so that
+/// the quoted string is always a single line, see the section "Condition Expressions".
+pub(crate) fn quote(text: &str) -> String {
+    let mut quoted = String::from('"');
+    for c in text.chars() {
+        match c {
+            '\\' => quoted.push_str("\\\\"),
+            '"' => quoted.push_str("\\\""),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => quoted.push_str(&format!("\\u{{{:x}}}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Writes the property name, the reserved names and the names which are not
+/// identifiers are quoted.
+fn write_name(f: &mut std::fmt::Formatter<'_>, name: &str) -> std::fmt::Result {
+    let is_identifier = name
+        .chars()
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+    if is_identifier && !RESERVED_PROPERTY_NAMES.contains(&name) {
+        f.write_str(name)
+    } else {
+        f.write_str(&quote(name))
+    }
+}
+
+fn write_checks(
+    f: &mut std::fmt::Formatter<'_>,
+    name: &str,
+    checks: &[DependencyConditionCheck],
+) -> std::fmt::Result {
+    let items = checks
+        .iter()
+        .map(|check| check.to_string())
+        .collect::<Vec<_>>();
+    write!(f, "{}({})", name, items.join(", "))
+}
+
+/// Formats the condition as a condition expression, e.g. `all(os = "linux", flag(enable_x))`.
+impl Display for DependencyCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DependencyCondition::True => f.write_str("true"),
+            DependencyCondition::False => f.write_str("false"),
+            DependencyCondition::Any(checks) => write_checks(f, "any", checks),
+            DependencyCondition::All(checks) => write_checks(f, "all", checks),
+            DependencyCondition::Not(condition) => write!(f, "not({})", condition),
+        }
+    }
+}
+
+impl Display for DependencyConditionCheck {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DependencyConditionCheck::String(name, value) => {
+                write_name(f, name)?;
+                write!(f, " = {}", quote(value))
+            }
+            DependencyConditionCheck::Number(name, value) => {
+                write_name(f, name)?;
+                write!(f, " = {}", value)
+            }
+            DependencyConditionCheck::True(name) => {
+                f.write_str("flag(")?;
+                write_name(f, name)?;
+                f.write_str(")")
+            }
+            DependencyConditionCheck::False(name) => {
+                f.write_str("not(flag(")?;
+                write_name(f, name)?;
+                f.write_str("))")
+            }
+            DependencyConditionCheck::Condition(condition) => write!(f, "{}", condition),
+            DependencyConditionCheck::TargetOs(value) => write!(f, "os = {}", quote(value)),
+            DependencyConditionCheck::TargetArch(value) => write!(f, "arch = {}", quote(value)),
+            DependencyConditionCheck::PointerWidth(value) => {
+                write!(f, "pointer_width = {}", value)
+            }
+            DependencyConditionCheck::Edition(value) => write!(f, "edition = {}", quote(value)),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum EvalError {
+    /// The condition refers to a property which is not declared by the module.
+    UndefinedProperty { name: String },
+
+    /// The type of the property does not match the check, e.g. `check::number`
+    /// against a `prop::string`. The types are the names of `PropertyValue`
+    /// variants, i.e., "string", "number", "bool", "group", "string_list" and "choice".
+    TypeMismatch {
+        name: String,
+        expected: &'static str,
+        actual: &'static str,
+    },
+}
+
+impl Display for EvalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EvalError::UndefinedProperty { name } => {
+                write!(f, "Undefined property \"{}\" in condition.", name)
+            }
+            EvalError::TypeMismatch {
+                name,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "Property \"{}\" is a {}, but the condition expects a {}.",
+                name, actual, expected
+            ),
+        }
+    }
+}
+
+impl std::error::Error for EvalError {}
+
+fn get_type_name(value: &PropertyValue) -> &'static str {
+    match value {
+        PropertyValue::String(_) => "string",
+        PropertyValue::Number(_) => "number",
+        PropertyValue::Flag(_) => "bool",
+        PropertyValue::Group(_, _) => "group",
+        PropertyValue::StringList(_) => "string_list",
+        PropertyValue::Choice(_, _) => "choice",
+    }
+}
+
+fn get_property<'a>(
+    properties: &'a HashMap<String, PropertyValue>,
+    name: &str,
+) -> Result<&'a PropertyValue, EvalError> {
+    properties
+        .get(name)
+        .ok_or_else(|| EvalError::UndefinedProperty {
+            name: name.to_owned(),
+        })
+}
+
+fn get_flag(properties: &HashMap<String, PropertyValue>, name: &str) -> Result<bool, EvalError> {
+    match get_property(properties, name)? {
+        PropertyValue::Flag(value) | PropertyValue::Group(_, value) => Ok(*value),
+        other => Err(EvalError::TypeMismatch {
+            name: name.to_owned(),
+            expected: "bool",
+            actual: get_type_name(other),
+        }),
+    }
+}
+
+impl DependencyCondition {
+    /// Evaluates the condition against the properties of the module and the host target,
+    /// see the section "Evaluation".
+    pub fn evaluate(&self, properties: &HashMap<String, PropertyValue>) -> Result<bool, EvalError> {
+        self.evaluate_for_target(properties, &TargetInfo::host())
+    }
+
+    pub fn evaluate_for_target(
+        &self,
+        properties: &HashMap<String, PropertyValue>,
+        target: &TargetInfo,
+    ) -> Result<bool, EvalError> {
+        let evaluate_checks = |checks: &[DependencyConditionCheck]| {
+            checks
+                .iter()
+                .map(|check| check.evaluate_for_target(properties, target))
+                .collect::<Result<Vec<_>, _>>()
+        };
+
+        match self {
+            DependencyCondition::True => Ok(true),
+            DependencyCondition::False => Ok(false),
+            DependencyCondition::Any(checks) => {
+                Ok(evaluate_checks(checks)?.into_iter().any(|value| value))
+            }
+            DependencyCondition::All(checks) => {
+                Ok(evaluate_checks(checks)?.into_iter().all(|value| value))
+            }
+            DependencyCondition::Not(condition) => {
+                Ok(!condition.evaluate_for_target(properties, target)?)
+            }
+        }
+    }
+}
+
+impl DependencyConditionCheck {
+    pub fn evaluate_for_target(
+        &self,
+        properties: &HashMap<String, PropertyValue>,
+        target: &TargetInfo,
+    ) -> Result<bool, EvalError> {
+        if let Some(value) = self.check_target(target) {
+            return Ok(value);
+        }
+
+        match self {
+            DependencyConditionCheck::String(name, expected) => {
+                match get_property(properties, name)? {
+                    PropertyValue::String(value) | PropertyValue::Choice(_, value) => {
+                        Ok(value == expected)
+                    }
+                    other => Err(EvalError::TypeMismatch {
+                        name: name.to_owned(),
+                        expected: "string",
+                        actual: get_type_name(other),
+                    }),
+                }
+            }
+            DependencyConditionCheck::Number(name, expected) => {
+                match get_property(properties, name)? {
+                    PropertyValue::Number(value) => Ok(value == expected),
+                    other => Err(EvalError::TypeMismatch {
+                        name: name.to_owned(),
+                        expected: "number",
+                        actual: get_type_name(other),
+                    }),
+                }
+            }
+            DependencyConditionCheck::True(name) => get_flag(properties, name),
+            DependencyConditionCheck::False(name) => Ok(!get_flag(properties, name)?),
+            DependencyConditionCheck::Condition(condition) => {
+                condition.evaluate_for_target(properties, target)
+            }
+            // the target checks are handled by `check_target`.
+            DependencyConditionCheck::TargetOs(_)
+            | DependencyConditionCheck::TargetArch(_)
+            | DependencyConditionCheck::PointerWidth(_)
+            | DependencyConditionCheck::Edition(_) => unreachable!(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use std::collections::HashMap;
+
+    use crate::{
+        condition::{ConditionParseError, EvalError},
+        DependencyCondition, DependencyConditionCheck, PropertyValue, TargetInfo, RUNTIME_EDITION,
+    };
+
+    #[test]
+    fn test_parse_condition_expression() {
+        let condition =
+            DependencyCondition::from_expression(r#"all(os = "linux", not(flag(enable_x)))"#)
+                .unwrap();
+        assert_eq!(
+            condition,
+            DependencyCondition::All(vec![
+                DependencyConditionCheck::TargetOs("linux".to_owned()),
+                DependencyConditionCheck::False("enable_x".to_owned()),
+            ])
+        );
+        assert_eq!(
+            condition.to_string(),
+            r#"all(os = "linux", not(flag(enable_x)))"#
+        );
+
+        let condition = DependencyCondition::from_expression(
+            r#"any(level = -2, name = "a \"b\"", not(any(pointer_width = 32)), all())"#,
+        )
+        .unwrap();
+        assert_eq!(
+            condition,
+            DependencyCondition::Any(vec![
+                DependencyConditionCheck::Number("level".to_owned(), -2),
+                DependencyConditionCheck::String("name".to_owned(), "a \"b\"".to_owned()),
+                DependencyConditionCheck::Condition(Box::new(DependencyCondition::Not(Box::new(
+                    DependencyCondition::Any(vec![DependencyConditionCheck::PointerWidth(32)])
+                )))),
+                DependencyConditionCheck::Condition(Box::new(DependencyCondition::All(vec![]))),
+            ])
+        );
+
+        // round trip
+        assert_eq!(
+            DependencyCondition::from_expression(&condition.to_string()),
+            Ok(condition)
+        );
+
+        // single check at the top level
+        assert_eq!(
+            DependencyCondition::from_expression(r#"edition = "2025""#),
+            Ok(DependencyCondition::All(vec![
+                DependencyConditionCheck::Edition("2025".to_owned())
+            ]))
+        );
+        assert_eq!(
+            DependencyCondition::from_expression(" true "),
+            Ok(DependencyCondition::True)
+        );
+    }
+
+    #[test]
+    fn test_condition_expression_round_trip() {
+        let condition = DependencyCondition::All(vec![
+            DependencyConditionCheck::String("name".to_owned(), "a\nb\t\"c\"\\\u{1}".to_owned()),
+            DependencyConditionCheck::TargetOs("linux\r".to_owned()),
+            DependencyConditionCheck::String("os".to_owned(), "linux".to_owned()),
+            DependencyConditionCheck::Number("pointer_width".to_owned(), 64),
+            DependencyConditionCheck::String("enable-x".to_owned(), "".to_owned()),
+            DependencyConditionCheck::Number("2d".to_owned(), 1),
+            DependencyConditionCheck::True("a b".to_owned()),
+            DependencyConditionCheck::False("edition".to_owned()),
+            DependencyConditionCheck::True("any".to_owned()),
+        ]);
+
+        let text = condition.to_string();
+        assert_eq!(
+            text,
+            r#"all(name = "a\nb\t\"c\"\\\u{1}", os = "linux\r", "os" = "linux", "pointer_width" = 64, "enable-x" = "", "2d" = 1, flag("a b"), not(flag("edition")), flag(any))"#
+        );
+        assert_eq!(DependencyCondition::from_expression(&text), Ok(condition));
+
+        assert_eq!(
+            DependencyCondition::from_expression(r#"name = "\u{4e2d}\u{a}""#),
+            Ok(DependencyCondition::All(vec![
+                DependencyConditionCheck::String("name".to_owned(), "\u{4e2d}\n".to_owned())
+            ]))
+        );
+        assert_eq!(
+            DependencyCondition::from_expression(r#"name = "\u{d800}""#),
+            Err(ConditionParseError::UnexpectedCharacter {
+                position: 9,
+                character: 'u'
+            })
+        );
+        assert_eq!(
+            DependencyCondition::from_expression(r#"name = "\x""#),
+            Err(ConditionParseError::UnexpectedCharacter {
+                position: 9,
+                character: 'x'
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_condition_expression_error() {
+        assert_eq!(
+            DependencyCondition::from_expression("all(flag(a)"),
+            Err(ConditionParseError::UnexpectedEnd)
+        );
+        assert_eq!(
+            DependencyCondition::from_expression("some(a)"),
+            Err(ConditionParseError::UnknownFunction {
+                position: 0,
+                name: "some".to_owned()
+            })
+        );
+        assert_eq!(
+            DependencyCondition::from_expression(r#"os = 1"#),
+            Err(ConditionParseError::UnexpectedToken {
+                position: 5,
+                found: "1".to_owned()
+            })
+        );
+        assert_eq!(
+            DependencyCondition::from_expression(r#"name = "abc"#),
+            Err(ConditionParseError::UnterminatedString { position: 7 })
+        );
+        assert_eq!(
+            DependencyCondition::from_expression("true false"),
+            Err(ConditionParseError::UnexpectedToken {
+                position: 5,
+                found: "false".to_owned()
+            })
+        );
+        assert!(matches!(
+            DependencyCondition::from_expression("level = 99999999999"),
+            Err(ConditionParseError::InvalidNumber { .. })
+        ));
+    }
+
+    #[test]
+    fn test_evaluate_condition() {
+        let mut properties = HashMap::new();
+        properties.insert("enable_x".to_owned(), PropertyValue::Flag(true));
+        properties.insert("enable_y".to_owned(), PropertyValue::Flag(false));
+        properties.insert(
+            "backend_gl".to_owned(),
+            PropertyValue::Group("backend".to_owned(), true),
+        );
+        properties.insert("name".to_owned(), PropertyValue::String("abc".to_owned()));
+        properties.insert("level".to_owned(), PropertyValue::Number(2));
+        properties.insert(
+            "device".to_owned(),
+            PropertyValue::Choice(vec!["cpu".to_owned(), "cuda".to_owned()], "cuda".to_owned()),
+        );
+        properties.insert(
+            "backends".to_owned(),
+            PropertyValue::StringList(vec!["gl".to_owned(), "vulkan".to_owned()]),
+        );
+
+        let target = TargetInfo {
+            os: "linux".to_owned(),
+            arch: "x86_64".to_owned(),
+            pointer_width: 64,
+            edition: RUNTIME_EDITION,
+        };
+
+        let evaluate = |text: &str| {
+            DependencyCondition::from_expression(text)
+                .unwrap()
+                .evaluate_for_target(&properties, &target)
+        };
+
+        assert_eq!(evaluate("true"), Ok(true));
+        assert_eq!(evaluate("false"), Ok(false));
+        assert_eq!(evaluate("any()"), Ok(false));
+        assert_eq!(evaluate("all()"), Ok(true));
+        assert_eq!(
+            evaluate(r#"all(os = "linux", flag(enable_x), not(flag(enable_y)))"#),
+            Ok(true)
+        );
+        assert_eq!(
+            evaluate(r#"all(name = "abc", level = 2, flag(backend_gl))"#),
+            Ok(true)
+        );
+        assert_eq!(evaluate(r#"any(arch = "aarch64", level = 3)"#), Ok(false));
+        assert_eq!(evaluate(r#"device = "cuda""#), Ok(true));
+        assert_eq!(evaluate(r#"device = "cpu""#), Ok(false));
+        assert_eq!(
+            evaluate("not(any(flag(enable_y), pointer_width = 32))"),
+            Ok(true)
+        );
+
+        // errors are reported even if the result is known.
+        assert_eq!(
+            evaluate("any(flag(enable_x), flag(enable_z))"),
+            Err(EvalError::UndefinedProperty {
+                name: "enable_z".to_owned()
+            })
+        );
+        assert_eq!(
+            evaluate("level = \"2\""),
+            Err(EvalError::TypeMismatch {
+                name: "level".to_owned(),
+                expected: "string",
+                actual: "number"
+            })
+        );
+        assert_eq!(
+            evaluate("flag(name)"),
+            Err(EvalError::TypeMismatch {
+                name: "name".to_owned(),
+                expected: "bool",
+                actual: "string"
+            })
+        );
+        assert_eq!(
+            evaluate(r#"backends = "gl""#),
+            Err(EvalError::TypeMismatch {
+                name: "backends".to_owned(),
+                expected: "string",
+                actual: "string_list"
+            })
+        );
+
+        assert_eq!(
+            DependencyCondition::All(vec![DependencyConditionCheck::TargetOs(
+                std::env::consts::OS.to_owned()
+            )])
+            .evaluate(&HashMap::new()),
+            Ok(true)
+        );
+    }
+}
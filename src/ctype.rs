@@ -0,0 +1,203 @@
+// Copyright (c) 2025 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions.
+// For more details, see the LICENSE, LICENSE.additional, and CONTRIBUTING files.
+
+// C ABI Types
+// -----------
+//
+// The parameters and return values of external functions (see the instruction `extcall`)
+// are described by C ABI types, which are shared by the compiler, the image format and
+// the FFI layer of the runtime.
+//
+// The size and alignment of types follow the C ABI of the 64-bit platforms (e.g., x86_64 and
+// AArch64), i.e., the pointer is 8 bytes, and the fields of a struct are laid out in order,
+// each field is aligned to its own alignment, and the struct is padded to the multiple
+// of the largest alignment of its fields.
+
+use std::fmt::Display;
+
+use crate::OperandDataType;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum CType {
+    Void,
+    I8,
+    I16,
+    I32,
+    I64,
+    U8,
+    U16,
+    U32,
+    U64,
+    F32,
+    F64,
+    Pointer,
+
+    /// A struct passed by value.
+    Struct(CStructLayout),
+}
+
+/// The fields of a struct, in the order of declaration.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct CStructLayout {
+    pub fields: Vec<CType>,
+}
+
+impl CType {
+    /// The size of `void` and the empty struct is 0.
+    pub fn get_size_in_bytes(&self) -> usize {
+        match self {
+            CType::Void => 0,
+            CType::I8 | CType::U8 => 1,
+            CType::I16 | CType::U16 => 2,
+            CType::I32 | CType::U32 | CType::F32 => 4,
+            CType::I64 | CType::U64 | CType::F64 | CType::Pointer => 8,
+            CType::Struct(layout) => layout.get_size_in_bytes(),
+        }
+    }
+
+    pub fn get_alignment_in_bytes(&self) -> usize {
+        match self {
+            CType::Void => 1,
+            CType::Struct(layout) => layout.get_alignment_in_bytes(),
+            _ => self.get_size_in_bytes(),
+        }
+    }
+
+    /// Returns the operand data type that carries the value of this type
+    /// on the operand stack, or `None` for `void` and structs.
+    ///
+    /// Note that the integers which are smaller than 32 bits are extended to i32,
+    /// and pointers are carried as i64.
+    pub fn get_operand_data_type(&self) -> Option<OperandDataType> {
+        match self {
+            CType::I8 | CType::I16 | CType::I32 | CType::U8 | CType::U16 | CType::U32 => {
+                Some(OperandDataType::I32)
+            }
+            CType::I64 | CType::U64 | CType::Pointer => Some(OperandDataType::I64),
+            CType::F32 => Some(OperandDataType::F32),
+            CType::F64 => Some(OperandDataType::F64),
+            CType::Void | CType::Struct(_) => None,
+        }
+    }
+}
+
+impl CStructLayout {
+    pub fn new(fields: Vec<CType>) -> Self {
+        Self { fields }
+    }
+
+    /// Returns the offset of each field.
+    pub fn get_field_offsets(&self) -> Vec<usize> {
+        let mut offset: usize = 0;
+        self.fields
+            .iter()
+            .map(|field| {
+                let field_offset = offset.next_multiple_of(field.get_alignment_in_bytes());
+                offset = field_offset + field.get_size_in_bytes();
+                field_offset
+            })
+            .collect()
+    }
+
+    /// The largest alignment of the fields, 1 for the empty struct.
+    pub fn get_alignment_in_bytes(&self) -> usize {
+        self.fields
+            .iter()
+            .map(|field| field.get_alignment_in_bytes())
+            .max()
+            .unwrap_or(1)
+    }
+
+    /// The size including the trailing padding.
+    pub fn get_size_in_bytes(&self) -> usize {
+        let end = match (self.fields.last(), self.get_field_offsets().last()) {
+            (Some(field), Some(offset)) => offset + field.get_size_in_bytes(),
+            _ => 0,
+        };
+        end.next_multiple_of(self.get_alignment_in_bytes())
+    }
+}
+
+impl Display for CType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            CType::Void => "void",
+            CType::I8 => "i8",
+            CType::I16 => "i16",
+            CType::I32 => "i32",
+            CType::I64 => "i64",
+            CType::U8 => "u8",
+            CType::U16 => "u16",
+            CType::U32 => "u32",
+            CType::U64 => "u64",
+            CType::F32 => "f32",
+            CType::F64 => "f64",
+            CType::Pointer => "pointer",
+            CType::Struct(layout) => return write!(f, "{}", layout),
+        };
+        f.write_str(name)
+    }
+}
+
+impl Display for CStructLayout {
+    /// Formats the struct as "struct {i32, f64}".
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let fields = self
+            .fields
+            .iter()
+            .map(|field| field.to_string())
+            .collect::<Vec<_>>();
+        write!(f, "struct {{{}}}", fields.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        ctype::{CStructLayout, CType},
+        OperandDataType,
+    };
+
+    #[test]
+    fn test_struct_layout() {
+        // struct { u8, i32, u16 } => offsets [0, 4, 8], size 12
+        let layout = CStructLayout::new(vec![CType::U8, CType::I32, CType::U16]);
+        assert_eq!(layout.get_field_offsets(), vec![0, 4, 8]);
+        assert_eq!(layout.get_alignment_in_bytes(), 4);
+        assert_eq!(layout.get_size_in_bytes(), 12);
+
+        // struct { i8, struct { u8, i32, u16 }, f64 } => offsets [0, 4, 16], size 24
+        let nested = CType::Struct(CStructLayout::new(vec![
+            CType::I8,
+            CType::Struct(layout),
+            CType::F64,
+        ]));
+        assert_eq!(nested.get_size_in_bytes(), 24);
+        assert_eq!(nested.get_alignment_in_bytes(), 8);
+        assert_eq!(
+            nested.to_string(),
+            "struct {i8, struct {u8, i32, u16}, f64}"
+        );
+
+        let empty = CStructLayout::new(vec![]);
+        assert_eq!(empty.get_size_in_bytes(), 0);
+    }
+
+    #[test]
+    fn test_operand_data_type() {
+        assert_eq!(
+            CType::U16.get_operand_data_type(),
+            Some(OperandDataType::I32)
+        );
+        assert_eq!(
+            CType::Pointer.get_operand_data_type(),
+            Some(OperandDataType::I64)
+        );
+        assert_eq!(CType::Void.get_operand_data_type(), None);
+    }
+}
@@ -0,0 +1,384 @@
+// Copyright (c) 2025 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions.
+// For more details, see the LICENSE, LICENSE.additional, and CONTRIBUTING files.
+
+// Edition
+// -------
+//
+// See the section "About Runtime Edition" of the crate root for the meaning of editions.
+//
+// An edition is stored in the image as a fixed 8-byte field, the name is
+// 1 to 8 printable ASCII characters (e.g. "2025"), and the remaining bytes are
+// padded with NUL (`\0`), e.g. `b"2025\0\0\0\0"`.
+//
+// Although editions cannot be compared for compatibility, they are released in
+// chronological order, see `EDITION_HISTORY`. The order is used to describe
+// the editions in which a feature (e.g., an instruction) is available.
+//
+// Edition Negotiation
+// -------------------
+//
+// A runtime supports its own (native) edition, and optionally some older editions
+// by compatibility shims. The supported editions are listed with the native edition
+// first, e.g. `SUPPORTED_EDITIONS` of the crate root. Before running an application,
+// the runtime negotiates the edition of the application with `Edition::negotiate`.
+//
+// Edition Compatibility
+// ---------------------
+//
+// When the edition of a module differs from the edition of the application, the compiler
+// attempts to compile the module using the application's edition. The table
+// `EDITION_COMPATIBILITY_TABLE` records which older editions can be compiled under a newer
+// edition, and which features break, so that the compiler can produce actionable
+// diagnostics, e.g. "This module targets edition "2025", the feature "X" is removed
+// in edition "2028".".
+
+use std::{fmt::Display, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+pub const EDITION_SIZE_IN_BYTES: usize = 8;
+
+/// All editions in chronological order.
+pub const EDITION_HISTORY: &[&str] = &["2025"];
+
+/// It is serialized as a string, e.g. "2025".
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub struct Edition(pub(crate) [u8; EDITION_SIZE_IN_BYTES]);
+
+impl Edition {
+    /// Parses the 8-byte field of the image.
+    pub fn from_bytes(bytes: &[u8; EDITION_SIZE_IN_BYTES]) -> Result<Self, EditionError> {
+        let length = bytes
+            .iter()
+            .position(|c| *c == 0)
+            .unwrap_or(EDITION_SIZE_IN_BYTES);
+
+        if length == 0 {
+            return Err(EditionError::Empty);
+        }
+
+        if !bytes[..length].iter().all(|c| c.is_ascii_graphic()) {
+            return Err(EditionError::InvalidCharacter {
+                edition: String::from_utf8_lossy(&bytes[..length]).into_owned(),
+            });
+        }
+
+        if bytes[length..].iter().any(|c| *c != 0) {
+            return Err(EditionError::InvalidPadding {
+                bytes: bytes.to_owned(),
+            });
+        }
+
+        Ok(Self(*bytes))
+    }
+
+    pub fn from_edition_string(edition: &str) -> Result<Self, EditionError> {
+        if edition.is_empty() {
+            return Err(EditionError::Empty);
+        }
+
+        if edition.len() > EDITION_SIZE_IN_BYTES {
+            return Err(EditionError::TooLong {
+                edition: edition.to_owned(),
+            });
+        }
+
+        if !edition.bytes().all(|c| c.is_ascii_graphic()) {
+            return Err(EditionError::InvalidCharacter {
+                edition: edition.to_owned(),
+            });
+        }
+
+        let mut bytes = [0u8; EDITION_SIZE_IN_BYTES];
+        bytes[..edition.len()].copy_from_slice(edition.as_bytes());
+        Ok(Self(bytes))
+    }
+
+    /// Checks whether an application with `app_edition` can run on a runtime
+    /// which supports `runtime_editions` (the native edition first).
+    ///
+    /// Returns `None` if the edition is not supported.
+    pub fn negotiate(
+        app_edition: &Edition,
+        runtime_editions: &[Edition],
+    ) -> Option<EditionSupport> {
+        match runtime_editions
+            .iter()
+            .position(|edition| edition == app_edition)?
+        {
+            0 => Some(EditionSupport::Native),
+            _ => Some(EditionSupport::Compatible),
+        }
+    }
+
+    /// Returns the position of the edition in `EDITION_HISTORY`,
+    /// or `None` if the edition is unknown.
+    pub fn get_release_index(&self) -> Option<usize> {
+        EDITION_HISTORY
+            .iter()
+            .position(|name| *name == self.as_str())
+    }
+
+    /// Returns the 8-byte field (including the NUL padding).
+    pub fn as_bytes(&self) -> &[u8; EDITION_SIZE_IN_BYTES] {
+        &self.0
+    }
+
+    /// Returns the name without the NUL padding, e.g. "2025".
+    pub fn as_str(&self) -> &str {
+        let length = self
+            .0
+            .iter()
+            .position(|c| *c == 0)
+            .unwrap_or(EDITION_SIZE_IN_BYTES);
+
+        // the bytes are validated printable ASCII characters.
+        std::str::from_utf8(&self.0[..length]).unwrap()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EditionSupport {
+    /// The edition is the native edition of the runtime.
+    Native,
+
+    /// The edition is supported by compatibility shims.
+    Compatible,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum EditionError {
+    /// The edition name is empty.
+    Empty,
+
+    /// The edition name is longer than 8 bytes.
+    TooLong { edition: String },
+
+    /// The edition name contains characters other than printable ASCII.
+    InvalidCharacter { edition: String },
+
+    /// There are non-NUL bytes after the NUL padding.
+    InvalidPadding { bytes: [u8; EDITION_SIZE_IN_BYTES] },
+}
+
+impl Display for EditionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EditionError::Empty => f.write_str("The edition is empty."),
+            EditionError::TooLong { edition } => write!(
+                f,
+                "The edition \"{}\" is longer than {} bytes.",
+                edition, EDITION_SIZE_IN_BYTES
+            ),
+            EditionError::InvalidCharacter { edition } => write!(
+                f,
+                "The edition \"{}\" contains invalid characters.",
+                edition
+            ),
+            EditionError::InvalidPadding { bytes } => {
+                write!(f, "The edition {:?} is not padded with NUL.", bytes)
+            }
+        }
+    }
+}
+
+impl std::error::Error for EditionError {}
+
+impl FromStr for Edition {
+    type Err = EditionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Edition::from_edition_string(s)
+    }
+}
+
+impl From<Edition> for String {
+    fn from(value: Edition) -> Self {
+        value.as_str().to_owned()
+    }
+}
+
+impl TryFrom<String> for Edition {
+    type Error = EditionError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Edition::from_edition_string(&value)
+    }
+}
+
+impl Display for Edition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The compatibility of the modules targeting the edition `from`
+/// when they are compiled under the newer edition `to`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct EditionCompatibility {
+    pub from: &'static str,
+    pub to: &'static str,
+
+    /// Whether the modules can be compiled under the newer edition automatically,
+    /// i.e., without source changes, if they do not use the breaking features.
+    pub auto_compilable: bool,
+
+    pub breaking_changes: &'static [EditionBreakingChange],
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct EditionBreakingChange {
+    /// The name of the feature, e.g. an instruction or a syntax.
+    pub feature: &'static str,
+    pub kind: BreakingChangeKind,
+
+    /// How to migrate, e.g. "use the instruction `Y` instead".
+    pub hint: &'static str,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum BreakingChangeKind {
+    Removed,
+    Changed,
+}
+
+/// There is only one edition currently, so the table is empty.
+pub const EDITION_COMPATIBILITY_TABLE: &[EditionCompatibility] = &[];
+
+impl EditionCompatibility {
+    /// Finds the entry of the editions in `EDITION_COMPATIBILITY_TABLE`.
+    pub fn find(from: &Edition, to: &Edition) -> Option<&'static EditionCompatibility> {
+        EDITION_COMPATIBILITY_TABLE
+            .iter()
+            .find(|item| item.from == from.as_str() && item.to == to.as_str())
+    }
+
+    /// Checks whether the modules targeting the edition `from` can be compiled under
+    /// the edition `to` automatically.
+    pub fn can_auto_compile(from: &Edition, to: &Edition) -> bool {
+        from == to || Self::find(from, to).is_some_and(|item| item.auto_compilable)
+    }
+
+    /// Returns the diagnostic message of each breaking change.
+    pub fn get_migration_hints(&self) -> Vec<String> {
+        self.breaking_changes
+            .iter()
+            .map(|change| {
+                format!(
+                    "This module targets edition \"{}\", the feature \"{}\" is {} in edition \"{}\", {}.",
+                    self.from, change.feature, change.kind, self.to, change.hint
+                )
+            })
+            .collect()
+    }
+}
+
+impl Display for BreakingChangeKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BreakingChangeKind::Removed => f.write_str("removed"),
+            BreakingChangeKind::Changed => f.write_str("changed"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        edition::{
+            BreakingChangeKind, Edition, EditionBreakingChange, EditionCompatibility, EditionError,
+            EditionSupport,
+        },
+        RUNTIME_EDITION, SUPPORTED_EDITIONS,
+    };
+
+    #[test]
+    fn test_edition() {
+        let edition = Edition::from_bytes(b"2025\0\0\0\0").unwrap();
+        assert_eq!(edition.as_str(), "2025");
+        assert_eq!(edition.to_string(), "2025");
+        assert_eq!(Edition::from_edition_string("2025"), Ok(edition));
+        assert_eq!(edition.as_bytes(), b"2025\0\0\0\0");
+        assert_eq!(
+            Edition::from_bytes(b"20250101").unwrap().as_str(),
+            "20250101"
+        );
+
+        assert_eq!(
+            Edition::from_bytes(b"\0\0\0\0\0\0\0\0"),
+            Err(EditionError::Empty)
+        );
+        assert_eq!(
+            Edition::from_bytes(b"2025\0\x01\0\0"),
+            Err(EditionError::InvalidPadding {
+                bytes: *b"2025\0\x01\0\0"
+            })
+        );
+        assert!(matches!(
+            Edition::from_bytes(b"20 5\0\0\0\0"),
+            Err(EditionError::InvalidCharacter { .. })
+        ));
+        assert!(matches!(
+            Edition::from_edition_string("202501010"),
+            Err(EditionError::TooLong { .. })
+        ));
+    }
+
+    #[test]
+    fn test_negotiate() {
+        assert_eq!(
+            Edition::negotiate(&RUNTIME_EDITION, SUPPORTED_EDITIONS),
+            Some(EditionSupport::Native)
+        );
+
+        let e2025 = Edition::from_edition_string("2025").unwrap();
+        let e2028 = Edition::from_edition_string("2028").unwrap();
+        let e2030 = Edition::from_edition_string("2030").unwrap();
+        let runtime_editions = [e2028, e2025];
+
+        assert_eq!(
+            Edition::negotiate(&e2028, &runtime_editions),
+            Some(EditionSupport::Native)
+        );
+        assert_eq!(
+            Edition::negotiate(&e2025, &runtime_editions),
+            Some(EditionSupport::Compatible)
+        );
+        assert_eq!(Edition::negotiate(&e2030, &runtime_editions), None);
+    }
+
+    #[test]
+    fn test_edition_compatibility() {
+        let e2025 = Edition::from_edition_string("2025").unwrap();
+        let e2028 = Edition::from_edition_string("2028").unwrap();
+
+        assert!(EditionCompatibility::can_auto_compile(&e2025, &e2025));
+        assert!(!EditionCompatibility::can_auto_compile(&e2025, &e2028));
+        assert_eq!(EditionCompatibility::find(&e2025, &e2028), None);
+
+        let compatibility = EditionCompatibility {
+            from: "2025",
+            to: "2028",
+            auto_compilable: true,
+            breaking_changes: &[EditionBreakingChange {
+                feature: "host_addr_function",
+                kind: BreakingChangeKind::Removed,
+                hint: "use the instruction `host_addr_function_dynamic` instead",
+            }],
+        };
+        assert_eq!(
+            compatibility.get_migration_hints(),
+            vec![
+                "This module targets edition \"2025\", the feature \"host_addr_function\" \
+                is removed in edition \"2028\", use the instruction `host_addr_function_dynamic` instead."
+                    .to_owned()
+            ]
+        );
+    }
+}
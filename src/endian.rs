@@ -0,0 +1,205 @@
+// Copyright (c) 2025 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions.
+// For more details, see the LICENSE, LICENSE.additional, and CONTRIBUTING files.
+
+// Byte Order
+// ----------
+//
+// All multi-byte numbers of the XiaoXuan Core VM are little-endian, regardless of
+// the byte order of the host, including:
+//
+// - opcodes and parameters in the bytecode,
+// - operands on the operand stack,
+// - local variables, data items and the image file.
+//
+// Do NOT access these numbers by casting raw pointers (e.g., `*(ptr as *const u32)`)
+// since the result depends on the host. Use the helpers in this module instead,
+// they behave the same on both little-endian and big-endian hosts (e.g., s390x).
+//
+// Operand Layout
+// --------------
+//
+// An operand is 8 bytes. The low part of the number is stored at the lower address:
+//
+// ```text
+// address  0    1    2    3    4    5    6    7
+//        |-------------------|-------------------|
+//        |         i32       | sign-extend       |
+//        |-------------------|-------------------|
+//        |         f32       | undefined         |
+//        |-------------------|-------------------|
+//        |                  i64                  |
+//        |---------------------------------------|
+//        |                  f64                  |
+//        |---------------------------------------|
+// ```
+
+use crate::{Operand, OPERAND_SIZE_IN_BYTES};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ByteOrder {
+    LittleEndian,
+    BigEndian,
+}
+
+/// The byte order of the XiaoXuan Core VM.
+pub const VM_BYTE_ORDER: ByteOrder = ByteOrder::LittleEndian;
+
+/// The byte order of the current host.
+pub const HOST_BYTE_ORDER: ByteOrder = if cfg!(target_endian = "big") {
+    ByteOrder::BigEndian
+} else {
+    ByteOrder::LittleEndian
+};
+
+/// Returns `None` if the slice is not long enough.
+pub fn read_u16(bytes: &[u8], offset: usize) -> Option<u16> {
+    let data = bytes.get(offset..offset.checked_add(2)?)?;
+    Some(u16::from_le_bytes(data.try_into().unwrap()))
+}
+
+/// Returns `None` if the slice is not long enough.
+pub fn read_u32(bytes: &[u8], offset: usize) -> Option<u32> {
+    let data = bytes.get(offset..offset.checked_add(4)?)?;
+    Some(u32::from_le_bytes(data.try_into().unwrap()))
+}
+
+/// Returns `None` if the slice is not long enough.
+pub fn read_u64(bytes: &[u8], offset: usize) -> Option<u64> {
+    let data = bytes.get(offset..offset.checked_add(8)?)?;
+    Some(u64::from_le_bytes(data.try_into().unwrap()))
+}
+
+pub fn write_u16(buffer: &mut Vec<u8>, value: u16) {
+    buffer.extend_from_slice(&value.to_le_bytes());
+}
+
+pub fn write_u32(buffer: &mut Vec<u8>, value: u32) {
+    buffer.extend_from_slice(&value.to_le_bytes());
+}
+
+pub fn write_u64(buffer: &mut Vec<u8>, value: u64) {
+    buffer.extend_from_slice(&value.to_le_bytes());
+}
+
+/// Puts an i32 number into an operand, the number is sign-extended to i64.
+pub fn operand_from_i32(value: u32) -> Operand {
+    operand_from_i64(value as i32 as i64 as u64)
+}
+
+pub fn operand_from_i64(value: u64) -> Operand {
+    value.to_le_bytes()
+}
+
+/// Puts an f32 number into an operand.
+///
+/// The high part of the operand is undefined by the ISA, this function fills it with zeros.
+pub fn operand_from_f32(value: f32) -> Operand {
+    let mut operand = [0u8; OPERAND_SIZE_IN_BYTES];
+    operand[0..4].copy_from_slice(&value.to_le_bytes());
+    operand
+}
+
+pub fn operand_from_f64(value: f64) -> Operand {
+    value.to_le_bytes()
+}
+
+/// Gets the i32 number (the low 32 bits) from an operand.
+pub fn operand_to_i32(operand: &Operand) -> u32 {
+    u32::from_le_bytes(operand[0..4].try_into().unwrap())
+}
+
+pub fn operand_to_i64(operand: &Operand) -> u64 {
+    u64::from_le_bytes(*operand)
+}
+
+/// Gets the f32 number from an operand.
+///
+/// The high part of the operand is ignored since it is undefined.
+pub fn operand_to_f32(operand: &Operand) -> f32 {
+    f32::from_le_bytes(operand[0..4].try_into().unwrap())
+}
+
+pub fn operand_to_f64(operand: &Operand) -> f64 {
+    f64::from_le_bytes(*operand)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::endian::{
+        operand_from_f32, operand_from_f64, operand_from_i32, operand_from_i64, operand_to_f32,
+        operand_to_f64, operand_to_i32, operand_to_i64, read_u16, read_u32, read_u64, write_u16,
+        write_u32, write_u64,
+    };
+
+    #[test]
+    fn test_read_and_write() {
+        let mut buffer = vec![];
+        write_u16(&mut buffer, 0x1122);
+        write_u32(&mut buffer, 0x3344_5566);
+        write_u64(&mut buffer, 0x7788_99aa_bbcc_ddee);
+
+        // the byte order is always little-endian, regardless of the host.
+        assert_eq!(
+            buffer,
+            vec![
+                0x22, 0x11, // u16
+                0x66, 0x55, 0x44, 0x33, // u32
+                0xee, 0xdd, 0xcc, 0xbb, 0xaa, 0x99, 0x88, 0x77, // u64
+            ]
+        );
+
+        assert_eq!(read_u16(&buffer, 0), Some(0x1122));
+        assert_eq!(read_u32(&buffer, 2), Some(0x3344_5566));
+        assert_eq!(read_u64(&buffer, 6), Some(0x7788_99aa_bbcc_ddee));
+
+        // out of range
+        assert_eq!(read_u16(&buffer, 13), None);
+        assert_eq!(read_u32(&buffer, 12), None);
+        assert_eq!(read_u64(&buffer, 7), None);
+        assert_eq!(read_u16(&buffer, usize::MAX), None);
+        assert_eq!(read_u32(&buffer, usize::MAX - 2), None);
+        assert_eq!(read_u64(&buffer, usize::MAX - 4), None);
+    }
+
+    #[test]
+    fn test_operand() {
+        assert_eq!(
+            operand_from_i32(0x8000_0001),
+            [0x01, 0x00, 0x00, 0x80, 0xff, 0xff, 0xff, 0xff]
+        );
+        assert_eq!(
+            operand_from_i32(0x7000_0001),
+            [0x01, 0x00, 0x00, 0x70, 0x00, 0x00, 0x00, 0x00]
+        );
+        assert_eq!(
+            operand_to_i32(&[0x01, 0x00, 0x00, 0x80, 0x11, 0x22, 0x33, 0x44]),
+            0x8000_0001
+        );
+
+        assert_eq!(
+            operand_from_i64(0x1122_3344_5566_7788),
+            [0x88, 0x77, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11]
+        );
+        assert_eq!(
+            operand_to_i64(&[0x88, 0x77, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11]),
+            0x1122_3344_5566_7788
+        );
+
+        let operand = operand_from_f32(1.25);
+        assert_eq!(operand, [0x00, 0x00, 0xa0, 0x3f, 0x00, 0x00, 0x00, 0x00]);
+        assert_eq!(operand_to_f32(&operand), 1.25);
+
+        // the high part of the operand is undefined and should be ignored.
+        let dirty = [0x00, 0x00, 0xa0, 0x3f, 0x11, 0x22, 0x33, 0x44];
+        assert_eq!(operand_to_f32(&dirty), 1.25);
+
+        let operand = operand_from_f64(-5.0);
+        assert_eq!(operand, [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x14, 0xc0]);
+        assert_eq!(operand_to_f64(&operand), -5.0);
+    }
+}
@@ -0,0 +1,580 @@
+// Copyright (c) 2025 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions.
+// For more details, see the LICENSE, LICENSE.additional, and CONTRIBUTING files.
+
+// Environment Call Numbers
+// ------------------------
+//
+// The instruction `envcall` calls the VM built-in functions by the call number,
+// e.g., `envcall(0x0001_0001)` gets the version of the runtime.
+//
+// The call number is a 32-bit number which consists of two parts:
+//
+// MSB                                 LSB
+// 00000000 00000000 00000000 00000000 <-- bits
+// ----------------- -----------------
+// ^                 ^
+// |                 | items
+// |
+// | categories
+//
+// The signature of each call is described in the form of
+// `(operand args...) -> (values)`, the same as the instructions.
+//
+// Number Ranges
+// -------------
+//
+// | range                       | category               |
+// |-----------------------------|------------------------|
+// | 0x0001_0000 - 0x0001_FFFF   | runtime information    |
+// | 0x0002_0000 - 0x0002_FFFF   | thread                 |
+// | 0x0003_0000 - 0x0003_FFFF   | time                   |
+// | 0x0004_0000 - 0x0004_FFFF   | I/O bridge             |
+// | 0x0005_0000 - 0x7FFF_FFFF   | reserved (official)    |
+// | 0x8000_0000 - 0xBFFF_FFFF   | vendor                 |
+// | 0xC000_0000 - 0xFFFF_FFFF   | experimental           |
+//
+// All ranges below 0x8000_0000 are reserved for the official call numbers, embedders
+// should define their own host calls in the "vendor" range, and the unstable calls in the
+// "experimental" range, so that they never collide with the future official numbers.
+
+use std::{fmt::Display, ops::RangeInclusive};
+
+use serde::{Deserialize, Serialize};
+
+#[repr(u32)]
+#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Clone, Copy)]
+#[allow(non_camel_case_types)]
+pub enum EnvCallNum {
+    // Category: Runtime Information
+    // ------------------------------
+
+    // Gets the edition of the runtime, i.e., the 8 bytes of `Edition::as_bytes`
+    // as a little-endian i64 number.
+    //
+    // () -> edition:i64
+    runtime_edition = 0x0001_0000,
+
+    // Gets the version of the runtime, see `EffectiveVersion::to_u64`.
+    //
+    // () -> version:i64
+    runtime_version,
+
+    // Gets the maximum image format version supported by the runtime.
+    //
+    // () -> (major:i32 minor:i32)
+    image_format_version,
+
+    // Gets the features supported by the runtime, e.g. "syscall" and "extcall".
+    //
+    // () -> features:i64
+    runtime_features,
+
+    // Category: Thread
+    // -----------------
+
+    // Gets the ID of the current thread, the main thread is 0.
+    //
+    // () -> thread_id:i32
+    thread_id = 0x0002_0000,
+
+    // Creates a new thread which runs the specified function.
+    //
+    // The type of the function must be `(i64) -> i64`, the `arg` is passed to
+    // the function, and the result of the function is the result of the thread.
+    //
+    // (operand function_module_index:i32 function_public_index:i32 arg:i64) -> thread_id:i32
+    thread_create,
+
+    // Waits for the specified thread to finish, and gets its result.
+    //
+    // (operand thread_id:i32) -> result:i64
+    thread_join,
+
+    // Exits the current thread with the specified result.
+    //
+    // (operand result:i64) -> NEVER_RETURN
+    thread_exit,
+
+    // Suspends the current thread for the specified duration.
+    //
+    // (operand nanoseconds:i64) -> ()
+    thread_sleep,
+
+    // Gives up the remaining time slice of the current thread.
+    //
+    // () -> ()
+    thread_yield,
+
+    // Category: Time
+    // ---------------
+
+    // Gets the current wall-clock time since the UNIX epoch.
+    //
+    // () -> (seconds:i64 nanoseconds:i32)
+    time_now = 0x0003_0000,
+
+    // Gets the value of the monotonic clock, it is only meaningful
+    // for measuring the elapsed time.
+    //
+    // () -> nanoseconds:i64
+    time_monotonic,
+
+    // Category: I/O Bridge
+    // ---------------------
+
+    // A baseline host interface (similar to WASI), so that modules compiled by different
+    // toolchains can access the host without private agreements. The clocks of the host
+    // are provided by the category "Time".
+    //
+    // - The file descriptors 0, 1 and 2 are the standard input, output and error
+    //   (see `STDIN_FD`, `STDOUT_FD` and `STDERR_FD`), other file descriptors are
+    //   opened by the host or the standard library.
+    // - The buffers are addressed like the instruction `memory_fill`.
+    // - The `error_number` is 0 on success, otherwise it is one of the `Errno`.
+
+    // Reads bytes from the file descriptor into the buffer.
+    //
+    // (operand
+    //     fd:i32
+    //     buffer_data_module_index:i32
+    //     buffer_data_public_index:i32
+    //     buffer_offset_in_bytes:i64
+    //     buffer_length_in_bytes:i64) -> (read_bytes:i64 error_number:i32)
+    fd_read = 0x0004_0000,
+
+    // Writes bytes of the buffer to the file descriptor.
+    //
+    // (operand
+    //     fd:i32
+    //     buffer_data_module_index:i32
+    //     buffer_data_public_index:i32
+    //     buffer_offset_in_bytes:i64
+    //     buffer_length_in_bytes:i64) -> (written_bytes:i64 error_number:i32)
+    fd_write,
+
+    // (operand fd:i32) -> error_number:i32
+    fd_close,
+
+    // Fills the buffer with cryptographically secure random bytes.
+    //
+    // (operand
+    //     buffer_data_module_index:i32
+    //     buffer_data_public_index:i32
+    //     buffer_offset_in_bytes:i64
+    //     buffer_length_in_bytes:i64) -> ()
+    random_fill,
+
+    // Gets the number of the program arguments, including the program name.
+    //
+    // () -> count:i32
+    args_count,
+
+    // Copies the UTF-8 bytes of the specified argument into the buffer, at most
+    // `buffer_length_in_bytes` bytes are copied, and returns the full length of the argument.
+    //
+    // (operand
+    //     index:i32
+    //     buffer_data_module_index:i32
+    //     buffer_data_public_index:i32
+    //     buffer_offset_in_bytes:i64
+    //     buffer_length_in_bytes:i64) -> length:i64
+    args_get,
+
+    // Copies the UTF-8 bytes of the specified environment variable into the buffer, at most
+    // `buffer_length_in_bytes` bytes are copied, and returns the full length of the value,
+    // or -1 if the variable does not exist.
+    //
+    // (operand
+    //     name_data_module_index:i32
+    //     name_data_public_index:i32
+    //     name_offset_in_bytes:i64
+    //     name_length_in_bytes:i64
+    //     buffer_data_module_index:i32
+    //     buffer_data_public_index:i32
+    //     buffer_offset_in_bytes:i64
+    //     buffer_length_in_bytes:i64) -> length:i64
+    env_get,
+}
+
+/// The file descriptors of the standard streams, see the category "I/O Bridge".
+pub const STDIN_FD: u32 = 0;
+pub const STDOUT_FD: u32 = 1;
+pub const STDERR_FD: u32 = 2;
+
+/// The last call number of each official category, in the ascending order of
+/// categories, i.e., the category 0x0001 is the first item.
+const LAST_ENVCALL_NUMS: [EnvCallNum; 4] = [
+    EnvCallNum::runtime_features,
+    EnvCallNum::thread_yield,
+    EnvCallNum::time_monotonic,
+    EnvCallNum::env_get,
+];
+
+impl EnvCallNum {
+    /// Converts a 32-bit number into a call number.
+    ///
+    /// Returns `None` if the number is not a valid call number.
+    pub fn from_u32(value: u32) -> Option<Self> {
+        let category = (value >> 16) as usize;
+        let last = *LAST_ENVCALL_NUMS.get(category.checked_sub(1)?)?;
+
+        if value <= last as u32 {
+            // SAFETY: the items of each category are continuous numbers starting from 0x0000,
+            // and the value has been checked to be in the range of the category.
+            Some(unsafe { std::mem::transmute::<u32, EnvCallNum>(value) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns all call numbers in ascending order.
+    pub fn get_all() -> impl Iterator<Item = EnvCallNum> {
+        LAST_ENVCALL_NUMS.into_iter().flat_map(|last| {
+            let last = last as u32;
+            (last & 0xffff_0000..=last).filter_map(EnvCallNum::from_u32)
+        })
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            // Category: Runtime Information
+            EnvCallNum::runtime_edition => "runtime_edition",
+            EnvCallNum::runtime_version => "runtime_version",
+            EnvCallNum::image_format_version => "image_format_version",
+            EnvCallNum::runtime_features => "runtime_features",
+            // Category: Thread
+            EnvCallNum::thread_id => "thread_id",
+            EnvCallNum::thread_create => "thread_create",
+            EnvCallNum::thread_join => "thread_join",
+            EnvCallNum::thread_exit => "thread_exit",
+            EnvCallNum::thread_sleep => "thread_sleep",
+            EnvCallNum::thread_yield => "thread_yield",
+            // Category: Time
+            EnvCallNum::time_now => "time_now",
+            EnvCallNum::time_monotonic => "time_monotonic",
+            // Category: I/O Bridge
+            EnvCallNum::fd_read => "fd_read",
+            EnvCallNum::fd_write => "fd_write",
+            EnvCallNum::fd_close => "fd_close",
+            EnvCallNum::random_fill => "random_fill",
+            EnvCallNum::args_count => "args_count",
+            EnvCallNum::args_get => "args_get",
+            EnvCallNum::env_get => "env_get",
+        }
+    }
+
+    /// Returns `None` if the name is unknown.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let num = match name {
+            // Category: Runtime Information
+            "runtime_edition" => EnvCallNum::runtime_edition,
+            "runtime_version" => EnvCallNum::runtime_version,
+            "image_format_version" => EnvCallNum::image_format_version,
+            "runtime_features" => EnvCallNum::runtime_features,
+            // Category: Thread
+            "thread_id" => EnvCallNum::thread_id,
+            "thread_create" => EnvCallNum::thread_create,
+            "thread_join" => EnvCallNum::thread_join,
+            "thread_exit" => EnvCallNum::thread_exit,
+            "thread_sleep" => EnvCallNum::thread_sleep,
+            "thread_yield" => EnvCallNum::thread_yield,
+            // Category: Time
+            "time_now" => EnvCallNum::time_now,
+            "time_monotonic" => EnvCallNum::time_monotonic,
+            // Category: I/O Bridge
+            "fd_read" => EnvCallNum::fd_read,
+            "fd_write" => EnvCallNum::fd_write,
+            "fd_close" => EnvCallNum::fd_close,
+            "random_fill" => EnvCallNum::random_fill,
+            "args_count" => EnvCallNum::args_count,
+            "args_get" => EnvCallNum::args_get,
+            "env_get" => EnvCallNum::env_get,
+            //
+            _ => return None,
+        };
+        Some(num)
+    }
+}
+
+/// The category of the call numbers, see the section "Number Ranges".
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EnvCallCategory {
+    RuntimeInformation,
+    Thread,
+    Time,
+    IoBridge,
+    Reserved,
+    Vendor,
+    Experimental,
+}
+
+impl EnvCallCategory {
+    pub fn get_range(&self) -> RangeInclusive<u32> {
+        match self {
+            EnvCallCategory::RuntimeInformation => 0x0001_0000..=0x0001_FFFF,
+            EnvCallCategory::Thread => 0x0002_0000..=0x0002_FFFF,
+            EnvCallCategory::Time => 0x0003_0000..=0x0003_FFFF,
+            EnvCallCategory::IoBridge => 0x0004_0000..=0x0004_FFFF,
+            EnvCallCategory::Reserved => 0x0005_0000..=0x7FFF_FFFF,
+            EnvCallCategory::Vendor => 0x8000_0000..=0xBFFF_FFFF,
+            EnvCallCategory::Experimental => 0xC000_0000..=0xFFFF_FFFF,
+        }
+    }
+
+    /// Finds the category of the specified number.
+    ///
+    /// Returns `None` if the number is less than 0x0001_0000.
+    pub fn from_u32(value: u32) -> Option<Self> {
+        [
+            EnvCallCategory::RuntimeInformation,
+            EnvCallCategory::Thread,
+            EnvCallCategory::Time,
+            EnvCallCategory::IoBridge,
+            EnvCallCategory::Reserved,
+            EnvCallCategory::Vendor,
+            EnvCallCategory::Experimental,
+        ]
+        .into_iter()
+        .find(|category| category.get_range().contains(&value))
+    }
+
+    /// The official categories are defined by this crate,
+    /// the call numbers within them are not available to embedders.
+    pub fn is_official(&self) -> bool {
+        !matches!(
+            self,
+            EnvCallCategory::Vendor | EnvCallCategory::Experimental
+        )
+    }
+}
+
+/// Checks whether the number can be used by the instruction `envcall`, i.e., it is
+/// an official call number, or it is within the "vendor" or "experimental" range.
+pub fn is_valid_envcall(value: u32) -> bool {
+    match EnvCallCategory::from_u32(value) {
+        Some(category) if !category.is_official() => true,
+        Some(_) => EnvCallNum::from_u32(value).is_some(),
+        None => false,
+    }
+}
+
+impl Display for EnvCallNum {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.get_name())
+    }
+}
+
+/// The features supported by the runtime, i.e., the result of the call `runtime_features`.
+///
+/// It is serialized as a list of feature names, e.g. `["syscall", "threads"]`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(into = "Vec<String>", try_from = "Vec<String>")]
+pub struct RuntimeFeature(u64);
+
+impl RuntimeFeature {
+    /// The instruction `syscall`.
+    pub const SYSCALL: RuntimeFeature = RuntimeFeature(0x1);
+
+    /// The instruction `extcall`.
+    pub const EXTCALL: RuntimeFeature = RuntimeFeature(0x2);
+
+    /// The calls of the category "Thread".
+    pub const THREADS: RuntimeFeature = RuntimeFeature(0x4);
+
+    /// The SIMD acceleration of the runtime.
+    pub const SIMD: RuntimeFeature = RuntimeFeature(0x8);
+
+    /// The instructions of the category "Atomic", and `memory_wait_xxx` and `memory_notify`.
+    pub const ATOMICS: RuntimeFeature = RuntimeFeature(0x10);
+
+    /// The instruction `host_addr_function` and `host_addr_function_dynamic`.
+    pub const JIT_BRIDGE: RuntimeFeature = RuntimeFeature(0x20);
+
+    /// The instructions of the optional category "Checksum".
+    pub const CHECKSUM: RuntimeFeature = RuntimeFeature(0x40);
+
+    /// The instructions of the optional category "Crypto".
+    pub const CRYPTO: RuntimeFeature = RuntimeFeature(0x80);
+
+    const ALL: [(RuntimeFeature, &'static str); 8] = [
+        (RuntimeFeature::SYSCALL, "syscall"),
+        (RuntimeFeature::EXTCALL, "extcall"),
+        (RuntimeFeature::THREADS, "threads"),
+        (RuntimeFeature::SIMD, "simd"),
+        (RuntimeFeature::ATOMICS, "atomics"),
+        (RuntimeFeature::JIT_BRIDGE, "jit_bridge"),
+        (RuntimeFeature::CHECKSUM, "checksum"),
+        (RuntimeFeature::CRYPTO, "crypto"),
+    ];
+
+    pub fn empty() -> Self {
+        RuntimeFeature(0)
+    }
+
+    /// Returns `None` if there are unknown bits.
+    pub fn from_bits(bits: u64) -> Option<Self> {
+        let all_bits = RuntimeFeature::ALL
+            .iter()
+            .fold(0, |bits, (feature, _)| bits | feature.0);
+
+        if bits & !all_bits == 0 {
+            Some(RuntimeFeature(bits))
+        } else {
+            None
+        }
+    }
+
+    pub fn bits(&self) -> u64 {
+        self.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn contains(&self, other: RuntimeFeature) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub fn union(&self, other: RuntimeFeature) -> Self {
+        RuntimeFeature(self.0 | other.0)
+    }
+
+    /// Returns the names of the features, in the order of bits.
+    pub fn get_names(&self) -> Vec<&'static str> {
+        RuntimeFeature::ALL
+            .iter()
+            .filter(|(feature, _)| self.contains(*feature))
+            .map(|(_, name)| *name)
+            .collect()
+    }
+
+    /// Returns `None` if the name is unknown.
+    pub fn from_name(name: &str) -> Option<Self> {
+        RuntimeFeature::ALL
+            .iter()
+            .find(|(_, item_name)| *item_name == name)
+            .map(|(feature, _)| *feature)
+    }
+}
+
+impl From<RuntimeFeature> for Vec<String> {
+    fn from(value: RuntimeFeature) -> Self {
+        value
+            .get_names()
+            .iter()
+            .map(|name| name.to_string())
+            .collect()
+    }
+}
+
+impl TryFrom<Vec<String>> for RuntimeFeature {
+    type Error = String;
+
+    fn try_from(value: Vec<String>) -> Result<Self, Self::Error> {
+        value
+            .iter()
+            .try_fold(RuntimeFeature::empty(), |features, name| {
+                RuntimeFeature::from_name(name)
+                    .map(|feature| features.union(feature))
+                    .ok_or_else(|| format!("Unknown runtime feature \"{}\".", name))
+            })
+    }
+}
+
+impl Display for RuntimeFeature {
+    /// Formats the features as names joined by "|", e.g. "syscall|threads".
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.get_names().join("|"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::envcall::{is_valid_envcall, EnvCallCategory, EnvCallNum, RuntimeFeature};
+
+    #[test]
+    fn test_envcall_num() {
+        assert_eq!(
+            EnvCallNum::from_u32(0x0001_0001),
+            Some(EnvCallNum::runtime_version)
+        );
+        assert_eq!(EnvCallNum::from_u32(0x0001_00ff), None);
+        assert_eq!(EnvCallNum::from_u32(0x00ff_0000), None);
+
+        // all numbers can be converted to names and back.
+        let nums = EnvCallNum::get_all().collect::<Vec<_>>();
+        assert_eq!(nums.len(), 19);
+        assert_eq!(nums[0], EnvCallNum::runtime_edition);
+        assert_eq!(nums[18], EnvCallNum::env_get);
+        for num in nums {
+            assert_eq!(EnvCallNum::from_u32(num as u32), Some(num));
+            assert_eq!(EnvCallNum::from_name(num.get_name()), Some(num));
+        }
+
+        assert_eq!(EnvCallNum::from_name("foo"), None);
+    }
+
+    #[test]
+    fn test_envcall_range() {
+        assert_eq!(
+            EnvCallCategory::from_u32(0x0002_0003),
+            Some(EnvCallCategory::Thread)
+        );
+        assert_eq!(
+            EnvCallCategory::from_u32(0x0004_0000),
+            Some(EnvCallCategory::IoBridge)
+        );
+        assert_eq!(
+            EnvCallCategory::from_u32(0x8000_0001),
+            Some(EnvCallCategory::Vendor)
+        );
+        assert_eq!(
+            EnvCallCategory::from_u32(0xffff_ffff),
+            Some(EnvCallCategory::Experimental)
+        );
+        assert_eq!(EnvCallCategory::from_u32(0x0000_1000), None);
+
+        assert!(is_valid_envcall(EnvCallNum::thread_join as u32));
+        assert!(is_valid_envcall(0x8000_1234));
+        assert!(is_valid_envcall(0xc000_0000));
+        assert!(!is_valid_envcall(0x0001_00ff));
+        assert!(!is_valid_envcall(0x0005_0000));
+        assert!(!is_valid_envcall(0));
+    }
+
+    #[test]
+    fn test_runtime_feature() {
+        let features = RuntimeFeature::SYSCALL.union(RuntimeFeature::THREADS);
+        assert_eq!(features.bits(), 0x5);
+        assert!(features.contains(RuntimeFeature::THREADS));
+        assert!(!features.contains(RuntimeFeature::EXTCALL));
+        assert!(RuntimeFeature::empty().is_empty());
+        assert_eq!(features.to_string(), "syscall|threads");
+
+        assert_eq!(
+            RuntimeFeature::from_bits(0xff).unwrap().get_names().len(),
+            8
+        );
+        assert_eq!(RuntimeFeature::from_bits(0x100), None);
+
+        assert_eq!(
+            RuntimeFeature::try_from(vec!["simd".to_owned(), "crypto".to_owned()]),
+            Ok(RuntimeFeature::SIMD.union(RuntimeFeature::CRYPTO))
+        );
+        assert!(RuntimeFeature::try_from(vec!["foo".to_owned()]).is_err());
+    }
+
+    #[test]
+    fn test_serialize_runtime_feature() {
+        assert_eq!(
+            ason::from_str::<RuntimeFeature>(r#"["extcall", "atomics"]"#).unwrap(),
+            RuntimeFeature::EXTCALL.union(RuntimeFeature::ATOMICS)
+        );
+    }
+}
@@ -0,0 +1,309 @@
+// Copyright (c) 2025 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions.
+// For more details, see the LICENSE, LICENSE.additional, and CONTRIBUTING files.
+
+// Error Numbers of System Calls
+// -----------------------------
+//
+// The instruction `syscall` pushes the `error_number` onto the operand stack, it is
+// the negated return value of the Linux system call (i.e., `-rax`) if the call fails,
+// otherwise it is 0.
+//
+// The numbers are the same as the ones of Linux on x86_64 and AArch64, only the
+// commonly used numbers are listed here.
+//
+// ref:
+// - https://man7.org/linux/man-pages/man3/errno.3.html
+// - https://github.com/torvalds/linux/blob/master/include/uapi/asm-generic/errno-base.h
+// - https://github.com/torvalds/linux/blob/master/include/uapi/asm-generic/errno.h
+
+use std::fmt::Display;
+
+#[repr(u32)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum Errno {
+    EPERM = 1,
+    ENOENT = 2,
+    ESRCH = 3,
+    EINTR = 4,
+    EIO = 5,
+    ENXIO = 6,
+    E2BIG = 7,
+    ENOEXEC = 8,
+    EBADF = 9,
+    ECHILD = 10,
+    EAGAIN = 11,
+    ENOMEM = 12,
+    EACCES = 13,
+    EFAULT = 14,
+    ENOTBLK = 15,
+    EBUSY = 16,
+    EEXIST = 17,
+    EXDEV = 18,
+    ENODEV = 19,
+    ENOTDIR = 20,
+    EISDIR = 21,
+    EINVAL = 22,
+    ENFILE = 23,
+    EMFILE = 24,
+    ENOTTY = 25,
+    ETXTBSY = 26,
+    EFBIG = 27,
+    ENOSPC = 28,
+    ESPIPE = 29,
+    EROFS = 30,
+    EMLINK = 31,
+    EPIPE = 32,
+    EDOM = 33,
+    ERANGE = 34,
+    EDEADLK = 35,
+    ENAMETOOLONG = 36,
+    ENOLCK = 37,
+    ENOSYS = 38,
+    ENOTEMPTY = 39,
+    ELOOP = 40,
+    ENOTSOCK = 88,
+    EOPNOTSUPP = 95,
+    EADDRINUSE = 98,
+    EADDRNOTAVAIL = 99,
+    ENETDOWN = 100,
+    ENETUNREACH = 101,
+    ECONNABORTED = 103,
+    ECONNRESET = 104,
+    EISCONN = 106,
+    ENOTCONN = 107,
+    ETIMEDOUT = 110,
+    ECONNREFUSED = 111,
+    EHOSTUNREACH = 113,
+    EALREADY = 114,
+    EINPROGRESS = 115,
+}
+
+impl Errno {
+    /// Returns `None` if the number is 0 or unknown.
+    pub fn from_u32(value: u32) -> Option<Self> {
+        let errno = match value {
+            1 => Errno::EPERM,
+            2 => Errno::ENOENT,
+            3 => Errno::ESRCH,
+            4 => Errno::EINTR,
+            5 => Errno::EIO,
+            6 => Errno::ENXIO,
+            7 => Errno::E2BIG,
+            8 => Errno::ENOEXEC,
+            9 => Errno::EBADF,
+            10 => Errno::ECHILD,
+            11 => Errno::EAGAIN,
+            12 => Errno::ENOMEM,
+            13 => Errno::EACCES,
+            14 => Errno::EFAULT,
+            15 => Errno::ENOTBLK,
+            16 => Errno::EBUSY,
+            17 => Errno::EEXIST,
+            18 => Errno::EXDEV,
+            19 => Errno::ENODEV,
+            20 => Errno::ENOTDIR,
+            21 => Errno::EISDIR,
+            22 => Errno::EINVAL,
+            23 => Errno::ENFILE,
+            24 => Errno::EMFILE,
+            25 => Errno::ENOTTY,
+            26 => Errno::ETXTBSY,
+            27 => Errno::EFBIG,
+            28 => Errno::ENOSPC,
+            29 => Errno::ESPIPE,
+            30 => Errno::EROFS,
+            31 => Errno::EMLINK,
+            32 => Errno::EPIPE,
+            33 => Errno::EDOM,
+            34 => Errno::ERANGE,
+            35 => Errno::EDEADLK,
+            36 => Errno::ENAMETOOLONG,
+            37 => Errno::ENOLCK,
+            38 => Errno::ENOSYS,
+            39 => Errno::ENOTEMPTY,
+            40 => Errno::ELOOP,
+            88 => Errno::ENOTSOCK,
+            95 => Errno::EOPNOTSUPP,
+            98 => Errno::EADDRINUSE,
+            99 => Errno::EADDRNOTAVAIL,
+            100 => Errno::ENETDOWN,
+            101 => Errno::ENETUNREACH,
+            103 => Errno::ECONNABORTED,
+            104 => Errno::ECONNRESET,
+            106 => Errno::EISCONN,
+            107 => Errno::ENOTCONN,
+            110 => Errno::ETIMEDOUT,
+            111 => Errno::ECONNREFUSED,
+            113 => Errno::EHOSTUNREACH,
+            114 => Errno::EALREADY,
+            115 => Errno::EINPROGRESS,
+            _ => return None,
+        };
+        Some(errno)
+    }
+
+    /// Converts the return value of a system call (i.e., the value of `rax`) into
+    /// an error number, returns `None` if the call succeeded or the number is unknown.
+    pub fn from_return_value(value: i64) -> Option<Self> {
+        if value < 0 && value >= -(u32::MAX as i64) {
+            Errno::from_u32((-value) as u32)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the symbolic name, e.g. "ENOENT".
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Errno::EPERM => "EPERM",
+            Errno::ENOENT => "ENOENT",
+            Errno::ESRCH => "ESRCH",
+            Errno::EINTR => "EINTR",
+            Errno::EIO => "EIO",
+            Errno::ENXIO => "ENXIO",
+            Errno::E2BIG => "E2BIG",
+            Errno::ENOEXEC => "ENOEXEC",
+            Errno::EBADF => "EBADF",
+            Errno::ECHILD => "ECHILD",
+            Errno::EAGAIN => "EAGAIN",
+            Errno::ENOMEM => "ENOMEM",
+            Errno::EACCES => "EACCES",
+            Errno::EFAULT => "EFAULT",
+            Errno::ENOTBLK => "ENOTBLK",
+            Errno::EBUSY => "EBUSY",
+            Errno::EEXIST => "EEXIST",
+            Errno::EXDEV => "EXDEV",
+            Errno::ENODEV => "ENODEV",
+            Errno::ENOTDIR => "ENOTDIR",
+            Errno::EISDIR => "EISDIR",
+            Errno::EINVAL => "EINVAL",
+            Errno::ENFILE => "ENFILE",
+            Errno::EMFILE => "EMFILE",
+            Errno::ENOTTY => "ENOTTY",
+            Errno::ETXTBSY => "ETXTBSY",
+            Errno::EFBIG => "EFBIG",
+            Errno::ENOSPC => "ENOSPC",
+            Errno::ESPIPE => "ESPIPE",
+            Errno::EROFS => "EROFS",
+            Errno::EMLINK => "EMLINK",
+            Errno::EPIPE => "EPIPE",
+            Errno::EDOM => "EDOM",
+            Errno::ERANGE => "ERANGE",
+            Errno::EDEADLK => "EDEADLK",
+            Errno::ENAMETOOLONG => "ENAMETOOLONG",
+            Errno::ENOLCK => "ENOLCK",
+            Errno::ENOSYS => "ENOSYS",
+            Errno::ENOTEMPTY => "ENOTEMPTY",
+            Errno::ELOOP => "ELOOP",
+            Errno::ENOTSOCK => "ENOTSOCK",
+            Errno::EOPNOTSUPP => "EOPNOTSUPP",
+            Errno::EADDRINUSE => "EADDRINUSE",
+            Errno::EADDRNOTAVAIL => "EADDRNOTAVAIL",
+            Errno::ENETDOWN => "ENETDOWN",
+            Errno::ENETUNREACH => "ENETUNREACH",
+            Errno::ECONNABORTED => "ECONNABORTED",
+            Errno::ECONNRESET => "ECONNRESET",
+            Errno::EISCONN => "EISCONN",
+            Errno::ENOTCONN => "ENOTCONN",
+            Errno::ETIMEDOUT => "ETIMEDOUT",
+            Errno::ECONNREFUSED => "ECONNREFUSED",
+            Errno::EHOSTUNREACH => "EHOSTUNREACH",
+            Errno::EALREADY => "EALREADY",
+            Errno::EINPROGRESS => "EINPROGRESS",
+        }
+    }
+
+    /// Returns the description, e.g. "no such file or directory".
+    pub fn get_message(&self) -> &'static str {
+        match self {
+            Errno::EPERM => "operation not permitted",
+            Errno::ENOENT => "no such file or directory",
+            Errno::ESRCH => "no such process",
+            Errno::EINTR => "interrupted system call",
+            Errno::EIO => "input/output error",
+            Errno::ENXIO => "no such device or address",
+            Errno::E2BIG => "argument list too long",
+            Errno::ENOEXEC => "exec format error",
+            Errno::EBADF => "bad file descriptor",
+            Errno::ECHILD => "no child processes",
+            Errno::EAGAIN => "resource temporarily unavailable",
+            Errno::ENOMEM => "cannot allocate memory",
+            Errno::EACCES => "permission denied",
+            Errno::EFAULT => "bad address",
+            Errno::ENOTBLK => "block device required",
+            Errno::EBUSY => "device or resource busy",
+            Errno::EEXIST => "file exists",
+            Errno::EXDEV => "invalid cross-device link",
+            Errno::ENODEV => "no such device",
+            Errno::ENOTDIR => "not a directory",
+            Errno::EISDIR => "is a directory",
+            Errno::EINVAL => "invalid argument",
+            Errno::ENFILE => "too many open files in system",
+            Errno::EMFILE => "too many open files",
+            Errno::ENOTTY => "inappropriate ioctl for device",
+            Errno::ETXTBSY => "text file busy",
+            Errno::EFBIG => "file too large",
+            Errno::ENOSPC => "no space left on device",
+            Errno::ESPIPE => "illegal seek",
+            Errno::EROFS => "read-only file system",
+            Errno::EMLINK => "too many links",
+            Errno::EPIPE => "broken pipe",
+            Errno::EDOM => "numerical argument out of domain",
+            Errno::ERANGE => "numerical result out of range",
+            Errno::EDEADLK => "resource deadlock avoided",
+            Errno::ENAMETOOLONG => "file name too long",
+            Errno::ENOLCK => "no locks available",
+            Errno::ENOSYS => "function not implemented",
+            Errno::ENOTEMPTY => "directory not empty",
+            Errno::ELOOP => "too many levels of symbolic links",
+            Errno::ENOTSOCK => "socket operation on non-socket",
+            Errno::EOPNOTSUPP => "operation not supported",
+            Errno::EADDRINUSE => "address already in use",
+            Errno::EADDRNOTAVAIL => "cannot assign requested address",
+            Errno::ENETDOWN => "network is down",
+            Errno::ENETUNREACH => "network is unreachable",
+            Errno::ECONNABORTED => "software caused connection abort",
+            Errno::ECONNRESET => "connection reset by peer",
+            Errno::EISCONN => "transport endpoint is already connected",
+            Errno::ENOTCONN => "transport endpoint is not connected",
+            Errno::ETIMEDOUT => "connection timed out",
+            Errno::ECONNREFUSED => "connection refused",
+            Errno::EHOSTUNREACH => "no route to host",
+            Errno::EALREADY => "operation already in progress",
+            Errno::EINPROGRESS => "operation now in progress",
+        }
+    }
+}
+
+impl Display for Errno {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.get_message(), self.get_name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::errno::Errno;
+
+    #[test]
+    fn test_errno() {
+        assert_eq!(Errno::from_u32(2), Some(Errno::ENOENT));
+        assert_eq!(Errno::from_u32(0), None);
+        assert_eq!(Errno::from_return_value(-13), Some(Errno::EACCES));
+        assert_eq!(Errno::from_return_value(13), None);
+        assert_eq!(Errno::from_return_value(i64::MIN), None);
+
+        assert_eq!(Errno::EBADF as u32, 9);
+        assert_eq!(Errno::ETIMEDOUT.get_name(), "ETIMEDOUT");
+        assert_eq!(
+            Errno::ENOENT.to_string(),
+            "no such file or directory (ENOENT)"
+        );
+    }
+}
@@ -0,0 +1,109 @@
+// Copyright (c) 2025 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions.
+// For more details, see the LICENSE, LICENSE.additional, and CONTRIBUTING files.
+
+// ISA Fingerprint
+// ---------------
+//
+// The ISA fingerprint is a 64-bit hash over the opcode numbering, the instruction formats,
+// and the envcall catalog. Compilers embed the fingerprint of their ISA into the image,
+// and runtimes compare it with their own fingerprint at load time, to detect mismatches
+// between the toolchain and the runtime which editions and image format versions
+// do not capture (e.g., a development build with renumbered opcodes).
+//
+// The hash algorithm is 64-bit FNV-1a, and the input is encoded in a fixed order
+// (ascending numbers), so the fingerprint is stable across platforms and Rust versions.
+
+use crate::{
+    envcall::EnvCallNum,
+    opcode::{InstructionFormat, Opcode},
+};
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+struct Fnv1aHasher {
+    state: u64,
+}
+
+impl Fnv1aHasher {
+    fn new() -> Self {
+        Self {
+            state: FNV_OFFSET_BASIS,
+        }
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.state ^= *byte as u64;
+            self.state = self.state.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    /// Writes the string and a NUL terminator, so that adjacent strings
+    /// are not ambiguous.
+    fn write_str(&mut self, text: &str) {
+        self.write(text.as_bytes());
+        self.write(&[0]);
+    }
+
+    fn finish(&self) -> u64 {
+        self.state
+    }
+}
+
+fn get_format_code(format: InstructionFormat) -> u8 {
+    match format {
+        InstructionFormat::NoParams => 0,
+        InstructionFormat::I16 => 1,
+        InstructionFormat::I32 => 2,
+        InstructionFormat::I16I32 => 3,
+        InstructionFormat::I32I32 => 4,
+        InstructionFormat::I32I32I32 => 5,
+    }
+}
+
+/// Returns the fingerprint of the current ISA, see the section "ISA Fingerprint".
+pub fn isa_fingerprint() -> u64 {
+    let mut hasher = Fnv1aHasher::new();
+
+    for value in 0..=u16::MAX {
+        if let Some(opcode) = Opcode::from_u16(value) {
+            hasher.write(&value.to_le_bytes());
+            hasher.write_str(opcode.get_name());
+            hasher.write(&[get_format_code(opcode.get_format())]);
+        }
+    }
+
+    for num in EnvCallNum::get_all() {
+        hasher.write(&(num as u32).to_le_bytes());
+        hasher.write_str(num.get_name());
+    }
+
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::fingerprint::{isa_fingerprint, Fnv1aHasher};
+
+    #[test]
+    fn test_fnv1a() {
+        assert_eq!(Fnv1aHasher::new().finish(), 0xcbf2_9ce4_8422_2325);
+
+        let mut hasher = Fnv1aHasher::new();
+        hasher.write(b"a");
+        assert_eq!(hasher.finish(), 0xaf63_dc4c_8601_ec8c);
+    }
+
+    #[test]
+    fn test_isa_fingerprint() {
+        // this value changes whenever an opcode, an instruction format or an envcall
+        // is added, renumbered or renamed, update it deliberately along with the ISA.
+        assert_eq!(isa_fingerprint(), 0x63af_0122_e593_4bcd);
+    }
+}
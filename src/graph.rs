@@ -0,0 +1,499 @@
+// Copyright (c) 2025 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions.
+// For more details, see the LICENSE, LICENSE.additional, and CONTRIBUTING files.
+
+// Dependency Graph
+// ----------------
+//
+// The dependency graph of an application, which is shared by the resolver, the linker
+// and the build scheduler. Each module appears in the graph only once (see the section
+// "Version Conflicts" of the crate), so the nodes are keyed by the module name,
+// and an edge points from the dependent to the dependency.
+//
+// The nodes are stored in the alphabetical order of names, so that the traversal
+// (e.g., the topological order) is deterministic.
+//
+// DOT Export
+// ----------
+//
+// The graph can be rendered to the Graphviz DOT language by `to_dot`, e.g.:
+//
+// ```dot
+// digraph dependencies {
+//     "app" [label="app\nroot"];
+//     "sha2" [label="sha2\n1.2.3\nshare"];
+//     "app" -> "sha2" [label="^1.2.0"];
+// }
+// ```
+//
+// The nodes are labeled with the name, the selected version (if any) and the source,
+// and the edges are labeled with the version requirement (if any) and the kind
+// of non-normal dependencies.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Display,
+};
+
+use crate::{version::VersionRequirement, DependencyKind, EffectiveVersion, ModuleDependency};
+
+const DOT_INDENT: &str = "    ";
+
+/// The identity of a module in the dependency graph.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, PartialOrd, Ord)]
+pub struct ModuleId {
+    pub name: String,
+}
+
+impl ModuleId {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+        }
+    }
+}
+
+impl Display for ModuleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.name)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct DependencyNode {
+    pub id: ModuleId,
+
+    /// The source of the module, it is `ModuleDependency::Current` for the root module.
+    pub source: ModuleDependency,
+
+    /// The selected version, it is `None` for the modules without version information
+    /// (e.g., local and remote modules).
+    pub version: Option<EffectiveVersion>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct DependencyEdge {
+    /// The dependent.
+    pub from: ModuleId,
+
+    /// The dependency.
+    pub to: ModuleId,
+
+    pub kind: DependencyKind,
+
+    /// The version requirement declared by the dependent, it is `None` for
+    /// the dependencies without version information.
+    pub requirement: Option<VersionRequirement>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum GraphError {
+    /// The edge refers to a module which is not in the graph.
+    UndefinedNode { id: ModuleId },
+
+    /// The modules on the cycle, the first module is repeated at the end,
+    /// e.g. `[a, b, a]`.
+    Cycle { modules: Vec<ModuleId> },
+}
+
+impl Display for GraphError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GraphError::UndefinedNode { id } => {
+                write!(f, "Module \"{}\" is not in the dependency graph.", id)
+            }
+            GraphError::Cycle { modules } => {
+                let names = modules
+                    .iter()
+                    .map(|id| id.name.as_str())
+                    .collect::<Vec<_>>();
+                write!(f, "Circular dependency: {}.", names.join(" -> "))
+            }
+        }
+    }
+}
+
+impl std::error::Error for GraphError {}
+
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct DependencyGraph {
+    nodes: BTreeMap<ModuleId, DependencyNode>,
+    edges: Vec<DependencyEdge>,
+}
+
+impl DependencyGraph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the node, or replaces the node with the same id and returns the old one.
+    pub fn add_node(&mut self, node: DependencyNode) -> Option<DependencyNode> {
+        self.nodes.insert(node.id.clone(), node)
+    }
+
+    /// Both the dependent and the dependency must be added before adding the edge.
+    pub fn add_edge(&mut self, edge: DependencyEdge) -> Result<(), GraphError> {
+        for id in [&edge.from, &edge.to] {
+            if !self.nodes.contains_key(id) {
+                return Err(GraphError::UndefinedNode { id: id.clone() });
+            }
+        }
+
+        self.edges.push(edge);
+        Ok(())
+    }
+
+    pub fn get_node(&self, id: &ModuleId) -> Option<&DependencyNode> {
+        self.nodes.get(id)
+    }
+
+    /// Returns the nodes in the alphabetical order of names.
+    pub fn get_nodes(&self) -> impl Iterator<Item = &DependencyNode> {
+        self.nodes.values()
+    }
+
+    /// Returns the edges in the order of addition.
+    pub fn get_edges(&self) -> &[DependencyEdge] {
+        &self.edges
+    }
+
+    /// Returns the edges from the module to its dependencies.
+    pub fn get_dependencies<'a>(
+        &'a self,
+        id: &'a ModuleId,
+    ) -> impl Iterator<Item = &'a DependencyEdge> + 'a {
+        self.edges.iter().filter(move |edge| edge.from == *id)
+    }
+
+    /// Returns the edges from the dependents to the module.
+    pub fn get_dependents<'a>(
+        &'a self,
+        id: &'a ModuleId,
+    ) -> impl Iterator<Item = &'a DependencyEdge> + 'a {
+        self.edges.iter().filter(move |edge| edge.to == *id)
+    }
+
+    /// Returns a cycle of the graph if there is one, see `GraphError::Cycle`.
+    pub fn find_cycle(&self) -> Option<Vec<ModuleId>> {
+        // the modules which are completely visited.
+        let mut visited: BTreeSet<&ModuleId> = BTreeSet::new();
+
+        for start in self.nodes.keys() {
+            if visited.contains(start) {
+                continue;
+            }
+
+            // depth-first search with an explicit stack of (module, index of next edge),
+            // the modules on the stack are the current path.
+            let mut stack: Vec<(&ModuleId, usize)> = vec![(start, 0)];
+
+            while let Some((id, index)) = stack.last_mut() {
+                let next = self.get_dependencies(id).nth(*index).map(|edge| &edge.to);
+                *index += 1;
+
+                match next {
+                    None => {
+                        visited.insert(*id);
+                        stack.pop();
+                    }
+                    Some(to) => {
+                        if let Some(position) = stack.iter().position(|(item, _)| *item == to) {
+                            let mut modules = stack[position..]
+                                .iter()
+                                .map(|(item, _)| (*item).clone())
+                                .collect::<Vec<_>>();
+                            modules.push(to.clone());
+                            return Some(modules);
+                        }
+
+                        if !visited.contains(to) {
+                            stack.push((to, 0));
+                        }
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Returns the modules in the build order, i.e., the dependencies come before
+    /// the dependents, and the modules which are ready at the same time are in
+    /// the alphabetical order.
+    pub fn topological_sort(&self) -> Result<Vec<&ModuleId>, GraphError> {
+        // the number of the unsorted dependencies of each module.
+        let mut pending: BTreeMap<&ModuleId, usize> = self.nodes.keys().map(|id| (id, 0)).collect();
+        for edge in &self.edges {
+            *pending.get_mut(&edge.from).unwrap() += 1;
+        }
+
+        let mut ready = pending
+            .iter()
+            .filter(|(_, count)| **count == 0)
+            .map(|(id, _)| *id)
+            .collect::<BTreeSet<_>>();
+        let mut sorted = vec![];
+
+        while let Some(id) = ready.pop_first() {
+            sorted.push(id);
+
+            for edge in self.get_dependents(id) {
+                let count = pending.get_mut(&edge.from).unwrap();
+                *count -= 1;
+                if *count == 0 {
+                    ready.insert(&edge.from);
+                }
+            }
+        }
+
+        if sorted.len() == self.nodes.len() {
+            Ok(sorted)
+        } else {
+            // the unsorted modules are on or after a cycle.
+            Err(GraphError::Cycle {
+                modules: self.find_cycle().unwrap_or_default(),
+            })
+        }
+    }
+}
+
+fn escape_dot_string(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+pub(crate) fn get_source_label(source: &ModuleDependency) -> String {
+    match source {
+        ModuleDependency::Local(dependency) => format!("local:{}", dependency.path),
+        ModuleDependency::Remote(dependency) => {
+            format!("remote:{}#{}", dependency.url, dependency.reference)
+        }
+        ModuleDependency::Share(_) => "share".to_owned(),
+        ModuleDependency::Runtime => "runtime".to_owned(),
+        ModuleDependency::Current => "root".to_owned(),
+        ModuleDependency::Vendored(dependency) => format!("vendored:{}", dependency.path),
+    }
+}
+
+impl DependencyGraph {
+    /// Renders the graph to the DOT language, see the section "DOT Export".
+    pub fn to_dot(&self) -> String {
+        let mut lines = vec!["digraph dependencies {".to_owned()];
+
+        for node in self.get_nodes() {
+            let mut label = vec![node.id.name.to_owned()];
+            if let Some(version) = &node.version {
+                label.push(version.to_string());
+            }
+            label.push(get_source_label(&node.source));
+
+            let label = label
+                .iter()
+                .map(|item| escape_dot_string(item))
+                .collect::<Vec<_>>();
+            lines.push(format!(
+                "{}\"{}\" [label=\"{}\"];",
+                DOT_INDENT,
+                escape_dot_string(&node.id.name),
+                label.join("\\n")
+            ));
+        }
+
+        for edge in &self.edges {
+            let mut label = vec![];
+            if let Some(requirement) = &edge.requirement {
+                label.push(requirement.to_string());
+            }
+            match edge.kind {
+                DependencyKind::Normal => {}
+                DependencyKind::Dev => label.push("dev".to_owned()),
+                DependencyKind::Build => label.push("build".to_owned()),
+            }
+
+            let attributes = if label.is_empty() {
+                String::new()
+            } else {
+                format!(" [label=\"{}\"]", escape_dot_string(&label.join(", ")))
+            };
+            lines.push(format!(
+                "{}\"{}\" -> \"{}\"{};",
+                DOT_INDENT,
+                escape_dot_string(&edge.from.name),
+                escape_dot_string(&edge.to.name),
+                attributes
+            ));
+        }
+
+        lines.push("}".to_owned());
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        graph::{DependencyEdge, DependencyGraph, DependencyNode, GraphError, ModuleId},
+        version::VersionRequirement,
+        DependencyCondition, DependencyKind, DependencyLocal, DependencyShare, EffectiveVersion,
+        ModuleDependency,
+    };
+
+    fn build_graph(names: &[&str], edges: &[(&str, &str)]) -> DependencyGraph {
+        let mut graph = DependencyGraph::new();
+        for name in names {
+            graph.add_node(DependencyNode {
+                id: ModuleId::new(name),
+                source: ModuleDependency::Current,
+                version: None,
+            });
+        }
+        for (from, to) in edges {
+            graph
+                .add_edge(DependencyEdge {
+                    from: ModuleId::new(from),
+                    to: ModuleId::new(to),
+                    kind: DependencyKind::Normal,
+                    requirement: None,
+                })
+                .unwrap();
+        }
+        graph
+    }
+
+    #[test]
+    fn test_topological_sort() {
+        let graph = build_graph(
+            &["app", "module_a", "module_b", "common"],
+            &[
+                ("app", "module_b"),
+                ("app", "module_a"),
+                ("module_a", "common"),
+                ("module_b", "common"),
+            ],
+        );
+
+        let names = graph
+            .topological_sort()
+            .unwrap()
+            .iter()
+            .map(|id| id.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["common", "module_a", "module_b", "app"]);
+        assert_eq!(graph.find_cycle(), None);
+
+        assert_eq!(
+            graph
+                .get_dependents(&ModuleId::new("common"))
+                .map(|edge| edge.from.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["module_a", "module_b"]
+        );
+
+        let mut graph = graph;
+        assert_eq!(
+            graph.add_edge(DependencyEdge {
+                from: ModuleId::new("app"),
+                to: ModuleId::new("missing"),
+                kind: DependencyKind::Normal,
+                requirement: None,
+            }),
+            Err(GraphError::UndefinedNode {
+                id: ModuleId::new("missing")
+            })
+        );
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        let graph = build_graph(
+            &["app", "module_a", "module_b", "module_c"],
+            &[
+                ("app", "module_a"),
+                ("module_a", "module_b"),
+                ("module_b", "module_c"),
+                ("module_c", "module_a"),
+            ],
+        );
+
+        let cycle = vec![
+            ModuleId::new("module_a"),
+            ModuleId::new("module_b"),
+            ModuleId::new("module_c"),
+            ModuleId::new("module_a"),
+        ];
+        assert_eq!(graph.find_cycle(), Some(cycle.clone()));
+        assert_eq!(
+            graph.topological_sort(),
+            Err(GraphError::Cycle { modules: cycle })
+        );
+
+        let graph = build_graph(&["app"], &[("app", "app")]);
+        assert_eq!(
+            graph.find_cycle(),
+            Some(vec![ModuleId::new("app"), ModuleId::new("app")])
+        );
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let mut graph = DependencyGraph::new();
+        graph.add_node(DependencyNode {
+            id: ModuleId::new("app"),
+            source: ModuleDependency::Current,
+            version: None,
+        });
+        graph.add_node(DependencyNode {
+            id: ModuleId::new("sha2"),
+            source: ModuleDependency::Share(Box::new(DependencyShare {
+                version: "1.2".parse().unwrap(),
+                checksum: None,
+                parameters: Default::default(),
+                condition: DependencyCondition::True,
+                kind: DependencyKind::Normal,
+                optional: false,
+            })),
+            version: Some(EffectiveVersion::new(1, 2, 3)),
+        });
+        graph.add_node(DependencyNode {
+            id: ModuleId::new("utils"),
+            source: ModuleDependency::Local(Box::new(DependencyLocal {
+                path: "../my \"utils\"".to_owned(),
+                parameters: Default::default(),
+                condition: DependencyCondition::True,
+                kind: DependencyKind::Dev,
+                optional: false,
+            })),
+            version: None,
+        });
+        graph
+            .add_edge(DependencyEdge {
+                from: ModuleId::new("app"),
+                to: ModuleId::new("sha2"),
+                kind: DependencyKind::Normal,
+                requirement: Some(VersionRequirement::from_requirement_string("1.2").unwrap()),
+            })
+            .unwrap();
+        graph
+            .add_edge(DependencyEdge {
+                from: ModuleId::new("app"),
+                to: ModuleId::new("utils"),
+                kind: DependencyKind::Dev,
+                requirement: None,
+            })
+            .unwrap();
+
+        assert_eq!(
+            graph.to_dot(),
+            r#"digraph dependencies {
+    "app" [label="app\nroot"];
+    "sha2" [label="sha2\n1.2.3\nshare"];
+    "utils" [label="utils\nlocal:../my \"utils\""];
+    "app" -> "sha2" [label="^1.2.0"];
+    "app" -> "utils" [label="dev"];
+}"#
+        );
+    }
+}
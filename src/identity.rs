@@ -0,0 +1,473 @@
+// Copyright (c) 2025 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions.
+// For more details, see the LICENSE, LICENSE.additional, and CONTRIBUTING files.
+
+// Dependency Identity
+// -------------------
+//
+// Dependencies implement `Eq` and `Hash` so that resolvers and caches can use them
+// as map keys. The parameters are hashed in the alphabetical order of names,
+// so the hash does not depend on the order of the parameter map.
+//
+// The content hash (i.e., `get_content_hash`) is the SHA-256 checksum of the canonical
+// form of a dependency, it is stable across platforms, runs and Rust versions,
+// and can be stored in lockfiles and caches.
+//
+// The canonical form is a list of "KEY=VALUE" lines, where the string values and
+// the parameter names are quoted as the strings of condition expressions (with '\', '"'
+// and the control characters escaped, e.g. a line break is written as "\n", see the
+// module `condition`), a string list is written as `["a", "b"]`, the parameters are in
+// the alphabetical order of names, and the condition is written as a condition expression, e.g.:
+//
+// ```text
+// module::local
+// path="../sha2"
+// parameter:"enable_asm"=true
+// condition=true
+// kind=normal
+// optional=false
+// ```
+//
+// Same Source
+// -----------
+//
+// The local and remote modules and libraries lack version information, they are
+// the same only if their sources match, see the section "About the Version of
+// Shared Modules" of the crate, i.e.:
+//
+// - local: the same normalized path, see the module `path`.
+// - remote: the same URL, Git reference and directory.
+// - vendored: the same archive path and checksum.
+// - system: the same lookup.
+// - share: always the same, the versions are selected by the version requirements.
+
+use std::{
+    collections::HashMap,
+    hash::{Hash, Hasher},
+};
+
+use crate::{
+    checksum::{Checksum, ChecksumAlgorithm},
+    condition::quote,
+    DependencyCondition, DependencyKind, DependencyLocal, DependencyParameterValue,
+    DependencyRemote, DependencyShare, DependencySystem, DependencyVendored,
+    ExternalLibraryDependency, ModuleDependency, SystemLibraryLookup,
+};
+
+fn hash_parameters<H: Hasher>(
+    parameters: &HashMap<String, DependencyParameterValue>,
+    state: &mut H,
+) {
+    let mut items = parameters.iter().collect::<Vec<_>>();
+    items.sort_unstable_by_key(|(name, _)| *name);
+    items.hash(state);
+}
+
+impl Hash for DependencyLocal {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.path.hash(state);
+        hash_parameters(&self.parameters, state);
+        self.condition.hash(state);
+        self.kind.hash(state);
+        self.optional.hash(state);
+    }
+}
+
+impl Hash for DependencyRemote {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.url.hash(state);
+        self.reference.hash(state);
+        self.dir.hash(state);
+        self.checksum.hash(state);
+        hash_parameters(&self.parameters, state);
+        self.condition.hash(state);
+        self.kind.hash(state);
+        self.optional.hash(state);
+    }
+}
+
+impl Hash for DependencyShare {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.version.hash(state);
+        self.checksum.hash(state);
+        hash_parameters(&self.parameters, state);
+        self.condition.hash(state);
+        self.kind.hash(state);
+        self.optional.hash(state);
+    }
+}
+
+impl Hash for DependencyVendored {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.path.hash(state);
+        self.checksum.hash(state);
+        hash_parameters(&self.parameters, state);
+        self.condition.hash(state);
+        self.kind.hash(state);
+        self.optional.hash(state);
+    }
+}
+
+/// Writes the canonical form, see the section "Dependency Identity".
+struct CanonicalWriter {
+    text: String,
+}
+
+impl CanonicalWriter {
+    fn new(header: &str) -> Self {
+        Self {
+            text: header.to_owned(),
+        }
+    }
+
+    fn write(&mut self, key: &str, value: &str) {
+        self.text.push('\n');
+        self.text.push_str(key);
+        self.text.push('=');
+        self.text.push_str(value);
+    }
+
+    fn write_string(&mut self, key: &str, value: &str) {
+        self.write(key, &quote(value));
+    }
+
+    fn write_optional_string(&mut self, key: &str, value: Option<&str>) {
+        if let Some(value) = value {
+            self.write_string(key, value);
+        }
+    }
+
+    fn write_checksum(&mut self, checksum: Option<&Checksum>) {
+        if let Some(checksum) = checksum {
+            self.write("checksum", &checksum.to_string());
+        }
+    }
+
+    fn write_settings(
+        &mut self,
+        parameters: Option<&HashMap<String, DependencyParameterValue>>,
+        condition: &DependencyCondition,
+        kind: DependencyKind,
+        optional: bool,
+    ) {
+        if let Some(parameters) = parameters {
+            let mut items = parameters.iter().collect::<Vec<_>>();
+            items.sort_unstable_by_key(|(name, _)| *name);
+
+            for (name, value) in items {
+                let key = format!("parameter:{}", quote(name));
+                match value {
+                    DependencyParameterValue::String(value) => self.write_string(&key, value),
+                    DependencyParameterValue::Number(value) => self.write(&key, &value.to_string()),
+                    DependencyParameterValue::Bool(value) => self.write(&key, &value.to_string()),
+                    DependencyParameterValue::StringList(values) => {
+                        let items = values.iter().map(|value| quote(value)).collect::<Vec<_>>();
+                        self.write(&key, &format!("[{}]", items.join(", ")))
+                    }
+                    DependencyParameterValue::From(property) => {
+                        self.write(&key, &format!("from({})", quote(property)))
+                    }
+                }
+            }
+        }
+
+        self.write("condition", &condition.to_string());
+        self.write(
+            "kind",
+            match kind {
+                DependencyKind::Normal => "normal",
+                DependencyKind::Dev => "dev",
+                DependencyKind::Build => "build",
+            },
+        );
+        self.write("optional", &optional.to_string());
+    }
+
+    fn write_local(&mut self, dependency: &DependencyLocal) {
+        self.write_string("path", &dependency.path);
+        self.write_settings(
+            Some(&dependency.parameters),
+            &dependency.condition,
+            dependency.kind,
+            dependency.optional,
+        );
+    }
+
+    fn write_remote(&mut self, dependency: &DependencyRemote) {
+        self.write_string("url", &dependency.url);
+        self.write_string("reference", &dependency.reference.to_string());
+        self.write_optional_string("dir", dependency.dir.as_deref());
+        self.write_checksum(dependency.checksum.as_ref());
+        self.write_settings(
+            Some(&dependency.parameters),
+            &dependency.condition,
+            dependency.kind,
+            dependency.optional,
+        );
+    }
+
+    fn write_share(&mut self, dependency: &DependencyShare) {
+        self.write_string("version", dependency.version.as_str());
+        self.write_checksum(dependency.checksum.as_ref());
+        self.write_settings(
+            Some(&dependency.parameters),
+            &dependency.condition,
+            dependency.kind,
+            dependency.optional,
+        );
+    }
+
+    fn write_vendored(&mut self, dependency: &DependencyVendored) {
+        self.write_string("path", &dependency.path);
+        self.write_checksum(Some(&dependency.checksum));
+        self.write_settings(
+            Some(&dependency.parameters),
+            &dependency.condition,
+            dependency.kind,
+            dependency.optional,
+        );
+    }
+
+    fn write_system(&mut self, dependency: &DependencySystem) {
+        match &dependency.lookup {
+            SystemLibraryLookup::Soname(name) => self.write_string("soname", name),
+            SystemLibraryLookup::PkgConfig(name) => self.write_string("pkg_config", name),
+        }
+        self.write_optional_string("min_version", dependency.min_version.as_deref());
+        self.write_settings(
+            None,
+            &dependency.condition,
+            dependency.kind,
+            dependency.optional,
+        );
+    }
+
+    fn finish(self) -> Checksum {
+        Checksum::compute(ChecksumAlgorithm::Sha256, self.text.as_bytes())
+    }
+}
+
+impl ModuleDependency {
+    /// Returns the SHA-256 checksum of the canonical form,
+    /// see the section "Dependency Identity".
+    pub fn get_content_hash(&self) -> Checksum {
+        match self {
+            ModuleDependency::Local(dependency) => {
+                let mut writer = CanonicalWriter::new("module::local");
+                writer.write_local(dependency);
+                writer.finish()
+            }
+            ModuleDependency::Remote(dependency) => {
+                let mut writer = CanonicalWriter::new("module::remote");
+                writer.write_remote(dependency);
+                writer.finish()
+            }
+            ModuleDependency::Share(dependency) => {
+                let mut writer = CanonicalWriter::new("module::share");
+                writer.write_share(dependency);
+                writer.finish()
+            }
+            ModuleDependency::Runtime => CanonicalWriter::new("module::runtime").finish(),
+            ModuleDependency::Current => CanonicalWriter::new("module::module").finish(),
+            ModuleDependency::Vendored(dependency) => {
+                let mut writer = CanonicalWriter::new("module::vendored");
+                writer.write_vendored(dependency);
+                writer.finish()
+            }
+        }
+    }
+
+    /// Checks whether the two dependencies have the same source, see the section "Same Source".
+    /// `Runtime` and `Current` are the same as themselves only.
+    pub fn is_same_source(&self, other: &ModuleDependency) -> bool {
+        match (self, other) {
+            (ModuleDependency::Local(left), ModuleDependency::Local(right)) => {
+                left.get_normalized_path() == right.get_normalized_path()
+            }
+            (ModuleDependency::Remote(left), ModuleDependency::Remote(right)) => {
+                is_same_remote(left, right)
+            }
+            (ModuleDependency::Share(_), ModuleDependency::Share(_))
+            | (ModuleDependency::Runtime, ModuleDependency::Runtime)
+            | (ModuleDependency::Current, ModuleDependency::Current) => true,
+            (ModuleDependency::Vendored(left), ModuleDependency::Vendored(right)) => {
+                is_same_vendored(left, right)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl ExternalLibraryDependency {
+    /// Returns the SHA-256 checksum of the canonical form,
+    /// see the section "Dependency Identity".
+    pub fn get_content_hash(&self) -> Checksum {
+        match self {
+            ExternalLibraryDependency::Local(dependency) => {
+                let mut writer = CanonicalWriter::new("library::local");
+                writer.write_local(dependency);
+                writer.finish()
+            }
+            ExternalLibraryDependency::Remote(dependency) => {
+                let mut writer = CanonicalWriter::new("library::remote");
+                writer.write_remote(dependency);
+                writer.finish()
+            }
+            ExternalLibraryDependency::Share(dependency) => {
+                let mut writer = CanonicalWriter::new("library::share");
+                writer.write_share(dependency);
+                writer.finish()
+            }
+            ExternalLibraryDependency::Runtime => CanonicalWriter::new("library::runtime").finish(),
+            ExternalLibraryDependency::Vendored(dependency) => {
+                let mut writer = CanonicalWriter::new("library::vendored");
+                writer.write_vendored(dependency);
+                writer.finish()
+            }
+            ExternalLibraryDependency::System(dependency) => {
+                let mut writer = CanonicalWriter::new("library::system");
+                writer.write_system(dependency);
+                writer.finish()
+            }
+        }
+    }
+
+    /// Checks whether the two dependencies have the same source, see the section "Same Source".
+    pub fn is_same_source(&self, other: &ExternalLibraryDependency) -> bool {
+        match (self, other) {
+            (ExternalLibraryDependency::Local(left), ExternalLibraryDependency::Local(right)) => {
+                left.get_normalized_path() == right.get_normalized_path()
+            }
+            (ExternalLibraryDependency::Remote(left), ExternalLibraryDependency::Remote(right)) => {
+                is_same_remote(left, right)
+            }
+            (ExternalLibraryDependency::Share(_), ExternalLibraryDependency::Share(_))
+            | (ExternalLibraryDependency::Runtime, ExternalLibraryDependency::Runtime) => true,
+            (
+                ExternalLibraryDependency::Vendored(left),
+                ExternalLibraryDependency::Vendored(right),
+            ) => is_same_vendored(left, right),
+            (ExternalLibraryDependency::System(left), ExternalLibraryDependency::System(right)) => {
+                left.lookup == right.lookup
+            }
+            _ => false,
+        }
+    }
+}
+
+fn is_same_remote(left: &DependencyRemote, right: &DependencyRemote) -> bool {
+    left.url == right.url && left.reference == right.reference && left.dir == right.dir
+}
+
+fn is_same_vendored(left: &DependencyVendored, right: &DependencyVendored) -> bool {
+    left.path == right.path && left.checksum == right.checksum
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        checksum::{Checksum, ChecksumAlgorithm},
+        condition::quote,
+        DependencyCondition, DependencyKind, DependencyLocal, DependencyParameterValue,
+        ModuleDependency,
+    };
+
+    fn local(path: &str, parameters: Vec<(&str, DependencyParameterValue)>) -> ModuleDependency {
+        ModuleDependency::Local(Box::new(DependencyLocal {
+            path: path.to_owned(),
+            parameters: parameters
+                .into_iter()
+                .map(|(name, value)| (name.to_owned(), value))
+                .collect::<HashMap<_, _>>(),
+            condition: DependencyCondition::True,
+            kind: DependencyKind::Normal,
+            optional: false,
+        }))
+    }
+
+    #[test]
+    fn test_dependency_identity() {
+        let parameters = (0..16)
+            .map(|index| (index.to_string(), DependencyParameterValue::Number(index)))
+            .collect::<Vec<_>>();
+
+        let first = local(
+            "../sha2",
+            parameters
+                .iter()
+                .map(|(name, value)| (name.as_str(), value.clone()))
+                .collect(),
+        );
+        let second = local(
+            "../sha2",
+            parameters
+                .iter()
+                .rev()
+                .map(|(name, value)| (name.as_str(), value.clone()))
+                .collect(),
+        );
+
+        assert_eq!(first, second);
+        assert_eq!(first.get_content_hash(), second.get_content_hash());
+
+        let mut set = HashSet::new();
+        set.insert(first.clone());
+        assert!(set.contains(&second));
+
+        let third = local("../sha2", vec![]);
+        assert!(!set.contains(&third));
+        assert_ne!(first.get_content_hash(), third.get_content_hash());
+
+        // the canonical form is stable
+        assert_eq!(
+            third.get_content_hash(),
+            Checksum::compute(
+                ChecksumAlgorithm::Sha256,
+                b"module::local\npath=\"../sha2\"\ncondition=true\nkind=normal\noptional=false"
+            )
+        );
+
+        assert_eq!(
+            local(
+                "../sha2",
+                vec![("enable_asm", DependencyParameterValue::Bool(true))]
+            )
+            .get_content_hash(),
+            Checksum::compute(
+                ChecksumAlgorithm::Sha256,
+                b"module::local\npath=\"../sha2\"\nparameter:\"enable_asm\"=true\n\
+                condition=true\nkind=normal\noptional=false"
+            )
+        );
+
+        // the names and values which contain the separators do not collide
+        // with other dependencies.
+        assert_ne!(
+            local(
+                "../sha2",
+                vec![("a=true\nparameter:b", DependencyParameterValue::Bool(true))]
+            )
+            .get_content_hash(),
+            local(
+                "../sha2",
+                vec![
+                    ("a", DependencyParameterValue::Bool(true)),
+                    ("b", DependencyParameterValue::Bool(true))
+                ]
+            )
+            .get_content_hash()
+        );
+        assert_eq!(quote("a\n\"b\"\\\u{1}"), r#""a\n\"b\"\\\u{1}""#);
+
+        assert!(first.is_same_source(&third));
+        assert!(first.is_same_source(&local("./../sha2/", vec![])));
+        assert!(!first.is_same_source(&local("../md5", vec![])));
+        assert!(!first.is_same_source(&ModuleDependency::Runtime));
+    }
+}
@@ -4,11 +4,37 @@
 // the Mozilla Public License version 2.0 and additional exceptions.
 // For more details, see the LICENSE, LICENSE.additional, and CONTRIBUTING files.
 
+pub mod bytecode;
+pub mod cache;
+pub mod checksum;
+pub mod condition;
+pub mod ctype;
+pub mod edition;
+pub mod endian;
+pub mod envcall;
+pub mod errno;
+pub mod fingerprint;
+pub mod graph;
+pub mod identity;
+pub mod lock;
+pub mod network;
 pub mod opcode;
-
-use std::{collections::HashMap, fmt::Display};
-
+pub mod overrides;
+pub mod parameter;
+pub mod path;
+pub mod registry;
+pub mod resolver;
+pub mod sbom;
+pub mod signing;
+pub mod version;
+pub mod workspace;
+
+use std::{collections::HashMap, fmt::Display, str::FromStr};
+
+use checksum::Checksum;
+use edition::Edition;
 use serde::{Deserialize, Serialize};
+use version::DeclaredVersionRequirement;
 
 // About Runtime Edition
 // ---------------------
@@ -17,23 +43,37 @@ use serde::{Deserialize, Serialize};
 // Each edition may introduce new syntax and features.
 //
 // Applications and modules must specify a runtime edition. The application and
-// its unit tests can only run if the specified edition matches the runtime edition exactly.
+// its unit tests can only run if the specified edition matches the runtime edition exactly,
+// or the runtime ships compatibility shims for the specified edition (i.e., the edition
+// is listed in `SUPPORTED_EDITIONS`), see `Edition::negotiate`.
 //
 // Note: An edition is not the same as a version number. Editions cannot be compared
 // or assumed to have backward compatibility. For example, a runtime with edition "2028"
-// cannot run applications with editions "2025" or "2030".
+// cannot run applications with editions "2025" or "2030" (unless it ships compatibility
+// shims for the edition "2025").
 //
 // If a module's edition differs from the application's edition, the compiler will
 // attempt to compile it using the application's edition. However, this does not
 // guarantee successful compilation. Developers should ensure module editions
 // are consistent with the application's edition.
-pub const RUNTIME_EDITION: &[u8; 8] = b"2025\0\0\0\0";
+//
+// See the module `edition` for the 8-byte representation.
+pub const RUNTIME_EDITION: Edition = Edition(*b"2025\0\0\0\0");
 pub const RUNTIME_EDITION_STRING: &str = "2025";
 
+/// The editions that the current runtime can run, the first one is `RUNTIME_EDITION`,
+/// and the others are older editions supported by compatibility shims.
+pub const SUPPORTED_EDITIONS: &[Edition] = &[RUNTIME_EDITION];
+
 // Semantic Versioning
 // -------------------
 // - https://semver.org/
-#[derive(Debug, PartialEq, Clone, Copy)]
+/// A version in the format "major.minor.patch".
+///
+/// It is serialized as a string, e.g. "1.2.3".
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(into = "String", try_from = "String")]
 pub struct EffectiveVersion {
     pub major: u16,
     pub minor: u16,
@@ -48,6 +88,28 @@ pub enum VersionCompatibility {
     Conflict,
 }
 
+/// The versions in the same class are compatible with each other.
+///
+/// - For the normal major version, the class is the major version, e.g. "1.x".
+/// - For the zero major version, each minor version is a class, e.g. "0.3.x".
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
+pub enum CompatibilityClass {
+    /// The minor version of a zero major version.
+    ZeroMajor(u16),
+
+    /// The normal (non-zero) major version.
+    Major(u16),
+}
+
+impl Display for CompatibilityClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CompatibilityClass::ZeroMajor(minor) => write!(f, "0.{}.x", minor),
+            CompatibilityClass::Major(major) => write!(f, "{}.x", major),
+        }
+    }
+}
+
 impl EffectiveVersion {
     pub fn new(major: u16, minor: u16, patch: u16) -> Self {
         Self {
@@ -69,18 +131,36 @@ impl EffectiveVersion {
     }
 
     /// Parses a version string in the format "x.y.z".
-    pub fn from_version_string(version: &str) -> Self {
-        let nums = version
-            .split('.')
-            .map(|item| item.parse::<u16>().unwrap()) // Parses each component as a u16.
-            .collect::<Vec<_>>();
-        assert!(nums.len() == 3);
+    pub fn from_version_string(version: &str) -> Result<Self, VersionParseError> {
+        let items = version.split('.').collect::<Vec<_>>();
+        if items.len() != 3 {
+            return Err(VersionParseError::WrongArity {
+                version: version.to_owned(),
+                count: items.len(),
+            });
+        }
 
-        Self {
+        let mut nums = [0u16; 3];
+        for (num, item) in nums.iter_mut().zip(items) {
+            if item.is_empty() || !item.chars().all(|c| c.is_ascii_digit()) {
+                return Err(VersionParseError::Malformed {
+                    version: version.to_owned(),
+                });
+            }
+
+            *num = item
+                .parse::<u16>()
+                .map_err(|_| VersionParseError::OutOfRange {
+                    version: version.to_owned(),
+                    component: item.to_owned(),
+                })?;
+        }
+
+        Ok(Self {
             major: nums[0],
             minor: nums[1],
             patch: nums[2],
-        }
+        })
     }
 
     pub fn to_u64(&self) -> u64 {
@@ -90,42 +170,117 @@ impl EffectiveVersion {
         value
     }
 
+    /// Returns the key used for the conflict detection, two versions are compatible
+    /// only if they have the same class, see the section "Zero Major Version".
+    pub fn compatibility_class(&self) -> CompatibilityClass {
+        if self.major == 0 {
+            CompatibilityClass::ZeroMajor(self.minor)
+        } else {
+            CompatibilityClass::Major(self.major)
+        }
+    }
+
     pub fn compatible(&self, other: &EffectiveVersion) -> VersionCompatibility {
-        if self.major != other.major {
-            // Major version differs.
+        if self.compatibility_class() != other.compatibility_class() {
+            // Major version differs, or minor version differs for zero major version.
             VersionCompatibility::Conflict
-        } else if self.major == 0 {
-            // Zero major version.
-            if self.minor != other.minor {
-                // Minor version differs.
-                VersionCompatibility::Conflict
-            } else if self.patch > other.patch {
-                VersionCompatibility::GreaterThan
-            } else if self.patch < other.patch {
-                VersionCompatibility::LessThan
-            } else {
-                VersionCompatibility::Equals
-            }
         } else {
-            // Normal major version.
-            if self.minor > other.minor {
-                VersionCompatibility::GreaterThan
-            } else if self.minor < other.minor {
-                VersionCompatibility::LessThan
-            } else if self.patch > other.patch {
-                VersionCompatibility::GreaterThan
-            } else if self.patch < other.patch {
-                VersionCompatibility::LessThan
-            } else {
-                VersionCompatibility::Equals
+            match self.cmp(other) {
+                std::cmp::Ordering::Less => VersionCompatibility::LessThan,
+                std::cmp::Ordering::Equal => VersionCompatibility::Equals,
+                std::cmp::Ordering::Greater => VersionCompatibility::GreaterThan,
+            }
+        }
+    }
+}
+
+impl FromStr for EffectiveVersion {
+    type Err = VersionParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        EffectiveVersion::from_version_string(s)
+    }
+}
+
+impl From<EffectiveVersion> for String {
+    fn from(value: EffectiveVersion) -> Self {
+        value.to_string()
+    }
+}
+
+impl TryFrom<String> for EffectiveVersion {
+    type Error = VersionParseError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        EffectiveVersion::from_version_string(&value)
+    }
+}
+
+/// The error of parsing a version string, see `EffectiveVersion::from_version_string`.
+#[derive(Debug, PartialEq, Clone)]
+pub enum VersionParseError {
+    /// A component is empty or contains non-digit characters.
+    Malformed { version: String },
+
+    /// A component is greater than 65535.
+    OutOfRange { version: String, component: String },
+
+    /// The number of components is not 3.
+    WrongArity { version: String, count: usize },
+
+    /// The pre-release tags are invalid, see the module `version`.
+    InvalidPreRelease { version: String },
+
+    /// The build metadata is invalid, see the module `version`.
+    InvalidBuildMetadata { version: String },
+
+    /// The version requirement is invalid, see the module `version`.
+    InvalidRequirement { requirement: String },
+}
+
+impl Display for VersionParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VersionParseError::Malformed { version } => {
+                write!(f, "Malformed version \"{}\".", version)
+            }
+            VersionParseError::OutOfRange { version, component } => write!(
+                f,
+                "The component \"{}\" of version \"{}\" is out of range (0-65535).",
+                component, version
+            ),
+            VersionParseError::WrongArity { version, count } => write!(
+                f,
+                "Version \"{}\" has {} components, expected 3 (i.e., \"x.y.z\").",
+                version, count
+            ),
+            VersionParseError::InvalidPreRelease { version } => {
+                write!(f, "Invalid pre-release tags in version \"{}\".", version)
+            }
+            VersionParseError::InvalidBuildMetadata { version } => {
+                write!(f, "Invalid build metadata in version \"{}\".", version)
+            }
+            VersionParseError::InvalidRequirement { requirement } => {
+                write!(f, "Invalid version requirement \"{}\".", requirement)
             }
         }
     }
 }
 
+impl std::error::Error for VersionParseError {}
+
 impl PartialOrd for EffectiveVersion {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        self.to_u64().partial_cmp(&other.to_u64())
+        Some(self.cmp(other))
+    }
+}
+
+/// Versions are ordered by major, minor and patch, it is the same as the order of `to_u64()`.
+///
+/// Note that the order does not imply compatibility, see `compatible()`.
+impl Ord for EffectiveVersion {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (self.major, self.minor, self.patch).cmp(&(other.major, other.minor, other.patch))
     }
 }
 
@@ -137,7 +292,51 @@ impl Display for EffectiveVersion {
 
 // The maximum version number supported by the current runtime.
 pub const IMAGE_FORMAT_MAJOR_VERSION: u16 = 1;
-pub const IMAGE_FORMAT_MINOR_VERSION: u16 = 0;
+pub const IMAGE_FORMAT_MINOR_VERSION: u16 = 1;
+
+pub const IMAGE_FORMAT_VERSION: ImageFormatVersion =
+    ImageFormatVersion::new(IMAGE_FORMAT_MAJOR_VERSION, IMAGE_FORMAT_MINOR_VERSION);
+
+/// The version of the image format, in the format "major.minor".
+///
+/// A runtime can load an image only if they have the same major version, and
+/// the minor version of the runtime is greater than or equal to the image's,
+/// see `supports()`.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
+pub struct ImageFormatVersion {
+    pub major: u16,
+    pub minor: u16,
+}
+
+impl ImageFormatVersion {
+    pub const fn new(major: u16, minor: u16) -> Self {
+        Self { major, minor }
+    }
+
+    /// The major version is stored in the high 16 bits.
+    pub fn from_u32(value: u32) -> Self {
+        Self {
+            major: (value >> 16) as u16,
+            minor: (value & 0xffff) as u16,
+        }
+    }
+
+    pub fn to_u32(&self) -> u32 {
+        ((self.major as u32) << 16) | self.minor as u32
+    }
+
+    /// Checks whether a runtime (or toolchain) supporting this version
+    /// can load the image with the specified version.
+    pub fn supports(&self, image_version: &ImageFormatVersion) -> bool {
+        self.major == image_version.major && self.minor >= image_version.minor
+    }
+}
+
+impl Display for ImageFormatVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
 
 // About the Version of Shared Modules
 // -----------------------------------
@@ -159,6 +358,7 @@ pub const IMAGE_FORMAT_MINOR_VERSION: u16 = 0;
 //
 // For example, if an application depends on a module with version 1.4.0,
 // the actual runtime version could range from 1.4.0 to 1.99.99.
+// See `version::VersionRequirement` for the executable form of this rule.
 //
 // For local and remote file-based shared modules and libraries, which lack
 // version information, compilation will fail if their sources (e.g., file paths
@@ -181,6 +381,8 @@ pub const IMAGE_FORMAT_MINOR_VERSION: u16 = 0;
 // - The API of version 1.9 and 2.0 may differ.
 
 /// The raw data type of operands.
+///
+/// Operands are little-endian, see the module `endian` for the layout and helpers.
 pub type Operand = [u8; 8];
 pub const OPERAND_SIZE_IN_BYTES: usize = 8;
 
@@ -234,7 +436,7 @@ pub enum OperandDataType {
 }
 
 /// The data type for:
-/// - Data in the data sections (read-only, read-write, uninitialized).
+/// - Data in the data sections (read-only, read-write, uninitialized, thread-local).
 /// - Data of dynamically allocated memory (heap).
 #[repr(u8)]
 #[derive(Debug, PartialEq, Clone, Copy)]
@@ -252,6 +454,15 @@ pub enum DataSectionType {
     ReadOnly = 0x0, // similar to the section ".rodata" in ELF.
     ReadWrite,      // similar to the section ".data" in ELF.
     Uninit,         // similar to the section ".bss" in ELF.
+    ThreadLocal,    // similar to the section ".tdata" in ELF, each thread has its own copy.
+}
+
+/// The protection of an allocated memory chunk, see the instruction `memory_protect`.
+#[repr(u32)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum MemoryProtection {
+    ReadOnly = 0x0,
+    ReadWrite,
 }
 
 impl Display for OperandDataType {
@@ -283,11 +494,58 @@ impl Display for DataSectionType {
             DataSectionType::ReadOnly => "read_only",
             DataSectionType::ReadWrite => "read_write",
             DataSectionType::Uninit => "uninit",
+            DataSectionType::ThreadLocal => "thread_local",
+        };
+        f.write_str(name)
+    }
+}
+
+impl Display for MemoryProtection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            MemoryProtection::ReadOnly => "read_only",
+            MemoryProtection::ReadWrite => "read_write",
         };
         f.write_str(name)
     }
 }
 
+/// The flags of an allocated memory chunk, see the instruction `memory_allocate_with_flags`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct MemoryAllocationFlags(u32);
+
+impl MemoryAllocationFlags {
+    pub const READABLE: MemoryAllocationFlags = MemoryAllocationFlags(0x1);
+    pub const WRITABLE: MemoryAllocationFlags = MemoryAllocationFlags(0x2);
+    pub const SHAREABLE: MemoryAllocationFlags = MemoryAllocationFlags(0x4);
+
+    /// The flags of the instruction `memory_allocate`.
+    pub const DEFAULT: MemoryAllocationFlags = MemoryAllocationFlags(0x1 | 0x2);
+
+    const ALL_BITS: u32 = 0x1 | 0x2 | 0x4;
+
+    /// Returns `None` if there are unknown bits.
+    pub fn from_bits(bits: u32) -> Option<Self> {
+        if bits & !Self::ALL_BITS == 0 {
+            Some(MemoryAllocationFlags(bits))
+        } else {
+            None
+        }
+    }
+
+    pub fn bits(&self) -> u32 {
+        self.0
+    }
+
+    pub fn contains(&self, other: MemoryAllocationFlags) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub fn union(&self, other: MemoryAllocationFlags) -> Self {
+        MemoryAllocationFlags(self.0 | other.0)
+    }
+}
+
 // Values for Foreign Function Interface (FFI)
 //
 // Used for calling VM functions from the outside or returning values to the foreign caller.
@@ -333,9 +591,159 @@ impl ForeignValue {
     }
 }
 
+/// The reason of a trap, i.e., an unrecoverable error raised by the VM
+/// while executing an instruction.
+///
+/// Runtimes, debuggers and test frameworks use this shared vocabulary to report traps,
+/// the number (i.e., `reason as u32`) and the serialized name are stable.
+#[repr(u32)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename = "trap")]
+pub enum TrapReason {
+    /// A floating-point variant which is not supported by the VM (i.e., NaN, +Inf and -Inf)
+    /// is encountered, see the section "Unsupported Floating-Point Variants" in the module `opcode`.
+    #[serde(rename = "unsupported_floating_point_variant")]
+    UnsupportedFloatingPointVariant = 0x01,
+
+    /// The value is out of the range of the target type, raised by the
+    /// instructions `convert_xxx_checked`, `demote_f32_to_f16` and `demote_f32_to_bf16`.
+    #[serde(rename = "conversion_out_of_range")]
+    ConversionOutOfRange,
+
+    /// The element index is out of the bounds of the function table,
+    /// raised by the instruction `call_indirect`.
+    #[serde(rename = "table_element_out_of_bounds")]
+    TableElementOutOfBounds,
+
+    /// The element of the function table is empty, raised by the instruction `call_indirect`.
+    #[serde(rename = "null_table_element")]
+    NullTableElement,
+
+    /// The exception thrown by the instruction `throw` is not caught by any `block_try`.
+    #[serde(rename = "uncaught_exception")]
+    UncaughtException,
+
+    /// Raised by the instruction `panic`, the message and the location
+    /// are reported along with the trap.
+    #[serde(rename = "panic")]
+    Panic,
+
+    /// Writing to a read-only memory chunk, see the instruction `memory_protect`.
+    #[serde(rename = "memory_protection_violation")]
+    MemoryProtectionViolation,
+
+    /// Integer division or remainder by zero, raised by the instructions
+    /// `div_xxx`, `rem_xxx` and `div_rem_xxx`.
+    #[serde(rename = "integer_divide_by_zero")]
+    IntegerDivideByZero,
+
+    /// The offset (plus the length) of a data access is out of the bounds of the data item
+    /// or the allocated memory chunk.
+    #[serde(rename = "data_access_out_of_bounds")]
+    DataAccessOutOfBounds,
+
+    /// The local variable index (or the `layers`) is invalid.
+    #[serde(rename = "invalid_local_variable_index")]
+    InvalidLocalVariableIndex,
+
+    /// The call stack or the operand stack exceeds the limit of the runtime.
+    #[serde(rename = "stack_overflow")]
+    StackOverflow,
+
+    /// The execution reaches the code which is unreachable, e.g., falling through the end
+    /// of the bytecode of a function, or an invalid opcode.
+    #[serde(rename = "unreachable")]
+    Unreachable,
+
+    /// Reading the allocated memory chunk which has not been initialized (only
+    /// detected by the runtimes with the memory checking enabled).
+    #[serde(rename = "uninitialized_memory")]
+    UninitializedMemory,
+}
+
+impl TrapReason {
+    /// Returns `None` if the number is not a valid trap reason.
+    pub fn from_u32(value: u32) -> Option<Self> {
+        if (TrapReason::UnsupportedFloatingPointVariant as u32
+            ..=TrapReason::UninitializedMemory as u32)
+            .contains(&value)
+        {
+            // SAFETY: the variants are continuous numbers, and the value has been checked.
+            Some(unsafe { std::mem::transmute::<u32, TrapReason>(value) })
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for TrapReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let message = match self {
+            TrapReason::UnsupportedFloatingPointVariant => "unsupported floating-point variant",
+            TrapReason::ConversionOutOfRange => "conversion out of range",
+            TrapReason::TableElementOutOfBounds => "table element out of bounds",
+            TrapReason::NullTableElement => "null table element",
+            TrapReason::UncaughtException => "uncaught exception",
+            TrapReason::Panic => "panic",
+            TrapReason::MemoryProtectionViolation => "memory protection violation",
+            TrapReason::IntegerDivideByZero => "integer divide by zero",
+            TrapReason::DataAccessOutOfBounds => "data access out of bounds",
+            TrapReason::InvalidLocalVariableIndex => "invalid local variable index",
+            TrapReason::StackOverflow => "stack overflow",
+            TrapReason::Unreachable => "unreachable",
+            TrapReason::UninitializedMemory => "uninitialized memory",
+        };
+        f.write_str(message)
+    }
+}
+
+/// An item of the function table section, i.e., a function table
+/// which is used by the instruction `call_indirect`.
+///
+/// All functions in a table have the same type (signature), the loader should
+/// check the type of each element when loading the module.
+#[derive(Debug, PartialEq, Clone)]
+pub struct FunctionTable {
+    pub type_index: u32,
+
+    /// The function public index of each element, `None` for empty elements.
+    pub elements: Vec<Option<u32>>,
+}
+
+impl FunctionTable {
+    pub fn new(type_index: u32, elements: Vec<Option<u32>>) -> Self {
+        Self {
+            type_index,
+            elements,
+        }
+    }
+
+    /// Returns the function public index of the element, or the trap reason
+    /// if the element index is out of bounds or the element is empty.
+    pub fn get_function_public_index(&self, element_index: u32) -> Result<u32, TrapReason> {
+        match self.elements.get(element_index as usize) {
+            Some(Some(function_public_index)) => Ok(*function_public_index),
+            Some(None) => Err(TrapReason::NullTableElement),
+            None => Err(TrapReason::TableElementOutOfBounds),
+        }
+    }
+}
+
+/// An item of the exception tag section.
+///
+/// The parameters of the type are the payload of the exceptions with this tag,
+/// and the results of the type must be empty.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ExceptionTag {
+    pub type_index: u32,
+}
+
 /// The type of dependent shared modules.
+///
+/// It is serialized as an enum, e.g. `module_type::share`, see also `ModuleDependency`.
 #[repr(u8)]
-#[derive(Debug, PartialEq, Clone, Copy)]
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename = "module_type")]
 pub enum ModuleDependencyType {
     // Module from the local file system.
     //
@@ -352,17 +760,19 @@ pub enum ModuleDependencyType {
     // Local modules are recompiled during every compilation if their source code changes.
     // This type of dependency is suitable for local development and testing only.
     // Modules with "Local" dependencies should not be distributed to the central repository.
+    #[serde(rename = "local")]
     Local = 0x0,
 
     // Module from a remote Git repository.
     //
-    // The value contains the Git repository URL, commit (hash), and directory, e.g.:
+    // The value contains the Git repository URL, Git reference (commit, tag or branch),
+    // and directory, e.g.:
     //
     // ```ason
     // modules: [
     //   "module_name": module::remote({
     //       url: "https://github.com/hemashushu/xiaoxuan-core-extension.git",
-    //       revision: "commit or tag",
+    //       reference: "tag:v1.0.0",  // or "commit:HASH", "branch:NAME"
     //       dir: "/modules/sha2"
     //     })
     // ]
@@ -371,6 +781,7 @@ pub enum ModuleDependencyType {
     // Remote modules are downloaded and cached locally during compilation or runtime.
     // This type of dependency is suitable for internal development and testing only.
     // Modules with "Remote" dependencies should not be distributed to the central repository.
+    #[serde(rename = "remote")]
     Remote,
 
     // Module from the central registry.
@@ -378,15 +789,17 @@ pub enum ModuleDependencyType {
     // The runtime specifies a default location for the central registry, which is a Git repository
     // providing the module index. Users can customize this location or add multiple registries.
     //
-    // The value contains the version, e.g.:
+    // The value contains the version and an optional checksum, e.g.:
     //
     // ```ason
     // modules: [
     //   "module_name": module::share({
     //       version: "{major.minor.patch}"
+    //       checksum: Option::Some("sha256:{digest}")
     //     })
     // ]
     // ```
+    #[serde(rename = "share")]
     Share,
 
     // Module bundled with the runtime.
@@ -401,6 +814,7 @@ pub enum ModuleDependencyType {
     //   "module_name": module::runtime
     // ]
     // ```
+    #[serde(rename = "runtime")]
     Runtime,
 
     // Represents the current module.
@@ -414,23 +828,70 @@ pub enum ModuleDependencyType {
     // should be resolved, and this virtual module item in the "import module section"
     // would be removed. Therefore, this type would not be present in the shared module and
     // application module image files.
+    #[serde(rename = "module")]
     Current,
+
+    // Module from a local archive (tarball or zip) with a required checksum.
+    //
+    // The value contains the path to the archive and its checksum, e.g.:
+    //
+    // ```ason
+    // modules: [
+    //   "module_name": module::vendored({
+    //       path: "vendor/sha2-1.0.0.tar.gz"
+    //       checksum: "sha256:{digest}"
+    //     })
+    // ]
+    // ```
+    //
+    // The archive is verified and extracted during compilation, no network access is
+    // required, so this type is suitable for offline and reproducible builds.
+    #[serde(rename = "vendored")]
+    Vendored,
 }
 
 /// The type of dependent libraries.
 /// The library refers to the module of XiaoXuan C, the XaioXuan Core Runtime will
 /// download the XiaoXuan C runtime if a module contains an external library dependency.
 /// The value of this type is similar to the `ModuleDependencyType`,
+///
+/// It is serialized as an enum, e.g. `library_type::system`.
 #[repr(u8)]
-#[derive(Debug, PartialEq, Clone, Copy)]
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename = "library_type")]
 pub enum ExternalLibraryDependencyType {
+    #[serde(rename = "local")]
     Local = 0x0,
+
+    #[serde(rename = "remote")]
     Remote,
+
+    #[serde(rename = "share")]
     Share,
+
+    #[serde(rename = "runtime")]
     Runtime,
+
+    #[serde(rename = "vendored")]
+    Vendored,
+
+    // Library provided by the host system, looked up by the soname or pkg-config name
+    // instead of being downloaded, e.g.:
+    //
+    // ```ason
+    // libraries: [
+    //   "ssl": library::system({
+    //       lookup: lookup::pkg_config("openssl")
+    //       min_version: Option::Some("3.0")
+    //     })
+    // ]
+    // ```
+    #[serde(rename = "system")]
+    System,
 }
 
-#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename = "module")]
 pub enum ModuleDependency {
     #[serde(rename = "local")]
@@ -447,6 +908,9 @@ pub enum ModuleDependency {
 
     #[serde(rename = "module")]
     Current,
+
+    #[serde(rename = "vendored")]
+    Vendored(Box<DependencyVendored>),
 }
 
 // The name of `ModuleDependency::Current`.
@@ -458,7 +922,7 @@ pub enum ModuleDependency {
 // The "full_name" always use the actual name of module.
 pub const SELF_REFERENCE_MODULE_NAME: &str = "module";
 
-#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
 #[serde(rename = "library")]
 pub enum ExternalLibraryDependency {
     #[serde(rename = "local")]
@@ -472,9 +936,16 @@ pub enum ExternalLibraryDependency {
 
     #[serde(rename = "runtime")]
     Runtime,
+
+    #[serde(rename = "vendored")]
+    Vendored(Box<DependencyVendored>),
+
+    #[serde(rename = "system")]
+    System(Box<DependencySystem>),
 }
 
-#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename = "local")]
 pub struct DependencyLocal {
     /// The module's path relative to the application (or module project) folder.
@@ -491,21 +962,41 @@ pub struct DependencyLocal {
     /// The default value is DependencyCondition::True.
     #[serde(default)]
     pub condition: DependencyCondition,
+
+    /// Optional.
+    /// The default value is DependencyKind::Normal.
+    #[serde(default)]
+    pub kind: DependencyKind,
+
+    /// Optional.
+    /// The default value is `false`.
+    /// An optional dependency is included only if it is activated by a flag of the
+    /// parent module, see `FeatureActivation`.
+    #[serde(default)]
+    pub optional: bool,
 }
 
-#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename = "remote")]
 pub struct DependencyRemote {
     /// Git repository URL, should use the "https" protocol.
     pub url: String,
 
-    /// Git commit or tag.
-    pub reversion: String,
+    /// Git commit, tag or branch, e.g. "tag:v1.0.0", see `GitReference`.
+    ///
+    /// The legacy field name `reversion` is also accepted.
+    #[serde(alias = "reversion")]
+    pub reference: GitReference,
 
     /// The directory in the repository where the module is located.
     /// If not specified, the default value is the root directory of the repository.
     pub dir: Option<String>,
 
+    /// Optional.
+    /// The checksum of the module source, e.g. "sha256:e3b0c442...", see the module `checksum`.
+    pub checksum: Option<Checksum>,
+
     /// Optional.
     /// The default value is [].
     #[serde(default)]
@@ -515,13 +1006,30 @@ pub struct DependencyRemote {
     /// The default value is DependencyCondition::True.
     #[serde(default)]
     pub condition: DependencyCondition,
+
+    /// Optional.
+    /// The default value is DependencyKind::Normal.
+    #[serde(default)]
+    pub kind: DependencyKind,
+
+    /// Optional.
+    /// The default value is `false`.
+    /// An optional dependency is included only if it is activated by a flag of the
+    /// parent module, see `FeatureActivation`.
+    #[serde(default)]
+    pub optional: bool,
 }
 
-#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
-#[serde(rename = "share")]
-pub struct DependencyShare {
-    /// Semver, e.g., "1.0.1".
-    pub version: String,
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename = "vendored")]
+pub struct DependencyVendored {
+    /// The path of the archive relative to the application (or module project) folder,
+    /// e.g. "vendor/sha2-1.0.0.tar.gz", see `ArchiveFormat` for the supported formats.
+    pub path: String,
+
+    /// The checksum of the archive, it is required.
+    pub checksum: Checksum,
 
     /// Optional.
     /// The default value is [].
@@ -532,55 +1040,379 @@ pub struct DependencyShare {
     /// The default value is DependencyCondition::True.
     #[serde(default)]
     pub condition: DependencyCondition,
+
+    /// Optional.
+    /// The default value is DependencyKind::Normal.
+    #[serde(default)]
+    pub kind: DependencyKind,
+
+    /// Optional.
+    /// The default value is `false`.
+    #[serde(default)]
+    pub optional: bool,
 }
-/// Defines the possible property values for a module.
-#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
-#[serde(rename = "prop")]
-pub enum PropertyValue {
-    /// Represents a string value.
-    #[serde(rename = "string")]
-    String(String),
 
-    /// Represents a numeric value (32-bit integer).
-    #[serde(rename = "number")]
-    Number(i32),
+/// The archive formats of vendored dependencies.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ArchiveFormat {
+    Tar,
+    TarGz,
+    Zip,
+}
 
-    /// Represents a boolean value (flag).
-    #[serde(rename = "bool")]
-    Flag(bool),
+impl ArchiveFormat {
+    /// Detects the format by the file extension, i.e.,
+    /// ".tar", ".tar.gz" (or ".tgz") and ".zip".
+    pub fn from_path(path: &str) -> Option<Self> {
+        let path = path.to_ascii_lowercase();
+        if path.ends_with(".tar.gz") || path.ends_with(".tgz") {
+            Some(ArchiveFormat::TarGz)
+        } else if path.ends_with(".tar") {
+            Some(ArchiveFormat::Tar)
+        } else if path.ends_with(".zip") {
+            Some(ArchiveFormat::Zip)
+        } else {
+            None
+        }
+    }
+}
 
-    /// Represents a boolean value with a set of mutually exclusive options.
-    #[serde(rename = "group")]
-    Group(/* group name */ String, /* checked */ bool),
+impl DependencyVendored {
+    pub fn get_archive_format(&self) -> Option<ArchiveFormat> {
+        ArchiveFormat::from_path(&self.path)
+    }
 }
 
-/// Represents values that can be passed to a dependency module.
-#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
-#[serde(rename = "param")]
-pub enum DependencyParameterValue {
-    /// Represents a string value.
-    #[serde(rename = "string")]
-    String(String),
+/// A library provided by the host system, e.g. "libc" and "libssl",
+/// which should never be fetched remotely.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename = "system")]
+pub struct DependencySystem {
+    pub lookup: SystemLibraryLookup,
 
-    /// Represents a numeric value (32-bit integer).
-    #[serde(rename = "number")]
-    Number(i32),
+    /// Optional.
+    /// The minimum version of the library, e.g. "3.0" or "1.2.13",
+    /// the omitted minor and patch are 0, see `get_min_version`.
+    pub min_version: Option<String>,
 
-    /// Represents a boolean value.
-    #[serde(rename = "bool")]
-    Bool(bool),
+    /// Optional.
+    /// The default value is DependencyCondition::True.
+    #[serde(default)]
+    pub condition: DependencyCondition,
 
-    /// Represents a value inherited from a specified property.
-    #[serde(rename = "from")]
-    From(String),
+    /// Optional.
+    /// The default value is DependencyKind::Normal.
+    #[serde(default)]
+    pub kind: DependencyKind,
+
+    /// Optional.
+    /// The default value is `false`.
+    #[serde(default)]
+    pub optional: bool,
 }
 
-// Flag Unification
-// ----------------
-//
-// When a single shared module is included multiple times in a project's dependency tree
-// with different flags requested by different dependencies, the XiaoXuan Core compiler
-// resolves the dependency tree to select a single compatible version for
+/// How to find a system library.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename = "lookup")]
+pub enum SystemLibraryLookup {
+    /// The shared object name, e.g. "libssl.so.3".
+    #[serde(rename = "soname")]
+    Soname(String),
+
+    /// The package name of pkg-config, e.g. "openssl".
+    #[serde(rename = "pkg_config")]
+    PkgConfig(String),
+}
+
+impl DependencySystem {
+    /// Returns `None` if the minimum version is not specified.
+    pub fn get_min_version(&self) -> Result<Option<EffectiveVersion>, VersionParseError> {
+        self.min_version
+            .as_deref()
+            .map(version::parse_partial_version)
+            .transpose()
+    }
+
+    /// Checks whether the version of the installed library satisfies the minimum version.
+    pub fn is_satisfied_by(&self, installed: &EffectiveVersion) -> Result<bool, VersionParseError> {
+        Ok(self
+            .get_min_version()?
+            .is_none_or(|min_version| *installed >= min_version))
+    }
+}
+
+/// A reference to a revision of a Git repository.
+///
+/// It is serialized as a string with a prefix, e.g. "commit:1a2b3c4", "tag:v1.0.0" and
+/// "branch:main". For the legacy value without prefix, a hexadecimal string of
+/// 7 to 40 characters is a commit hash, and others are tags.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(into = "String", try_from = "String")]
+pub enum GitReference {
+    Commit(String),
+    Tag(String),
+    Branch(String),
+}
+
+impl GitReference {
+    pub fn from_reference_string(reference: &str) -> Result<Self, GitReferenceError> {
+        let is_commit_hash = |text: &str| {
+            (7..=40).contains(&text.len()) && text.chars().all(|c| c.is_ascii_hexdigit())
+        };
+
+        let value = if let Some(hash) = reference.strip_prefix("commit:") {
+            if !is_commit_hash(hash) {
+                return Err(GitReferenceError::InvalidCommitHash {
+                    hash: hash.to_owned(),
+                });
+            }
+            GitReference::Commit(hash.to_owned())
+        } else if let Some(name) = reference.strip_prefix("tag:") {
+            GitReference::Tag(name.to_owned())
+        } else if let Some(name) = reference.strip_prefix("branch:") {
+            GitReference::Branch(name.to_owned())
+        } else if is_commit_hash(reference) {
+            // legacy commit hash
+            GitReference::Commit(reference.to_owned())
+        } else {
+            // legacy tag
+            GitReference::Tag(reference.to_owned())
+        };
+
+        match &value {
+            GitReference::Commit(name) | GitReference::Tag(name) | GitReference::Branch(name)
+                if name.is_empty() =>
+            {
+                Err(GitReferenceError::Empty)
+            }
+            _ => Ok(value),
+        }
+    }
+
+    /// A branch may point to different commits over time, resolvers should
+    /// warn about it and record the actual commit in the lockfile.
+    pub fn is_moving(&self) -> bool {
+        matches!(self, GitReference::Branch(_))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum GitReferenceError {
+    /// The reference (or the name after the prefix) is empty.
+    Empty,
+
+    /// The commit hash is not a hexadecimal string of 7 to 40 characters.
+    InvalidCommitHash { hash: String },
+}
+
+impl Display for GitReferenceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GitReferenceError::Empty => f.write_str("The Git reference is empty."),
+            GitReferenceError::InvalidCommitHash { hash } => {
+                write!(f, "Invalid Git commit hash \"{}\".", hash)
+            }
+        }
+    }
+}
+
+impl std::error::Error for GitReferenceError {}
+
+impl From<GitReference> for String {
+    fn from(value: GitReference) -> Self {
+        value.to_string()
+    }
+}
+
+impl TryFrom<String> for GitReference {
+    type Error = GitReferenceError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        GitReference::from_reference_string(&value)
+    }
+}
+
+impl Display for GitReference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GitReference::Commit(hash) => write!(f, "commit:{}", hash),
+            GitReference::Tag(name) => write!(f, "tag:{}", name),
+            GitReference::Branch(name) => write!(f, "branch:{}", name),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename = "share")]
+pub struct DependencyShare {
+    /// Semver, e.g., "1.0.1".
+    ///
+    /// It is a version requirement, it is parsed when deserializing and serialized
+    /// as the original string, see `version::DeclaredVersionRequirement`.
+    pub version: DeclaredVersionRequirement,
+
+    /// Optional.
+    /// The checksum of the module source, e.g. "sha256:e3b0c442...", see the module `checksum`.
+    pub checksum: Option<Checksum>,
+
+    /// Optional.
+    /// The default value is [].
+    #[serde(default)]
+    pub parameters: HashMap<String, DependencyParameterValue>,
+
+    /// Optional.
+    /// The default value is DependencyCondition::True.
+    #[serde(default)]
+    pub condition: DependencyCondition,
+
+    /// Optional.
+    /// The default value is DependencyKind::Normal.
+    #[serde(default)]
+    pub kind: DependencyKind,
+
+    /// Optional.
+    /// The default value is `false`.
+    /// An optional dependency is included only if it is activated by a flag of the
+    /// parent module, see `FeatureActivation`.
+    #[serde(default)]
+    pub optional: bool,
+}
+/// Defines the possible property values for a module.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename = "prop")]
+pub enum PropertyValue {
+    /// Represents a string value.
+    #[serde(rename = "string")]
+    String(String),
+
+    /// Represents a numeric value (32-bit integer).
+    #[serde(rename = "number")]
+    Number(i32),
+
+    /// Represents a boolean value (flag).
+    #[serde(rename = "bool")]
+    Flag(bool),
+
+    /// Represents a boolean value with a set of mutually exclusive options.
+    #[serde(rename = "group")]
+    Group(/* group name */ String, /* checked */ bool),
+
+    /// Represents a list of strings, e.g. the supported backends.
+    #[serde(rename = "string_list")]
+    StringList(Vec<String>),
+
+    /// Represents one of the allowed strings, e.g.:
+    ///
+    /// ```ason
+    /// "backend": prop::choice(["cpu", "cuda", "vulkan"], "cpu")
+    /// ```
+    ///
+    /// The selected value must be one of the allowed values, and a dependent
+    /// can select another one by a string parameter.
+    #[serde(rename = "choice")]
+    Choice(
+        /* allowed values */ Vec<String>,
+        /* selected */ String,
+    ),
+}
+
+/// Represents values that can be passed to a dependency module.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename = "param")]
+pub enum DependencyParameterValue {
+    /// Represents a string value.
+    #[serde(rename = "string")]
+    String(String),
+
+    /// Represents a numeric value (32-bit integer).
+    #[serde(rename = "number")]
+    Number(i32),
+
+    /// Represents a boolean value.
+    #[serde(rename = "bool")]
+    Bool(bool),
+
+    /// Represents a list of strings, it sets a `prop::string_list`.
+    #[serde(rename = "string_list")]
+    StringList(Vec<String>),
+
+    /// Represents a value inherited from a specified property.
+    #[serde(rename = "from")]
+    From(String),
+}
+
+/// Maps the flags (i.e., the boolean properties) of a module to the names of the optional
+/// dependencies which they activate, e.g.:
+///
+/// ```ason
+/// features: [
+///     "enable_tls": ["openssl"]
+///     "enable_zip": ["zlib", "lz4"]
+/// ]
+/// ```
+///
+/// An optional dependency is included only if any flag that activates it is enabled,
+/// and its `condition` evaluates to `true`. Non-optional dependencies are not affected.
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct FeatureActivation {
+    pub flags: HashMap<String, Vec<String>>,
+}
+
+impl FeatureActivation {
+    /// Returns the flags which activate the dependency, in alphabetical order.
+    pub fn get_activating_flags(&self, dependency_name: &str) -> Vec<&str> {
+        let mut flags = self
+            .flags
+            .iter()
+            .filter(|(_, names)| names.iter().any(|name| name == dependency_name))
+            .map(|(flag, _)| flag.as_str())
+            .collect::<Vec<_>>();
+        flags.sort();
+        flags
+    }
+
+    /// Checks whether any flag that activates the dependency is enabled
+    /// in the properties of the parent module.
+    pub fn is_activated(
+        &self,
+        dependency_name: &str,
+        properties: &HashMap<String, PropertyValue>,
+    ) -> bool {
+        self.get_activating_flags(dependency_name)
+            .iter()
+            .any(|flag| matches!(properties.get(*flag), Some(PropertyValue::Flag(true))))
+    }
+
+    /// Returns the activation of the dependency in the form of `DependencyCondition`,
+    /// i.e., `cond::any([check::true(FLAG), ...])`, or `cond::false` if there is no
+    /// flag activating the dependency.
+    pub fn get_activation_condition(&self, dependency_name: &str) -> DependencyCondition {
+        let checks = self
+            .get_activating_flags(dependency_name)
+            .iter()
+            .map(|flag| DependencyConditionCheck::True(flag.to_string()))
+            .collect::<Vec<_>>();
+
+        if checks.is_empty() {
+            DependencyCondition::False
+        } else {
+            DependencyCondition::Any(checks)
+        }
+    }
+}
+
+// Flag Unification
+// ----------------
+//
+// When a single shared module is included multiple times in a project's dependency tree
+// with different flags requested by different dependencies, the XiaoXuan Core compiler
+// resolves the dependency tree to select a single compatible version for
 // each module. When building that version, it enables the union of all flags requested
 // for that module across the entire dependency graph.
 //
@@ -593,17 +1425,133 @@ pub enum DependencyParameterValue {
 //
 // When XiaoXuan Core compiles "common_module" v1.0.2 for your project, it will do so
 // with both "flag_x" and "flag_y" enabled.
+//
+// See `parameter::UnifiedFlags`.
 
 // Dependency Parameter Conflicts
 // ------------------------------
 //
 // Unlike flags, when a single shared module is included multiple times in a project's
-// dependency tree with different string or number type parameter values requested
+// dependency tree with different string, number or string list type parameter values requested
 // by different dependencies, the compilation will fail. This is because these type parameter values
 // cannot be unified like flags.
+//
+// See `parameter::check_parameter_conflicts`.
+
+/// The kind of a dependency, i.e., in which stage the dependency is used.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[serde(rename = "kind")]
+pub enum DependencyKind {
+    /// The dependency is compiled into (or distributed with) the image.
+    #[default]
+    #[serde(rename = "normal")]
+    Normal,
+
+    /// The dependency is used only by unit tests, examples and benchmarks.
+    #[serde(rename = "dev")]
+    Dev,
+
+    /// The dependency is used only by build tools (e.g., code generators).
+    #[serde(rename = "build")]
+    Build,
+}
+
+impl DependencyKind {
+    /// Only the normal dependencies are included in the release images.
+    pub fn is_included_in_release(&self) -> bool {
+        matches!(self, DependencyKind::Normal)
+    }
+}
+
+impl ModuleDependency {
+    /// `Runtime` and `Current` are never optional.
+    pub fn is_optional(&self) -> bool {
+        match self {
+            ModuleDependency::Local(dependency) => dependency.optional,
+            ModuleDependency::Remote(dependency) => dependency.optional,
+            ModuleDependency::Share(dependency) => dependency.optional,
+            ModuleDependency::Vendored(dependency) => dependency.optional,
+            ModuleDependency::Runtime | ModuleDependency::Current => false,
+        }
+    }
+
+    /// `Runtime` and `Current` are always normal dependencies.
+    pub fn get_kind(&self) -> DependencyKind {
+        match self {
+            ModuleDependency::Local(dependency) => dependency.kind,
+            ModuleDependency::Remote(dependency) => dependency.kind,
+            ModuleDependency::Share(dependency) => dependency.kind,
+            ModuleDependency::Vendored(dependency) => dependency.kind,
+            ModuleDependency::Runtime | ModuleDependency::Current => DependencyKind::Normal,
+        }
+    }
+
+    /// Returns `None` for `Runtime` and `Current`.
+    pub fn get_parameters(&self) -> Option<&HashMap<String, DependencyParameterValue>> {
+        match self {
+            ModuleDependency::Local(dependency) => Some(&dependency.parameters),
+            ModuleDependency::Remote(dependency) => Some(&dependency.parameters),
+            ModuleDependency::Share(dependency) => Some(&dependency.parameters),
+            ModuleDependency::Vendored(dependency) => Some(&dependency.parameters),
+            ModuleDependency::Runtime | ModuleDependency::Current => None,
+        }
+    }
+
+    /// Returns `None` for `Runtime` and `Current`.
+    pub fn get_condition(&self) -> Option<&DependencyCondition> {
+        match self {
+            ModuleDependency::Local(dependency) => Some(&dependency.condition),
+            ModuleDependency::Remote(dependency) => Some(&dependency.condition),
+            ModuleDependency::Share(dependency) => Some(&dependency.condition),
+            ModuleDependency::Vendored(dependency) => Some(&dependency.condition),
+            ModuleDependency::Runtime | ModuleDependency::Current => None,
+        }
+    }
+
+    /// Returns the declared checksum of the module source,
+    /// `Local`, `Runtime` and `Current` have no checksum.
+    pub fn get_checksum(&self) -> Option<&Checksum> {
+        match self {
+            ModuleDependency::Remote(dependency) => dependency.checksum.as_ref(),
+            ModuleDependency::Share(dependency) => dependency.checksum.as_ref(),
+            ModuleDependency::Vendored(dependency) => Some(&dependency.checksum),
+            ModuleDependency::Local(_) | ModuleDependency::Runtime | ModuleDependency::Current => {
+                None
+            }
+        }
+    }
+}
+
+impl ExternalLibraryDependency {
+    /// `Runtime` is never optional.
+    pub fn is_optional(&self) -> bool {
+        match self {
+            ExternalLibraryDependency::Local(dependency) => dependency.optional,
+            ExternalLibraryDependency::Remote(dependency) => dependency.optional,
+            ExternalLibraryDependency::Share(dependency) => dependency.optional,
+            ExternalLibraryDependency::Vendored(dependency) => dependency.optional,
+            ExternalLibraryDependency::System(dependency) => dependency.optional,
+            ExternalLibraryDependency::Runtime => false,
+        }
+    }
+
+    /// `Runtime` is always a normal dependency.
+    pub fn get_kind(&self) -> DependencyKind {
+        match self {
+            ExternalLibraryDependency::Local(dependency) => dependency.kind,
+            ExternalLibraryDependency::Remote(dependency) => dependency.kind,
+            ExternalLibraryDependency::Share(dependency) => dependency.kind,
+            ExternalLibraryDependency::Vendored(dependency) => dependency.kind,
+            ExternalLibraryDependency::System(dependency) => dependency.kind,
+            ExternalLibraryDependency::Runtime => DependencyKind::Normal,
+        }
+    }
+}
 
 /// Defines conditions for dependency inclusion.
-#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename = "cond")]
 pub enum DependencyCondition {
     /// Always evaluates to `true`. Used for default settings.
@@ -621,6 +1569,10 @@ pub enum DependencyCondition {
     /// Evaluates to `true` if all of the specified properties match the given conditions.
     #[serde(rename = "all")]
     All(Vec<DependencyConditionCheck>),
+
+    /// Evaluates to `true` if the specified condition evaluates to `false`.
+    #[serde(rename = "not")]
+    Not(Box<DependencyCondition>),
 }
 
 impl Default for DependencyCondition {
@@ -631,7 +1583,8 @@ impl Default for DependencyCondition {
 }
 
 /// Represents a condition check for a dependency.
-#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename = "check")]
 pub enum DependencyConditionCheck {
     /// Checks if a string property matches a specific value.
@@ -655,6 +1608,124 @@ pub enum DependencyConditionCheck {
     /// Checks if a boolean is set to `false`.
     #[serde(rename = "false")]
     False(String),
+
+    /// Evaluates a nested condition, so that `Any` and `All` can be combined, e.g.
+    /// `cond::any([check::true("a"), check::cond(cond::all([...]))])`.
+    #[serde(rename = "cond")]
+    Condition(Box<DependencyCondition>),
+
+    /// Checks if the target OS matches, e.g. "linux", "macos" and "windows",
+    /// the names are the same as `std::env::consts::OS`.
+    #[serde(rename = "os")]
+    TargetOs(String),
+
+    /// Checks if the target architecture matches, e.g. "x86_64", "aarch64" and "riscv64",
+    /// the names are the same as `std::env::consts::ARCH`.
+    #[serde(rename = "arch")]
+    TargetArch(String),
+
+    /// Checks if the pointer width (in bits) of the target matches, e.g. 64.
+    #[serde(rename = "pointer_width")]
+    PointerWidth(u32),
+
+    /// Checks if the runtime edition matches, e.g. "2025".
+    #[serde(rename = "edition")]
+    Edition(String),
+}
+
+/// The target platform and runtime, which the target checks of
+/// `DependencyConditionCheck` are evaluated against.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct TargetInfo {
+    pub os: String,
+    pub arch: String,
+    pub pointer_width: u32,
+    pub edition: Edition,
+}
+
+impl TargetInfo {
+    /// The current host and runtime edition.
+    pub fn host() -> Self {
+        Self {
+            os: std::env::consts::OS.to_owned(),
+            arch: std::env::consts::ARCH.to_owned(),
+            pointer_width: usize::BITS,
+            edition: RUNTIME_EDITION,
+        }
+    }
+}
+
+impl DependencyConditionCheck {
+    /// Evaluates the target checks (i.e., `TargetOs`, `TargetArch`, `PointerWidth`
+    /// and `Edition`), returns `None` for other checks.
+    pub fn check_target(&self, target: &TargetInfo) -> Option<bool> {
+        match self {
+            DependencyConditionCheck::TargetOs(os) => Some(*os == target.os),
+            DependencyConditionCheck::TargetArch(arch) => Some(*arch == target.arch),
+            DependencyConditionCheck::PointerWidth(width) => Some(*width == target.pointer_width),
+            DependencyConditionCheck::Edition(edition) => Some(edition == target.edition.as_str()),
+            _ => None,
+        }
+    }
+}
+
+/// The error of converting a byte to `ModuleDependencyType` or `ExternalLibraryDependencyType`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct InvalidDependencyTypeError {
+    pub value: u8,
+}
+
+impl Display for InvalidDependencyTypeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid dependency type 0x{:02x}.", self.value)
+    }
+}
+
+impl std::error::Error for InvalidDependencyTypeError {}
+
+impl TryFrom<u8> for ModuleDependencyType {
+    type Error = InvalidDependencyTypeError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0x0 => Ok(ModuleDependencyType::Local),
+            0x1 => Ok(ModuleDependencyType::Remote),
+            0x2 => Ok(ModuleDependencyType::Share),
+            0x3 => Ok(ModuleDependencyType::Runtime),
+            0x4 => Ok(ModuleDependencyType::Current),
+            0x5 => Ok(ModuleDependencyType::Vendored),
+            _ => Err(InvalidDependencyTypeError { value }),
+        }
+    }
+}
+
+impl Display for ModuleDependencyType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ModuleDependencyType::Local => f.write_str("local"),
+            ModuleDependencyType::Remote => f.write_str("remote"),
+            ModuleDependencyType::Share => f.write_str("share"),
+            ModuleDependencyType::Runtime => f.write_str("runtime"),
+            ModuleDependencyType::Current => f.write_str("module"),
+            ModuleDependencyType::Vendored => f.write_str("vendored"),
+        }
+    }
+}
+
+impl TryFrom<u8> for ExternalLibraryDependencyType {
+    type Error = InvalidDependencyTypeError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0x0 => Ok(ExternalLibraryDependencyType::Local),
+            0x1 => Ok(ExternalLibraryDependencyType::Remote),
+            0x2 => Ok(ExternalLibraryDependencyType::Share),
+            0x3 => Ok(ExternalLibraryDependencyType::Runtime),
+            0x4 => Ok(ExternalLibraryDependencyType::Vendored),
+            0x5 => Ok(ExternalLibraryDependencyType::System),
+            _ => Err(InvalidDependencyTypeError { value }),
+        }
+    }
 }
 
 impl Display for ExternalLibraryDependencyType {
@@ -664,6 +1735,8 @@ impl Display for ExternalLibraryDependencyType {
             ExternalLibraryDependencyType::Remote => f.write_str("remote"),
             ExternalLibraryDependencyType::Share => f.write_str("share"),
             ExternalLibraryDependencyType::Runtime => f.write_str("runtime"),
+            ExternalLibraryDependencyType::Vendored => f.write_str("vendored"),
+            ExternalLibraryDependencyType::System => f.write_str("system"),
         }
     }
 }
@@ -708,11 +1781,111 @@ mod tests {
     use pretty_assertions::assert_eq;
 
     use crate::{
-        DependencyCondition, DependencyConditionCheck, DependencyLocal, DependencyParameterValue,
-        DependencyRemote, DependencyShare, EffectiveVersion, ExternalLibraryDependency,
-        ModuleDependency, VersionCompatibility, RUNTIME_EDITION, RUNTIME_EDITION_STRING,
+        checksum::{Checksum, ChecksumAlgorithm},
+        edition::Edition,
+        ArchiveFormat, CompatibilityClass, DependencyCondition, DependencyConditionCheck,
+        DependencyKind, DependencyLocal, DependencyParameterValue, DependencyRemote,
+        DependencyShare, DependencySystem, EffectiveVersion, ExternalLibraryDependency,
+        ExternalLibraryDependencyType, FeatureActivation, FunctionTable, GitReference,
+        GitReferenceError, ImageFormatVersion, InvalidDependencyTypeError, MemoryAllocationFlags,
+        ModuleDependency, ModuleDependencyType, PropertyValue, SystemLibraryLookup, TargetInfo,
+        TrapReason, VersionCompatibility, VersionParseError, IMAGE_FORMAT_VERSION, RUNTIME_EDITION,
+        RUNTIME_EDITION_STRING, SUPPORTED_EDITIONS,
     };
 
+    #[test]
+    fn test_function_table() {
+        let table = FunctionTable::new(1, vec![Some(3), None, Some(5)]);
+        assert_eq!(table.get_function_public_index(0), Ok(3));
+        assert_eq!(table.get_function_public_index(2), Ok(5));
+        assert_eq!(
+            table.get_function_public_index(1),
+            Err(TrapReason::NullTableElement)
+        );
+        assert_eq!(
+            table.get_function_public_index(3),
+            Err(TrapReason::TableElementOutOfBounds)
+        );
+    }
+
+    #[test]
+    fn test_trap_reason() {
+        assert_eq!(
+            TrapReason::from_u32(1),
+            Some(TrapReason::UnsupportedFloatingPointVariant)
+        );
+        assert_eq!(
+            TrapReason::from_u32(8),
+            Some(TrapReason::IntegerDivideByZero)
+        );
+        assert_eq!(TrapReason::from_u32(0), None);
+        assert_eq!(TrapReason::from_u32(0x100), None);
+        assert_eq!(TrapReason::StackOverflow.to_string(), "stack overflow");
+    }
+
+    #[test]
+    fn test_serialize_trap_reason() {
+        assert_eq!(
+            ason::to_string(&TrapReason::IntegerDivideByZero).unwrap(),
+            "trap::integer_divide_by_zero"
+        );
+        assert_eq!(
+            ason::from_str::<TrapReason>("trap::stack_overflow").unwrap(),
+            TrapReason::StackOverflow
+        );
+    }
+
+    #[test]
+    fn test_dependency_type() {
+        for value in 0..=5u8 {
+            assert_eq!(ModuleDependencyType::try_from(value).unwrap() as u8, value);
+            assert_eq!(
+                ExternalLibraryDependencyType::try_from(value).unwrap() as u8,
+                value
+            );
+        }
+
+        assert_eq!(
+            ModuleDependencyType::try_from(6),
+            Err(InvalidDependencyTypeError { value: 6 })
+        );
+        assert_eq!(
+            ExternalLibraryDependencyType::try_from(0xff),
+            Err(InvalidDependencyTypeError { value: 0xff })
+        );
+
+        assert_eq!(ModuleDependencyType::Current.to_string(), "module");
+        assert_eq!(ExternalLibraryDependencyType::System.to_string(), "system");
+        assert_eq!(
+            InvalidDependencyTypeError { value: 6 }.to_string(),
+            "Invalid dependency type 0x06."
+        );
+    }
+
+    #[test]
+    fn test_serialize_dependency_type() {
+        assert_eq!(
+            ason::to_string(&ModuleDependencyType::Share).unwrap(),
+            "module_type::share"
+        );
+        assert_eq!(
+            ason::from_str::<ExternalLibraryDependencyType>("library_type::system").unwrap(),
+            ExternalLibraryDependencyType::System
+        );
+    }
+
+    #[test]
+    fn test_memory_allocation_flags() {
+        let flags = MemoryAllocationFlags::READABLE.union(MemoryAllocationFlags::SHAREABLE);
+        assert_eq!(flags.bits(), 0x5);
+        assert!(flags.contains(MemoryAllocationFlags::READABLE));
+        assert!(!flags.contains(MemoryAllocationFlags::WRITABLE));
+        assert!(MemoryAllocationFlags::DEFAULT.contains(MemoryAllocationFlags::WRITABLE));
+
+        assert_eq!(MemoryAllocationFlags::from_bits(0x7).unwrap().bits(), 0x7);
+        assert_eq!(MemoryAllocationFlags::from_bits(0x8), None);
+    }
+
     #[test]
     fn test_effective_version() {
         let v0 = EffectiveVersion::new(0x11, 0x13, 0x17);
@@ -724,10 +1897,56 @@ mod tests {
         assert_eq!(v1.minor, 0x13);
         assert_eq!(v1.patch, 0x17);
 
-        let v2 = EffectiveVersion::from_version_string("11.13.17");
+        let v2 = EffectiveVersion::from_version_string("11.13.17").unwrap();
         assert_eq!(v2.major, 11);
         assert_eq!(v2.minor, 13);
         assert_eq!(v2.patch, 17);
+
+        let v3 = "1.2.3".parse::<EffectiveVersion>().unwrap();
+        assert_eq!(v3, EffectiveVersion::new(1, 2, 3));
+    }
+
+    #[test]
+    fn test_effective_version_parse_error() {
+        assert!(matches!(
+            EffectiveVersion::from_version_string("1.x.3"),
+            Err(VersionParseError::Malformed { .. })
+        ));
+        assert!(matches!(
+            EffectiveVersion::from_version_string("1..3"),
+            Err(VersionParseError::Malformed { .. })
+        ));
+        assert!(matches!(
+            EffectiveVersion::from_version_string("1.-2.3"),
+            Err(VersionParseError::Malformed { .. })
+        ));
+        assert_eq!(
+            EffectiveVersion::from_version_string("1.65536.3"),
+            Err(VersionParseError::OutOfRange {
+                version: "1.65536.3".to_owned(),
+                component: "65536".to_owned()
+            })
+        );
+        assert_eq!(
+            EffectiveVersion::from_version_string("1.2"),
+            Err(VersionParseError::WrongArity {
+                version: "1.2".to_owned(),
+                count: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_serialize_effective_version() {
+        assert_eq!(
+            ason::to_string(&EffectiveVersion::new(1, 2, 3)).unwrap(),
+            r#""1.2.3""#
+        );
+        assert_eq!(
+            ason::from_str::<EffectiveVersion>(r#""11.13.17""#).unwrap(),
+            EffectiveVersion::new(11, 13, 17)
+        );
+        assert!(ason::from_str::<EffectiveVersion>(r#""1.2""#).is_err());
     }
 
     #[test]
@@ -748,76 +1967,284 @@ mod tests {
         assert!(v0 < v2);
         assert!(v0 < v3);
         assert!(v0 < v4);
+
+        // Ord
+        let mut versions = vec![v2, v3, v0, v4];
+        versions.sort();
+        assert_eq!(versions, vec![v0, v4, v3, v2]);
+        assert_eq!(v0.cmp(&v1), std::cmp::Ordering::Equal);
+
+        // Hash
+        let mut map = HashMap::new();
+        map.insert(v0, "a");
+        map.insert(v2, "b");
+        assert_eq!(map.get(&v1), Some(&"a"));
     }
 
     #[test]
     fn test_effective_version_competibility() {
         assert_eq!(
             EffectiveVersion::from_version_string("1.2.3")
-                .compatible(&EffectiveVersion::from_version_string("1.2.3")),
+                .unwrap()
+                .compatible(&EffectiveVersion::from_version_string("1.2.3").unwrap()),
             VersionCompatibility::Equals
         );
 
         assert_eq!(
             EffectiveVersion::from_version_string("1.2.3")
-                .compatible(&EffectiveVersion::from_version_string("1.1.3")),
+                .unwrap()
+                .compatible(&EffectiveVersion::from_version_string("1.1.3").unwrap()),
             VersionCompatibility::GreaterThan
         );
 
         assert_eq!(
             EffectiveVersion::from_version_string("1.2.3")
-                .compatible(&EffectiveVersion::from_version_string("1.2.2")),
+                .unwrap()
+                .compatible(&EffectiveVersion::from_version_string("1.2.2").unwrap()),
             VersionCompatibility::GreaterThan
         );
 
         assert_eq!(
             EffectiveVersion::from_version_string("1.2.3")
-                .compatible(&EffectiveVersion::from_version_string("1.11.3")),
+                .unwrap()
+                .compatible(&EffectiveVersion::from_version_string("1.11.3").unwrap()),
             VersionCompatibility::LessThan
         );
 
         assert_eq!(
             EffectiveVersion::from_version_string("1.2.3")
-                .compatible(&EffectiveVersion::from_version_string("2.1.3")),
+                .unwrap()
+                .compatible(&EffectiveVersion::from_version_string("2.1.3").unwrap()),
             VersionCompatibility::Conflict
         );
 
         // Zero-major
         assert_eq!(
             EffectiveVersion::from_version_string("0.2.3")
-                .compatible(&EffectiveVersion::from_version_string("0.2.3")),
+                .unwrap()
+                .compatible(&EffectiveVersion::from_version_string("0.2.3").unwrap()),
             VersionCompatibility::Equals
         );
 
         assert_eq!(
             EffectiveVersion::from_version_string("0.2.3")
-                .compatible(&EffectiveVersion::from_version_string("0.2.2")),
+                .unwrap()
+                .compatible(&EffectiveVersion::from_version_string("0.2.2").unwrap()),
             VersionCompatibility::GreaterThan
         );
 
         assert_eq!(
             EffectiveVersion::from_version_string("0.2.3")
-                .compatible(&EffectiveVersion::from_version_string("0.2.11")),
+                .unwrap()
+                .compatible(&EffectiveVersion::from_version_string("0.2.11").unwrap()),
             VersionCompatibility::LessThan
         );
 
         assert_eq!(
             EffectiveVersion::from_version_string("0.2.3")
-                .compatible(&EffectiveVersion::from_version_string("0.3.2")),
+                .unwrap()
+                .compatible(&EffectiveVersion::from_version_string("0.3.2").unwrap()),
             VersionCompatibility::Conflict
         );
     }
 
     #[test]
-    fn test_runtime_edition() {
-        let strlen = RUNTIME_EDITION
-            .iter()
-            .position(|c| *c == 0)
-            .unwrap_or(RUNTIME_EDITION.len());
+    fn test_compatibility_class() {
+        let v = |text: &str| EffectiveVersion::from_version_string(text).unwrap();
+
+        assert_eq!(
+            v("1.2.3").compatibility_class(),
+            CompatibilityClass::Major(1)
+        );
+        assert_eq!(
+            v("1.2.3").compatibility_class(),
+            v("1.99.0").compatibility_class()
+        );
+        assert_eq!(
+            v("0.2.3").compatibility_class(),
+            CompatibilityClass::ZeroMajor(2)
+        );
+        assert_ne!(
+            v("0.2.3").compatibility_class(),
+            v("0.3.0").compatibility_class()
+        );
+        assert_eq!(v("0.2.3").compatibility_class().to_string(), "0.2.x");
+        assert_eq!(v("2.0.0").compatibility_class().to_string(), "2.x");
+    }
+
+    #[test]
+    fn test_image_format_version() {
+        let v1_1 = ImageFormatVersion::new(1, 1);
+        assert!(v1_1.supports(&ImageFormatVersion::new(1, 0)));
+        assert!(v1_1.supports(&ImageFormatVersion::new(1, 1)));
+        assert!(!v1_1.supports(&ImageFormatVersion::new(1, 2)));
+        assert!(!v1_1.supports(&ImageFormatVersion::new(0, 1)));
+        assert!(!v1_1.supports(&ImageFormatVersion::new(2, 0)));
+
+        assert_eq!(ImageFormatVersion::from_u32(v1_1.to_u32()), v1_1);
+        assert_eq!(v1_1.to_u32(), 0x0001_0001);
+        assert_eq!(IMAGE_FORMAT_VERSION.to_string(), "1.1");
+    }
+
+    #[test]
+    fn test_git_reference() {
+        let parse = |text: &str| GitReference::from_reference_string(text);
+
+        assert_eq!(
+            parse("commit:1a2b3c4"),
+            Ok(GitReference::Commit("1a2b3c4".to_owned()))
+        );
+        assert_eq!(
+            parse("branch:main"),
+            Ok(GitReference::Branch("main".to_owned()))
+        );
+        assert_eq!(
+            parse("tag:v1.0.0"),
+            Ok(GitReference::Tag("v1.0.0".to_owned()))
+        );
+
+        // legacy values
+        assert_eq!(
+            parse("0123456789abcdef0123456789abcdef01234567"),
+            Ok(GitReference::Commit(
+                "0123456789abcdef0123456789abcdef01234567".to_owned()
+            ))
+        );
+        assert_eq!(parse("v1.0.0"), Ok(GitReference::Tag("v1.0.0".to_owned())));
+
+        assert_eq!(parse(""), Err(GitReferenceError::Empty));
+        assert_eq!(parse("branch:"), Err(GitReferenceError::Empty));
+        assert_eq!(
+            parse("commit:xyz"),
+            Err(GitReferenceError::InvalidCommitHash {
+                hash: "xyz".to_owned()
+            })
+        );
 
         assert_eq!(
-            std::str::from_utf8(&RUNTIME_EDITION[..strlen]).unwrap(),
-            RUNTIME_EDITION_STRING
+            GitReference::Branch("main".to_owned()).to_string(),
+            "branch:main"
+        );
+        assert!(GitReference::Branch("main".to_owned()).is_moving());
+        assert!(!GitReference::Tag("v1.0.0".to_owned()).is_moving());
+    }
+
+    #[test]
+    fn test_feature_activation() {
+        let mut flags = HashMap::new();
+        flags.insert("enable_tls".to_owned(), vec!["openssl".to_owned()]);
+        flags.insert(
+            "enable_zip".to_owned(),
+            vec!["zlib".to_owned(), "openssl".to_owned()],
+        );
+        let activation = FeatureActivation { flags };
+
+        assert_eq!(
+            activation.get_activating_flags("openssl"),
+            vec!["enable_tls", "enable_zip"]
+        );
+        assert_eq!(
+            activation.get_activation_condition("zlib"),
+            DependencyCondition::Any(vec![DependencyConditionCheck::True(
+                "enable_zip".to_owned()
+            )])
+        );
+        assert_eq!(
+            activation.get_activation_condition("lz4"),
+            DependencyCondition::False
+        );
+
+        let mut properties = HashMap::new();
+        properties.insert("enable_tls".to_owned(), PropertyValue::Flag(false));
+        properties.insert("enable_zip".to_owned(), PropertyValue::Flag(true));
+        assert!(activation.is_activated("openssl", &properties));
+        assert!(activation.is_activated("zlib", &properties));
+        assert!(!activation.is_activated("lz4", &properties));
+
+        properties.insert("enable_zip".to_owned(), PropertyValue::Flag(false));
+        assert!(!activation.is_activated("openssl", &properties));
+    }
+
+    #[test]
+    fn test_archive_format() {
+        assert_eq!(
+            ArchiveFormat::from_path("vendor/sha2-1.0.0.tar.gz"),
+            Some(ArchiveFormat::TarGz)
+        );
+        assert_eq!(
+            ArchiveFormat::from_path("vendor/SHA2.ZIP"),
+            Some(ArchiveFormat::Zip)
+        );
+        assert_eq!(
+            ArchiveFormat::from_path("vendor/sha2.tar"),
+            Some(ArchiveFormat::Tar)
+        );
+        assert_eq!(ArchiveFormat::from_path("vendor/sha2"), None);
+    }
+
+    #[test]
+    fn test_system_library_min_version() {
+        let mut dependency = DependencySystem {
+            lookup: SystemLibraryLookup::PkgConfig("openssl".to_owned()),
+            min_version: Some("3.0".to_owned()),
+            condition: DependencyCondition::True,
+            kind: DependencyKind::Normal,
+            optional: false,
+        };
+
+        let v = |text: &str| EffectiveVersion::from_version_string(text).unwrap();
+        assert_eq!(dependency.get_min_version(), Ok(Some(v("3.0.0"))));
+        assert_eq!(dependency.is_satisfied_by(&v("3.0.2")), Ok(true));
+        assert_eq!(dependency.is_satisfied_by(&v("1.1.1")), Ok(false));
+
+        dependency.min_version = None;
+        assert_eq!(dependency.is_satisfied_by(&v("1.1.1")), Ok(true));
+
+        dependency.min_version = Some("3.x".to_owned());
+        assert!(dependency.get_min_version().is_err());
+    }
+
+    #[test]
+    fn test_check_target() {
+        let target = TargetInfo {
+            os: "linux".to_owned(),
+            arch: "aarch64".to_owned(),
+            pointer_width: 64,
+            edition: RUNTIME_EDITION,
+        };
+
+        assert_eq!(
+            DependencyConditionCheck::TargetOs("linux".to_owned()).check_target(&target),
+            Some(true)
+        );
+        assert_eq!(
+            DependencyConditionCheck::TargetArch("x86_64".to_owned()).check_target(&target),
+            Some(false)
+        );
+        assert_eq!(
+            DependencyConditionCheck::PointerWidth(64).check_target(&target),
+            Some(true)
+        );
+        assert_eq!(
+            DependencyConditionCheck::Edition(RUNTIME_EDITION_STRING.to_owned())
+                .check_target(&target),
+            Some(true)
+        );
+        assert_eq!(
+            DependencyConditionCheck::True("enable_abc".to_owned()).check_target(&target),
+            None
+        );
+
+        assert_eq!(TargetInfo::host().os, std::env::consts::OS);
+    }
+
+    #[test]
+    fn test_runtime_edition() {
+        assert_eq!(RUNTIME_EDITION.as_str(), RUNTIME_EDITION_STRING);
+        assert_eq!(SUPPORTED_EDITIONS[0], RUNTIME_EDITION);
+        assert_eq!(
+            Edition::from_bytes(RUNTIME_EDITION.as_bytes()),
+            Ok(RUNTIME_EDITION)
         );
     }
 
@@ -829,6 +2256,8 @@ mod tests {
         assert_eq!(
             ason::to_string(&ModuleDependency::Local(Box::new(DependencyLocal {
                 path: "~/projects/helloworld".to_owned(),
+                kind: DependencyKind::Dev,
+                optional: false,
                 parameters: params0,
                 condition: DependencyCondition::True
             })))
@@ -839,6 +2268,8 @@ mod tests {
         "name": param::bool(true)
     ]
     condition: cond::true
+    kind: kind::dev
+    optional: false
 })"#
         );
 
@@ -851,20 +2282,26 @@ mod tests {
         assert_eq!(
             ason::to_string(&ModuleDependency::Remote(Box::new(DependencyRemote {
                 url: "https://github.com/hemashushu/xiaoxuan-core-module.git".to_owned(),
-                reversion: "v1.0.0".to_owned(),
+                reference: GitReference::Tag("v1.0.0".to_owned()),
+                kind: DependencyKind::Normal,
+                optional: false,
                 parameters: params1,
                 condition: DependencyCondition::False,
                 dir: Some("/modules/http_client".to_owned()),
+                checksum: None,
             })))
             .unwrap(),
             r#"module::remote({
     url: "https://github.com/hemashushu/xiaoxuan-core-module.git"
-    reversion: "v1.0.0"
+    reference: "tag:v1.0.0"
     dir: Option::Some("/modules/http_client")
+    checksum: Option::None
     parameters: [
         "name": param::string("value")
     ]
     condition: cond::false
+    kind: kind::normal
+    optional: false
 })"#
         );
 
@@ -873,7 +2310,10 @@ mod tests {
 
         assert_eq!(
             ason::to_string(&ModuleDependency::Share(Box::new(DependencyShare {
-                version: "2.3".to_owned(),
+                version: "2.3".parse().unwrap(),
+                checksum: Some(Checksum::compute(ChecksumAlgorithm::Sha256, b"")),
+                kind: DependencyKind::Normal,
+                optional: false,
                 parameters: params2,
                 condition: DependencyCondition::Any(vec![
                     DependencyConditionCheck::True("enable_abc".to_owned()),
@@ -883,6 +2323,7 @@ mod tests {
             .unwrap(),
             r#"module::share({
     version: "2.3"
+    checksum: Option::Some("sha256:e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855")
     parameters: [
         "name": param::number(123)
     ]
@@ -890,6 +2331,8 @@ mod tests {
         check::true("enable_abc")
         check::false("enable_xyz")
     ])
+    kind: kind::normal
+    optional: false
 })"#
         );
 
@@ -900,7 +2343,10 @@ mod tests {
         );
         assert_eq!(
             ason::to_string(&ModuleDependency::Share(Box::new(DependencyShare {
-                version: "11.13".to_owned(),
+                version: "11.13".parse().unwrap(),
+                checksum: None,
+                kind: DependencyKind::Normal,
+                optional: false,
                 parameters: params3,
                 condition: DependencyCondition::All(vec![
                     DependencyConditionCheck::String("name".to_owned(), "value".to_owned()),
@@ -910,6 +2356,7 @@ mod tests {
             .unwrap(),
             r#"module::share({
     version: "11.13"
+    checksum: Option::None
     parameters: [
         "name": param::from("other_name")
     ]
@@ -917,10 +2364,35 @@ mod tests {
         check::string("name", "value")
         check::number("another_name", 123)
     ])
+    kind: kind::normal
+    optional: false
 })"#
         );
     }
 
+    #[test]
+    fn test_deserialize_nested_condition() {
+        assert_eq!(
+            ason::from_str::<DependencyCondition>(
+                r#"cond::not(cond::any([
+                    check::true("enable_abc"),
+                    check::cond(cond::all([
+                        check::false("enable_xyz"),
+                        check::number("level", 2)
+                    ]))
+                ]))"#
+            )
+            .unwrap(),
+            DependencyCondition::Not(Box::new(DependencyCondition::Any(vec![
+                DependencyConditionCheck::True("enable_abc".to_owned()),
+                DependencyConditionCheck::Condition(Box::new(DependencyCondition::All(vec![
+                    DependencyConditionCheck::False("enable_xyz".to_owned()),
+                    DependencyConditionCheck::Number("level".to_owned(), 2)
+                ])))
+            ])))
+        );
+    }
+
     #[test]
     fn test_deserialize_dependency() {
         assert_eq!(
@@ -932,6 +2404,8 @@ mod tests {
             .unwrap(),
             ExternalLibraryDependency::Local(Box::new(DependencyLocal {
                 path: "~/projects/helloworld/libabc.so.1".to_owned(),
+                kind: DependencyKind::Normal,
+                optional: false,
                 parameters: HashMap::default(),
                 condition: DependencyCondition::True
             }))
@@ -948,10 +2422,13 @@ mod tests {
             .unwrap(),
             ExternalLibraryDependency::Remote(Box::new(DependencyRemote {
                 url: "https://github.com/hemashushu/xiaoxuan-cc-lz4.git".to_owned(),
-                reversion: "v1.0.0".to_owned(),
+                reference: GitReference::Tag("v1.0.0".to_owned()),
+                kind: DependencyKind::Normal,
+                optional: false,
                 parameters: HashMap::default(),
                 condition: DependencyCondition::False,
                 dir: None,
+                checksum: None,
             }))
         );
 
@@ -967,7 +2444,10 @@ mod tests {
             )
             .unwrap(),
             ExternalLibraryDependency::Share(Box::new(DependencyShare {
-                version: "2.3".to_owned(),
+                version: "2.3".parse().unwrap(),
+                checksum: None,
+                kind: DependencyKind::Normal,
+                optional: false,
                 parameters: HashMap::default(),
                 condition: DependencyCondition::Any(vec![
                     DependencyConditionCheck::True("enable_abc".to_owned()),
@@ -988,7 +2468,10 @@ mod tests {
             )
             .unwrap(),
             ExternalLibraryDependency::Share(Box::new(DependencyShare {
-                version: "11.13".to_owned(),
+                version: "11.13".parse().unwrap(),
+                checksum: None,
+                kind: DependencyKind::Normal,
+                optional: false,
                 parameters: HashMap::default(),
                 condition: DependencyCondition::All(vec![
                     DependencyConditionCheck::String("name".to_owned(), "value".to_owned()),
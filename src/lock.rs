@@ -0,0 +1,380 @@
+// Copyright (c) 2025 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions.
+// For more details, see the LICENSE, LICENSE.additional, and CONTRIBUTING files.
+
+// Lockfile
+// --------
+//
+// The lockfile records the resolved modules (i.e., the selected versions, the sources
+// and the checksums) of an application, so that the later builds use the same
+// modules, e.g.:
+//
+// ```ason
+// [
+//     {
+//         name: "sha2"
+//         version: Option::Some("1.2.3")
+//         source: module::share({
+//             version: "1.2"
+//         })
+//         checksum: Option::Some("sha256:e3b0c442...")
+//     }
+// ]
+// ```
+//
+// The modules are ordered by name, and the root module is not recorded.
+//
+// Lockfile Diff
+// -------------
+//
+// The function `diff_locks` compares two lockfiles (e.g., before and after
+// updating the dependencies) and reports the changes of each module:
+//
+// - `Added` and `Removed`, the module exists in one lockfile only.
+// - `Upgraded` and `Downgraded`, the version of the shared module is changed.
+// - `SourceChanged`, the module comes from a different source, see `ModuleDependency::is_same_source`.
+// - `ChecksumChanged`, the checksum is changed (or added or removed).
+//
+// A module may have multiple changes, e.g. both `Upgraded` and `ChecksumChanged`.
+
+use std::{collections::BTreeMap, fmt::Display};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    checksum::Checksum, graph::get_source_label, resolver::Resolution, EffectiveVersion,
+    ModuleDependency,
+};
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename = "locked_module")]
+pub struct LockedModule {
+    pub name: String,
+
+    /// The selected version of the shared module, or `None` for other modules.
+    pub version: Option<EffectiveVersion>,
+
+    pub source: ModuleDependency,
+
+    /// The checksum of the module source.
+    pub checksum: Option<Checksum>,
+}
+
+/// It is serialized as a list of `LockedModule`.
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ModuleLock {
+    pub modules: Vec<LockedModule>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum LockChange {
+    Added {
+        name: String,
+        version: Option<EffectiveVersion>,
+    },
+
+    Removed {
+        name: String,
+        version: Option<EffectiveVersion>,
+    },
+
+    Upgraded {
+        name: String,
+        from: EffectiveVersion,
+        to: EffectiveVersion,
+    },
+
+    Downgraded {
+        name: String,
+        from: EffectiveVersion,
+        to: EffectiveVersion,
+    },
+
+    SourceChanged {
+        name: String,
+        from: ModuleDependency,
+        to: ModuleDependency,
+    },
+
+    ChecksumChanged {
+        name: String,
+        from: Option<Checksum>,
+        to: Option<Checksum>,
+    },
+}
+
+impl LockChange {
+    pub fn get_name(&self) -> &str {
+        match self {
+            LockChange::Added { name, .. }
+            | LockChange::Removed { name, .. }
+            | LockChange::Upgraded { name, .. }
+            | LockChange::Downgraded { name, .. }
+            | LockChange::SourceChanged { name, .. }
+            | LockChange::ChecksumChanged { name, .. } => name,
+        }
+    }
+}
+
+fn format_optional<T: Display>(value: &Option<T>) -> String {
+    match value {
+        Some(value) => value.to_string(),
+        None => "none".to_owned(),
+    }
+}
+
+impl Display for LockChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LockChange::Added { name, version } => match version {
+                Some(version) => write!(f, "added {} {}", name, version),
+                None => write!(f, "added {}", name),
+            },
+            LockChange::Removed { name, version } => match version {
+                Some(version) => write!(f, "removed {} {}", name, version),
+                None => write!(f, "removed {}", name),
+            },
+            LockChange::Upgraded { name, from, to } => {
+                write!(f, "upgraded {} {} -> {}", name, from, to)
+            }
+            LockChange::Downgraded { name, from, to } => {
+                write!(f, "downgraded {} {} -> {}", name, from, to)
+            }
+            LockChange::SourceChanged { name, from, to } => write!(
+                f,
+                "changed the source of {} {} -> {}",
+                name,
+                get_source_label(from),
+                get_source_label(to)
+            ),
+            LockChange::ChecksumChanged { name, from, to } => write!(
+                f,
+                "changed the checksum of {} {} -> {}",
+                name,
+                format_optional(from),
+                format_optional(to)
+            ),
+        }
+    }
+}
+
+impl ModuleLock {
+    /// Creates the lockfile of the resolution, the checksums are the declared ones,
+    /// the tooling should replace them with the checksums of the fetched sources.
+    pub fn from_resolution(resolution: &Resolution) -> Self {
+        // the nodes are ordered by name.
+        let modules = resolution
+            .graph
+            .get_nodes()
+            .filter(|node| node.source != ModuleDependency::Current)
+            .map(|node| LockedModule {
+                name: node.id.name.to_owned(),
+                version: node.version,
+                source: node.source.clone(),
+                checksum: node.source.get_checksum().cloned(),
+            })
+            .collect();
+
+        Self { modules }
+    }
+
+    pub fn get_module(&self, name: &str) -> Option<&LockedModule> {
+        self.modules.iter().find(|item| item.name == name)
+    }
+}
+
+/// Compares two lockfiles, see the section "Lockfile Diff".
+/// The changes are ordered by the module name.
+pub fn diff_locks(old: &ModuleLock, new: &ModuleLock) -> Vec<LockChange> {
+    let mut pairs: BTreeMap<&str, (Option<&LockedModule>, Option<&LockedModule>)> = BTreeMap::new();
+    for module in &old.modules {
+        pairs.entry(&module.name).or_default().0 = Some(module);
+    }
+    for module in &new.modules {
+        pairs.entry(&module.name).or_default().1 = Some(module);
+    }
+
+    let mut changes = vec![];
+    for (name, pair) in pairs {
+        let name = name.to_owned();
+        let (old, new) = match pair {
+            (Some(old), Some(new)) => (old, new),
+            (None, Some(new)) => {
+                changes.push(LockChange::Added {
+                    name,
+                    version: new.version,
+                });
+                continue;
+            }
+            (Some(old), None) => {
+                changes.push(LockChange::Removed {
+                    name,
+                    version: old.version,
+                });
+                continue;
+            }
+            (None, None) => unreachable!(),
+        };
+
+        if !old.source.is_same_source(&new.source) {
+            changes.push(LockChange::SourceChanged {
+                name: name.clone(),
+                from: old.source.clone(),
+                to: new.source.clone(),
+            });
+        }
+
+        if let (Some(from), Some(to)) = (old.version, new.version) {
+            if to > from {
+                changes.push(LockChange::Upgraded {
+                    name: name.clone(),
+                    from,
+                    to,
+                });
+            } else if to < from {
+                changes.push(LockChange::Downgraded {
+                    name: name.clone(),
+                    from,
+                    to,
+                });
+            }
+        }
+
+        if old.checksum != new.checksum {
+            changes.push(LockChange::ChecksumChanged {
+                name,
+                from: old.checksum.clone(),
+                to: new.checksum.clone(),
+            });
+        }
+    }
+
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        checksum::{Checksum, ChecksumAlgorithm},
+        lock::{diff_locks, LockChange, LockedModule, ModuleLock},
+        DependencyCondition, DependencyKind, DependencyLocal, DependencyShare, EffectiveVersion,
+        ModuleDependency,
+    };
+
+    fn share() -> ModuleDependency {
+        ModuleDependency::Share(Box::new(DependencyShare {
+            version: "1.0".parse().unwrap(),
+            checksum: None,
+            parameters: HashMap::new(),
+            condition: DependencyCondition::True,
+            kind: DependencyKind::Normal,
+            optional: false,
+        }))
+    }
+
+    fn local(path: &str) -> ModuleDependency {
+        ModuleDependency::Local(Box::new(DependencyLocal {
+            path: path.to_owned(),
+            parameters: HashMap::new(),
+            condition: DependencyCondition::True,
+            kind: DependencyKind::Normal,
+            optional: false,
+        }))
+    }
+
+    fn locked(
+        name: &str,
+        version: Option<&str>,
+        source: ModuleDependency,
+        checksum: Option<&[u8]>,
+    ) -> LockedModule {
+        LockedModule {
+            name: name.to_owned(),
+            version: version.map(|item| EffectiveVersion::from_version_string(item).unwrap()),
+            source,
+            checksum: checksum.map(|data| Checksum::compute(ChecksumAlgorithm::Sha256, data)),
+        }
+    }
+
+    #[test]
+    fn test_diff_locks() {
+        let old = ModuleLock {
+            modules: vec![
+                locked("common", Some("1.2.0"), share(), Some(b"a")),
+                locked("md5", Some("1.0.0"), share(), None),
+                locked("sha2", Some("1.3.0"), share(), None),
+                locked("utils", None, local("../utils"), None),
+                locked("zlib", None, local("zlib"), None),
+            ],
+        };
+        let new = ModuleLock {
+            modules: vec![
+                locked("common", Some("1.4.1"), share(), Some(b"b")),
+                locked("sha2", Some("1.2.9"), share(), None),
+                locked("utils", None, local("../utils/"), None),
+                locked("xml", Some("0.3.0"), share(), None),
+                locked("zlib", None, local("vendor/zlib"), None),
+            ],
+        };
+
+        let v = |text: &str| EffectiveVersion::from_version_string(text).unwrap();
+        let changes = diff_locks(&old, &new);
+        assert_eq!(
+            changes,
+            vec![
+                LockChange::Upgraded {
+                    name: "common".to_owned(),
+                    from: v("1.2.0"),
+                    to: v("1.4.1"),
+                },
+                LockChange::ChecksumChanged {
+                    name: "common".to_owned(),
+                    from: old.modules[0].checksum.clone(),
+                    to: new.modules[0].checksum.clone(),
+                },
+                LockChange::Removed {
+                    name: "md5".to_owned(),
+                    version: Some(v("1.0.0")),
+                },
+                LockChange::Downgraded {
+                    name: "sha2".to_owned(),
+                    from: v("1.3.0"),
+                    to: v("1.2.9"),
+                },
+                LockChange::Added {
+                    name: "xml".to_owned(),
+                    version: Some(v("0.3.0")),
+                },
+                LockChange::SourceChanged {
+                    name: "zlib".to_owned(),
+                    from: local("zlib"),
+                    to: local("vendor/zlib"),
+                },
+            ]
+        );
+
+        assert_eq!(
+            changes
+                .iter()
+                .map(|change| change.to_string())
+                .filter(|text| !text.contains("checksum"))
+                .collect::<Vec<_>>(),
+            vec![
+                "upgraded common 1.2.0 -> 1.4.1",
+                "removed md5 1.0.0",
+                "downgraded sha2 1.3.0 -> 1.2.9",
+                "added xml 0.3.0",
+                "changed the source of zlib local:zlib -> local:vendor/zlib",
+            ]
+        );
+
+        assert!(diff_locks(&new, &new).is_empty());
+    }
+}
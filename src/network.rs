@@ -0,0 +1,273 @@
+// Copyright (c) 2025 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions.
+// For more details, see the LICENSE, LICENSE.additional, and CONTRIBUTING files.
+
+// Network Configuration
+// ---------------------
+//
+// The remote modules (i.e., `ModuleDependency::Remote`) and the registries
+// (see the module `registry`) are fetched over the network, users can configure
+// the mirrors, the proxy and the credentials, e.g.:
+//
+// ```ason
+// network: {
+//     mirrors: [
+//         {
+//             source: "https://github.com/"
+//             mirror: "https://mirror.example.com/github/"
+//         }
+//     ]
+//     proxy: {
+//         http: Option::Some("http://proxy.example.com:8080")
+//         https: Option::Some("http://proxy.example.com:8080")
+//         no_proxy: ["localhost", "example.com"]
+//     }
+//     credentials: [
+//         {
+//             registry: "corp"
+//             token: "..."
+//         }
+//     ]
+// }
+// ```
+//
+// Mirrors
+// -------
+//
+// A URL starting with the `source` of a mirror is rewritten by replacing the
+// prefix with the `mirror`, if multiple mirrors match, the one with the longest
+// `source` is used.
+//
+// Proxy
+// -----
+//
+// The proxy is selected by the scheme of the URL (after the mirror rewriting).
+// The hosts listed in `no_proxy` (and their subdomains) are accessed directly,
+// and the item "*" disables the proxy for all hosts.
+//
+// Credentials
+// -----------
+//
+// The tokens are sent to the registries with the same name (see `RegistryConfig::name`).
+// The tokens are redacted in the `Debug` output so that they do not leak
+// into logs and error messages.
+
+use std::fmt::Debug;
+
+use serde::{Deserialize, Serialize};
+
+pub const NO_PROXY_WILDCARD: &str = "*";
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename = "mirror")]
+pub struct MirrorConfig {
+    /// The URL prefix to be replaced, e.g. "https://github.com/".
+    pub source: String,
+
+    /// The replacement URL prefix, e.g. "https://mirror.example.com/github/".
+    pub mirror: String,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Default, Serialize, Deserialize)]
+#[serde(rename = "proxy")]
+pub struct ProxyConfig {
+    /// Optional.
+    /// The proxy for the "http" URLs, e.g. "http://proxy.example.com:8080".
+    #[serde(default)]
+    pub http: Option<String>,
+
+    /// Optional.
+    /// The proxy for the "https" URLs.
+    #[serde(default)]
+    pub https: Option<String>,
+
+    /// Optional.
+    /// The default value is [].
+    #[serde(default)]
+    pub no_proxy: Vec<String>,
+}
+
+/// An authentication token.
+///
+/// It is serialized as a string, and it is redacted in the `Debug` output.
+#[derive(PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AuthToken(String);
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename = "credential")]
+pub struct RegistryCredential {
+    /// The name of the registry, e.g. "corp".
+    pub registry: String,
+
+    pub token: AuthToken,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Default, Serialize, Deserialize)]
+#[serde(rename = "network")]
+pub struct NetworkConfig {
+    /// Optional.
+    /// The default value is [].
+    #[serde(default)]
+    pub mirrors: Vec<MirrorConfig>,
+
+    /// Optional.
+    /// The default value is a proxy config without any proxy.
+    #[serde(default)]
+    pub proxy: ProxyConfig,
+
+    /// Optional.
+    /// The default value is [].
+    #[serde(default)]
+    pub credentials: Vec<RegistryCredential>,
+}
+
+impl AuthToken {
+    pub fn new(token: &str) -> Self {
+        Self(token.to_owned())
+    }
+
+    /// Returns the plain token, do not write it to logs.
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Debug for AuthToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("AuthToken(<redacted>)")
+    }
+}
+
+/// Returns the scheme and the host of the URL, e.g.
+/// "https://user@example.com:8080/path" to `Some(("https", "example.com"))`.
+fn split_url(url: &str) -> Option<(&str, &str)> {
+    let (scheme, rest) = url.split_once("://")?;
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    let host_port = authority
+        .rsplit_once('@')
+        .map_or(authority, |(_, host_port)| host_port);
+
+    let host = if let Some(ipv6) = host_port.strip_prefix('[') {
+        ipv6.split_once(']').map_or(ipv6, |(host, _)| host)
+    } else {
+        host_port.split(':').next().unwrap_or_default()
+    };
+
+    Some((scheme, host))
+}
+
+impl ProxyConfig {
+    fn is_excluded(&self, host: &str) -> bool {
+        self.no_proxy.iter().any(|item| {
+            let item = item.trim_start_matches('.');
+            item == NO_PROXY_WILDCARD
+                || host.eq_ignore_ascii_case(item)
+                || (host.len() > item.len()
+                    && host.as_bytes()[host.len() - item.len() - 1] == b'.'
+                    && host[host.len() - item.len()..].eq_ignore_ascii_case(item))
+        })
+    }
+
+    /// Returns the proxy for the URL, see the section "Proxy".
+    pub fn get_proxy(&self, url: &str) -> Option<&str> {
+        let (scheme, host) = split_url(url)?;
+        if self.is_excluded(host) {
+            return None;
+        }
+
+        if scheme.eq_ignore_ascii_case("https") {
+            self.https.as_deref()
+        } else if scheme.eq_ignore_ascii_case("http") {
+            self.http.as_deref()
+        } else {
+            None
+        }
+    }
+}
+
+impl NetworkConfig {
+    /// Returns the URL rewritten by the mirrors, see the section "Mirrors".
+    pub fn rewrite_url(&self, url: &str) -> String {
+        match self
+            .mirrors
+            .iter()
+            .filter(|item| url.starts_with(&item.source))
+            .max_by_key(|item| item.source.len())
+        {
+            Some(item) => format!("{}{}", item.mirror, &url[item.source.len()..]),
+            None => url.to_owned(),
+        }
+    }
+
+    pub fn get_token(&self, registry_name: &str) -> Option<&AuthToken> {
+        self.credentials
+            .iter()
+            .find(|item| item.registry == registry_name)
+            .map(|item| &item.token)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::network::{AuthToken, MirrorConfig, NetworkConfig, ProxyConfig, RegistryCredential};
+
+    #[test]
+    fn test_network_config() {
+        let config = NetworkConfig {
+            mirrors: vec![
+                MirrorConfig {
+                    source: "https://github.com/".to_owned(),
+                    mirror: "https://mirror.example.com/github/".to_owned(),
+                },
+                MirrorConfig {
+                    source: "https://github.com/hemashushu/".to_owned(),
+                    mirror: "https://git.example.com/".to_owned(),
+                },
+            ],
+            proxy: ProxyConfig {
+                http: None,
+                https: Some("http://proxy:8080".to_owned()),
+                no_proxy: vec![".example.com".to_owned()],
+            },
+            credentials: vec![RegistryCredential {
+                registry: "corp".to_owned(),
+                token: AuthToken::new("secret"),
+            }],
+        };
+
+        assert_eq!(
+            config.rewrite_url("https://github.com/foo/sha2.git"),
+            "https://mirror.example.com/github/foo/sha2.git"
+        );
+        assert_eq!(
+            config.rewrite_url("https://github.com/hemashushu/registry.git"),
+            "https://git.example.com/registry.git"
+        );
+        assert_eq!(
+            config.rewrite_url("https://gitlab.com/sha2.git"),
+            "https://gitlab.com/sha2.git"
+        );
+
+        let proxy = &config.proxy;
+        assert_eq!(
+            proxy.get_proxy("https://github.com/foo/sha2.git"),
+            Some("http://proxy:8080")
+        );
+        assert_eq!(proxy.get_proxy("http://github.com/foo/sha2.git"), None);
+        assert_eq!(proxy.get_proxy("https://git.example.com:443/sha2"), None);
+        assert_eq!(proxy.get_proxy("https://user@example.com/sha2"), None);
+        assert_eq!(
+            proxy.get_proxy("https://notexample.com/sha2"),
+            Some("http://proxy:8080")
+        );
+
+        assert_eq!(config.get_token("corp").unwrap().expose(), "secret");
+        assert_eq!(config.get_token("central"), None);
+        assert!(!format!("{:?}", config).contains("secret"));
+    }
+}
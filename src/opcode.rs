@@ -202,8 +202,16 @@
 // The 'index' carries information about the kind, data type, length (boundary), and other properties of the object.
 // For example, when accessing data using an index, the VM can verify the type and range to ensure safety.
 
+use std::fmt::Display;
+
+use crate::{
+    edition::{Edition, EDITION_HISTORY},
+    ImageFormatVersion,
+};
+
 #[repr(u16)]
-#[derive(Debug, PartialEq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Clone, Copy)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[allow(non_camel_case_types)]
 pub enum Opcode {
     // Category: Fundamental
@@ -237,6 +245,52 @@ pub enum Opcode {
     imm_f32, // (param number:i32) -> f32
     imm_f64, // (param number_low:i32 number_high:i32) -> f64
 
+    // Operand Stack Manipulation
+    // --------------------------
+    //
+    // All operands occupy the same size (8 bytes) on the operand stack,
+    // so the following instructions work for operands of any data type.
+    //
+    // Note: These instructions are available since the image format version 1.1.
+
+    // Duplicates the operand on the top of the stack.
+    //
+    // () (operand value:any) -> (any, any)
+    dup,
+
+    // Discards the operand on the top of the stack, it is commonly used to
+    // discard the unused results of a function call.
+    //
+    // () (operand value:any) -> ()
+    drop,
+
+    // Swaps the top two operands.
+    //
+    // () (operand a:any b:any) -> (b:any a:any)
+    swap,
+
+    // Compact Immediate Numbers
+    // -------------------------
+    //
+    // Most immediate numbers in programs are small, the following instructions encode them
+    // in a shorter form, i.e., 4 bytes instead of 8 bytes of the instruction `imm_i32`.
+    //
+    // Note: These instructions are available since the image format version 1.1.
+
+    // Pushes a small immediate number onto the operand stack.
+    //
+    // Note: The i16 immediate number will be internally sign-extended to i64 automatically.
+    //
+    // (param immediate_number:i16) -> i32
+    imm_i32_small,
+
+    // Pushes 0 onto the operand stack.
+    //
+    // Since all bits of the operand are zero, it can be used as i32 0, i64 0, f32 +0.0 and f64 +0.0.
+    //
+    // () -> any
+    push_zero,
+
     // Category: Local Variables
     // --------------------------
 
@@ -381,9 +435,11 @@ pub enum Opcode {
     // - Imported read-only data items
     // - Imported read-write data items
     // - Imported uninitialized data items
+    // - Imported thread-local data items
     // - Internal read-only data items
     // - Internal read-write data items
     // - Internal uninitialized data items
+    // - Internal thread-local data items
     // - Dynamically allocated memory
     //
     // In the default VM implementation, the data public index is sorted in the order listed above.
@@ -395,6 +451,22 @@ pub enum Opcode {
     //   making this index more like an identifier than a sequential number.
     // - The index should be unique within the VM scope, but it maybe reused for dynamically allocated memory.
 
+    // Thread-Local Data
+    // -----------------
+    //
+    // Data items in the thread-local section (see `DataSectionType::ThreadLocal`) are
+    // accessed by the same "data_load_xxx" and "data_store_xxx" instructions (and the
+    // "extend", "dynamic" variants), the section type of the data public index
+    // selects the addressing mode:
+    //
+    // - Each VM thread has its own copy of the thread-local data items, the copy is
+    //   initialized with the initial values of the items when the thread is created.
+    // - The data public index of a thread-local data item is the same in all threads,
+    //   but it refers to the copy of the current thread.
+    // - The instructions `host_addr_data_xxx` return the address of the copy of the current thread.
+    //
+    // Note: Thread-local data is available since the image format version 1.1.
+
     // Load Data
     // ---------
     // Note: All loaded data, except i64, will be sign-extended to i64.
@@ -661,6 +733,148 @@ pub enum Opcode {
     // () (operand left:f64 right:f64) -> f64
     div_f64,
 
+    // Overflow-Reporting Arithmetic
+    // -----------------------------
+    //
+    // The following instructions push both the wrapped result and an overflow flag
+    // onto the operand stack, the flag is on the top of the stack.
+    // The flag is a boolean (i.e., TRUE is 1 and FALSE is 0, type i64).
+    //
+    // - The "_s" variants treat operands as signed integers,
+    //   e.g., `add_overflow_i32_s(0x7fff_ffff, 1)` -> (0x8000_0000, 1).
+    // - The "_u" variants treat operands as unsigned integers,
+    //   e.g., `add_overflow_i32_u(0xffff_ffff, 1)` -> (0, 1).
+    //
+    // Note: These instructions are available since the image format version 1.1.
+
+    // Addition with overflow flag.
+    //
+    // () (operand left:i32 right:i32) -> (result:i32 overflow:i64)
+    add_overflow_i32_s,
+
+    // () (operand left:i32 right:i32) -> (result:i32 overflow:i64)
+    add_overflow_i32_u,
+
+    // () (operand left:i64 right:i64) -> (result:i64 overflow:i64)
+    add_overflow_i64_s,
+
+    // () (operand left:i64 right:i64) -> (result:i64 overflow:i64)
+    add_overflow_i64_u,
+
+    // Subtraction with overflow flag.
+    //
+    // () (operand left:i32 right:i32) -> (result:i32 overflow:i64)
+    sub_overflow_i32_s,
+
+    // () (operand left:i32 right:i32) -> (result:i32 overflow:i64)
+    sub_overflow_i32_u,
+
+    // () (operand left:i64 right:i64) -> (result:i64 overflow:i64)
+    sub_overflow_i64_s,
+
+    // () (operand left:i64 right:i64) -> (result:i64 overflow:i64)
+    sub_overflow_i64_u,
+
+    // Multiplication with overflow flag.
+    //
+    // () (operand left:i32 right:i32) -> (result:i32 overflow:i64)
+    mul_overflow_i32_s,
+
+    // () (operand left:i32 right:i32) -> (result:i32 overflow:i64)
+    mul_overflow_i32_u,
+
+    // () (operand left:i64 right:i64) -> (result:i64 overflow:i64)
+    mul_overflow_i64_s,
+
+    // () (operand left:i64 right:i64) -> (result:i64 overflow:i64)
+    mul_overflow_i64_u,
+
+    // Carry-Chain Arithmetic
+    // ----------------------
+    //
+    // The following instructions treat operands as unsigned integers, they are used to
+    // implement big integers (i.e., multi-precision arithmetic) by chaining the carry
+    // (or borrow) from the low limbs to the high limbs, e.g., adding two 128-bit numbers:
+    //
+    // ```assembly
+    // ;; (carry_0, low) = a_low + b_low + 0
+    // ;; (carry_1, high) = a_high + b_high + carry_0
+    // ```
+    //
+    // The carry (or borrow) is a boolean (i.e., 0 or 1, type i64), the `carry_in` (or `borrow_in`)
+    // operand is treated as 1 if it is not zero.
+    //
+    // Note: These instructions are available since the image format version 1.1.
+
+    // Addition with carry, `result = left + right + carry_in`.
+    //
+    // () (operand left:i32 right:i32 carry_in:i64) -> (result:i32 carry_out:i64)
+    add_with_carry_i32,
+
+    // () (operand left:i64 right:i64 carry_in:i64) -> (result:i64 carry_out:i64)
+    add_with_carry_i64,
+
+    // Subtraction with borrow, `result = left - right - borrow_in`.
+    //
+    // () (operand left:i32 right:i32 borrow_in:i64) -> (result:i32 borrow_out:i64)
+    sub_with_borrow_i32,
+
+    // () (operand left:i64 right:i64 borrow_in:i64) -> (result:i64 borrow_out:i64)
+    sub_with_borrow_i64,
+
+    // Widening Multiplication
+    // -----------------------
+    //
+    // Note: These instructions are available since the image format version 1.1.
+
+    // Returns the high 64 bits of the 128-bit product of two signed i64 numbers.
+    //
+    // e.g., `mul_hi_i64_s(-1, 2)` -> -1 (0xffff_ffff_ffff_ffff), since -1 * 2 = -2
+    // is 0xffff_ffff_ffff_ffff_ffff_ffff_ffff_fffe in 128-bit.
+    //
+    // () (operand left:i64 right:i64) -> i64
+    mul_hi_i64_s,
+
+    // Returns the high 64 bits of the 128-bit product of two unsigned i64 numbers.
+    //
+    // e.g., `mul_hi_i64_u(0xffff_ffff_ffff_ffff, 2)` -> 1
+    //
+    // () (operand left:i64 right:i64) -> i64
+    mul_hi_i64_u,
+
+    // Multiplies two signed i32 numbers and returns the full 64-bit product.
+    //
+    // () (operand left:i32 right:i32) -> i64
+    mul_wide_i32_s,
+
+    // Multiplies two unsigned i32 numbers and returns the full 64-bit product.
+    //
+    // () (operand left:i32 right:i32) -> i64
+    mul_wide_i32_u,
+
+    // Fused Division and Remainder
+    // ----------------------------
+    //
+    // The following instructions push both the quotient and the remainder onto the operand stack,
+    // the remainder is on the top of the stack. They are equivalent to `div_xxx` followed by
+    // `rem_xxx` with the same operands, but the division is only performed once.
+    //
+    // e.g., `div_rem_i32_s(-7, 2)` -> (-3, -1)
+    //
+    // Note: These instructions are available since the image format version 1.1.
+
+    // () (operand left:i32 right:i32) -> (quotient:i32 remainder:i32)
+    div_rem_i32_s,
+
+    // () (operand left:i32 right:i32) -> (quotient:i32 remainder:i32)
+    div_rem_i32_u,
+
+    // () (operand left:i64 right:i64) -> (quotient:i64 remainder:i64)
+    div_rem_i64_s,
+
+    // () (operand left:i64 right:i64) -> (quotient:i64 remainder:i64)
+    div_rem_i64_u,
+
     // Category: Bitwise
     // -----------------
     //
@@ -741,6 +955,65 @@ pub enum Opcode {
     count_trailing_zeros_i64, // Count trailing zeros: () (operand number:i64) -> i32
     count_ones_i64, // Count the number of 1s in the binary representation: () (operand number:i64) -> i32
 
+    // Bit-Field Instructions
+    // ----------------------
+    //
+    // The bit-field is specified by the parameters `offset_bits` (the position of the lowest bit)
+    // and `length_bits`, the range of the field must be within the number, i.e.,
+    // `offset_bits + length_bits <= 32` (or 64 for i64), and `length_bits > 0`.
+    //
+    // Example of instruction `extract_bits_i32`:
+    //
+    // ```assembly
+    // imm_i32(0b1011_0110)
+    //
+    // ;; The top operand on the operand stack is 0b1101
+    // extract_bits_i32(2, 4)
+    // ```
+    //
+    // Example of instruction `insert_bits_i32`:
+    //
+    // ```assembly
+    // imm_i32(0b1111_1111)    ;; the target number
+    // imm_i32(0b0000_0010)    ;; the value of the field
+    //
+    // ;; The top operand on the operand stack is 0b1100_1011
+    // insert_bits_i32(2, 4)
+    // ```
+    //
+    // Note: These instructions are available since the image format version 1.1.
+
+    // Extracts a bit-field, the result is zero-extended.
+    //
+    // (param offset_bits:i16 length_bits:i32) (operand number:i32) -> i32
+    extract_bits_i32,
+
+    // (param offset_bits:i16 length_bits:i32) (operand number:i64) -> i64
+    extract_bits_i64,
+
+    // Replaces a bit-field of the target number with the low bits of the value.
+    //
+    // (param offset_bits:i16 length_bits:i32) (operand target:i32 value:i32) -> i32
+    insert_bits_i32,
+
+    // (param offset_bits:i16 length_bits:i32) (operand target:i64 value:i64) -> i64
+    insert_bits_i64,
+
+    // Shift and rotate by an immediate number of bits, e.g., `shift_left_imm_i32(3)` is
+    // equivalent to `imm_i32(3); shift_left_i32()`.
+    //
+    // Note: These instructions are available since the image format version 1.1.
+    shift_left_imm_i32, // (param move_bits:i16) (operand number:i32) -> i32, move_bits = [0, 32)
+    shift_right_imm_i32_s, // (param move_bits:i16) (operand number:i32) -> i32, move_bits = [0, 32)
+    shift_right_imm_i32_u, // (param move_bits:i16) (operand number:i32) -> i32, move_bits = [0, 32)
+    rotate_left_imm_i32, // (param move_bits:i16) (operand number:i32) -> i32, move_bits = [0, 32)
+    rotate_right_imm_i32, // (param move_bits:i16) (operand number:i32) -> i32, move_bits = [0, 32)
+    shift_left_imm_i64, // (param move_bits:i16) (operand number:i64) -> i64, move_bits = [0, 64)
+    shift_right_imm_i64_s, // (param move_bits:i16) (operand number:i64) -> i64, move_bits = [0, 64)
+    shift_right_imm_i64_u, // (param move_bits:i16) (operand number:i64) -> i64, move_bits = [0, 64)
+    rotate_left_imm_i64, // (param move_bits:i16) (operand number:i64) -> i64, move_bits = [0, 64)
+    rotate_right_imm_i64, // (param move_bits:i16) (operand number:i64) -> i64, move_bits = [0, 64)
+
     // Category: Math
     // --------------
     //
@@ -1035,6 +1308,140 @@ pub enum Opcode {
     // () (operand number:f64 base:f64) -> f64
     log_f64,
 
+    // Note: The following instructions (`atan2_xxx` and the hyperbolic functions)
+    // are available since the image format version 1.1.
+
+    // Four-quadrant arctangent of `y/x` for f32, the result is in the range [-π, π]
+    //
+    // () (operand y:f32 x:f32) -> f32
+    atan2_f32,
+
+    // Four-quadrant arctangent of `y/x` for f64
+    //
+    // () (operand y:f64 x:f64) -> f64
+    atan2_f64,
+
+    // Hyperbolic sine function for f32
+    //
+    // () (operand number:f32) -> f32
+    sinh_f32,
+
+    // Hyperbolic sine function for f64
+    //
+    // () (operand number:f64) -> f64
+    sinh_f64,
+
+    // Hyperbolic cosine function for f32
+    //
+    // () (operand number:f32) -> f32
+    cosh_f32,
+
+    // Hyperbolic cosine function for f64
+    //
+    // () (operand number:f64) -> f64
+    cosh_f64,
+
+    // Hyperbolic tangent function for f32
+    //
+    // () (operand number:f32) -> f32
+    tanh_f32,
+
+    // Hyperbolic tangent function for f64
+    //
+    // () (operand number:f64) -> f64
+    tanh_f64,
+
+    // Inverse hyperbolic sine function for f32
+    //
+    // () (operand number:f32) -> f32
+    asinh_f32,
+
+    // Inverse hyperbolic sine function for f64
+    //
+    // () (operand number:f64) -> f64
+    asinh_f64,
+
+    // Inverse hyperbolic cosine function for f32
+    //
+    // () (operand number:f32) -> f32
+    acosh_f32,
+
+    // Inverse hyperbolic cosine function for f64
+    //
+    // () (operand number:f64) -> f64
+    acosh_f64,
+
+    // Inverse hyperbolic tangent function for f32
+    //
+    // () (operand number:f32) -> f32
+    atanh_f32,
+
+    // Inverse hyperbolic tangent function for f64
+    //
+    // () (operand number:f64) -> f64
+    atanh_f64,
+
+    // Note: The following instructions (`hypot_xxx`, `exp_m1_xxx` and `ln_1p_xxx`)
+    // are available since the image format version 1.1.
+    // They are more accurate than the naive formulations, e.g., `sqrt(x*x + y*y)`,
+    // `exp(x) - 1` and `ln(1 + x)`, especially when the argument is close to zero
+    // (or the intermediate result overflows).
+
+    // Length of the hypotenuse, i.e., `sqrt(x^2 + y^2)`, without intermediate overflow, for f32
+    //
+    // () (operand x:f32 y:f32) -> f32
+    hypot_f32,
+
+    // () (operand x:f64 y:f64) -> f64
+    hypot_f64,
+
+    // `e^x - 1` for f32
+    //
+    // () (operand number:f32) -> f32
+    exp_m1_f32,
+
+    // () (operand number:f64) -> f64
+    exp_m1_f64,
+
+    // `ln(1 + x)` for f32
+    //
+    // () (operand number:f32) -> f32
+    ln_1p_f32,
+
+    // () (operand number:f64) -> f64
+    ln_1p_f64,
+
+    // Integer Minimum, Maximum and Clamp
+    // ----------------------------------
+    //
+    // The "_s" variants treat operands as signed integers, and the "_u" variants
+    // treat operands as unsigned integers.
+    //
+    // The instruction `clamp_xxx` restricts the number to the range [min, max], i.e.,
+    // `max(min, min(number, max))`, the result is undefined if `min > max`.
+    //
+    // Note: These instructions are available since the image format version 1.1.
+
+    // () (operand left:i32 right:i32) -> i32
+    min_i32_s,
+    min_i32_u,
+    max_i32_s,
+    max_i32_u,
+
+    // () (operand left:i64 right:i64) -> i64
+    min_i64_s,
+    min_i64_u,
+    max_i64_s,
+    max_i64_u,
+
+    // () (operand number:i32 min:i32 max:i32) -> i32
+    clamp_i32_s,
+    clamp_i32_u,
+
+    // () (operand number:i64 min:i64 max:i64) -> i64
+    clamp_i64_s,
+    clamp_i64_u,
+
     // Category: Conversion
     // --------------------
 
@@ -1151,6 +1558,103 @@ pub enum Opcode {
     // () (operand number: i64) -> f64
     convert_i64_u_to_f64,
 
+    // Saturating Conversions
+    // ----------------------
+    //
+    // Convert a floating-point number to an integer with saturation (the same as the
+    // WebAssembly "trunc_sat" instructions): the fractional part is truncated, and
+    // the values out of the range of the target integer type are clamped to the
+    // minimum or maximum value of the type, e.g.:
+    //
+    // - `convert_f32_to_i32_s_sat(3.0e10)` -> 0x7fff_ffff
+    // - `convert_f32_to_i32_s_sat(-3.0e10)` -> 0x8000_0000
+    // - `convert_f32_to_i32_u_sat(-1.5)` -> 0
+    //
+    // NaN is converted to 0 (although NaN is not supported by the VM, see the section
+    // "Unsupported Floating-Point Variants").
+    //
+    // Note: These instructions are available since the image format version 1.1.
+
+    // () (operand number:f32) -> i32
+    convert_f32_to_i32_s_sat,
+    convert_f32_to_i32_u_sat,
+
+    // () (operand number:f64) -> i32
+    convert_f64_to_i32_s_sat,
+    convert_f64_to_i32_u_sat,
+
+    // () (operand number:f32) -> i64
+    convert_f32_to_i64_s_sat,
+    convert_f32_to_i64_u_sat,
+
+    // () (operand number:f64) -> i64
+    convert_f64_to_i64_s_sat,
+    convert_f64_to_i64_u_sat,
+
+    // Checked Conversions
+    // -------------------
+    //
+    // Convert a floating-point number to an integer, the fractional part is truncated.
+    // If the truncated value is out of the range of the target integer type, the VM
+    // traps with the reason `TrapReason::ConversionOutOfRange` instead of
+    // truncating or clamping the value, e.g.:
+    //
+    // - `convert_f32_to_i32_s_checked(-2.5)` -> -2
+    // - `convert_f32_to_i32_s_checked(3.0e10)` -> trap
+    // - `convert_f32_to_i32_u_checked(-1.5)` -> trap
+    //
+    // Note: These instructions are available since the image format version 1.1.
+
+    // () (operand number:f32) -> i32
+    convert_f32_to_i32_s_checked,
+    convert_f32_to_i32_u_checked,
+
+    // () (operand number:f64) -> i32
+    convert_f64_to_i32_s_checked,
+    convert_f64_to_i32_u_checked,
+
+    // () (operand number:f32) -> i64
+    convert_f32_to_i64_s_checked,
+    convert_f32_to_i64_u_checked,
+
+    // () (operand number:f64) -> i64
+    convert_f64_to_i64_s_checked,
+    convert_f64_to_i64_u_checked,
+
+    // Half-Precision Conversions
+    // --------------------------
+    //
+    // The VM has no native 16-bit floating-point types, the IEEE 754 half-precision (f16)
+    // and the bfloat16 (bf16) numbers are stored as i16 bit patterns (e.g., in data items),
+    // and converted from/to f32 for calculation.
+    //
+    // ```text
+    //        sign  exponent  fraction
+    // f16    1     5         10
+    // bf16   1     8         7          <-- the high 16 bits of an f32
+    // f32    1     8         23
+    // ```
+    //
+    // Notes:
+    // - When promoting, the high 16 bits of the operand are ignored. If the bit pattern
+    //   is NaN or Infinity, the VM traps with the reason `TrapReason::UnsupportedFloatingPointVariant`.
+    // - When demoting, the number is rounded to the nearest (ties to even), the result
+    //   is zero-extended to i32. If the number is out of the range of the target type,
+    //   the VM traps with the reason `TrapReason::ConversionOutOfRange`.
+    // - These instructions are available since the image format version 1.1.
+
+    // () (operand bits:i32) -> f32
+    promote_f16_to_f32,
+
+    // () (operand number:f32) -> bits:i32
+    demote_f32_to_f16,
+
+    // () (operand bits:i32) -> f32
+    promote_bf16_to_f32,
+
+    // () (operand number:f32) -> bits:i32
+    demote_f32_to_bf16,
+
     // Category: Comparison
     // --------------------
 
@@ -1239,6 +1743,18 @@ pub enum Opcode {
     le_f64, // Checks if the left f64 value is less than or equal to the right. () (operand left: f64, right: f64) -> i64
     ge_f64, // Checks if the left f64 value is greater than or equal to the right. () (operand left: f64, right: f64) -> i64
 
+    // Three-way comparison, returns -1 if `left < right`, 0 if `left == right`, and 1 if `left > right`.
+    // The result can be consumed directly by sorting routines and the `Ordering` type of the language.
+    //
+    // Note: -0.0 and +0.0 are equal.
+    // Note: These instructions are available since the image format version 1.1.
+    cmp_i32_s, // () (operand left: i32, right: i32) -> i32
+    cmp_i32_u, // () (operand left: i32, right: i32) -> i32
+    cmp_i64_s, // () (operand left: i64, right: i64) -> i32
+    cmp_i64_u, // () (operand left: i64, right: i64) -> i32
+    cmp_f32,   // () (operand left: f32, right: f32) -> i32
+    cmp_f64,   // () (operand left: f64, right: f64) -> i32
+
     // Category: Control flow
     // ----------------------
 
@@ -1441,9 +1957,9 @@ pub enum Opcode {
     // (param type_index:i32 local_variable_list_index:i32 next_inst_offset:i32) -> NO_RETURN
     block_alt,
 
-    // The "break_alt" instruction is used to exit the current "block_alt" scope.
+    // The "break_alt" instruction is used to exit the current "block_alt" (or "block_try") scope.
     //
-    // It can only exist within the scope of a "block_alt" instruction.
+    // It can only exist within the scope of a "block_alt" (or "block_try") instruction.
     // It is equivalent to the instruction `break 0, next_inst_offset`.
     //
     // (param next_inst_offset:i32) -> NO_RETURN
@@ -1481,6 +1997,151 @@ pub enum Opcode {
     // (param local_variable_list_index:i32 next_inst_offset:i32) NO_RETURN
     block_nez,
 
+    // Structured Exception Handling
+    // -----------------------------
+    //
+    // The "block_try" instruction creates a block scope (the same as the "block" instruction)
+    // with a list of catch clauses. When an exception is thrown (by the "throw" instruction)
+    // within the scope (including the functions called within the scope), the VM removes
+    // the stack frames above the "block_try" frame, clears its operands, and
+    // checks the catch clauses in order:
+    //
+    // - "catch" matches the exceptions with the specified tag,
+    // - "catch_all" matches all exceptions, it must be the last clause.
+    //
+    // The payload of the exception (i.e., the operands specified by the type of the tag)
+    // is pushed onto the operand stack, and the execution continues at the instruction
+    // following the matched clause. If no clause matches, the exception is propagated to
+    // the outer "block_try". If the exception is not caught by any "block_try" of the thread,
+    // the VM traps with the reason `TrapReason::UncaughtException`.
+    //
+    // The try body and each clause body (except the last one) should end with the "break_alt"
+    // instruction to exit the scope, e.g.:
+    //
+    // ```bytecode
+    // 0d0000 block_try(0,0,28)  ;; -----------\ (28 = 28 - 0)
+    // 0d0016   ...              ;; try body   |
+    // 0d0020   break_alt(36)    ;; -----------|--------------\ (36 = 56 - 20)
+    // 0d0028   catch(3,24)      ;; <----------/ -------\     | (24 = 52 - 28)
+    // 0d0040   ...              ;; handle tag 3        |     |
+    // 0d0044   break_alt(12)    ;; -----------------------\  | (12 = 56 - 44)
+    // 0d0052   catch_all        ;; <-------------------/  |  |
+    // 0d0054 end                ;;                        |  |
+    // 0d0056 ...                ;; <----------------------/--/
+    // ```
+    //
+    // Exception tags are defined in the "exception tag section" (see `ExceptionTag`), the
+    // `tag_index` is a unified index of the imported and internal tags (the same as the
+    // `function_public_index`).
+    //
+    // Note: These instructions are available since the image format version 1.1.
+
+    // The parameter `next_inst_offset` is the offset to the first catch clause.
+    //
+    // (param type_index:i32 local_variable_list_index:i32 next_inst_offset:i32) -> NO_RETURN
+    block_try,
+
+    // The parameter `next_inst_offset` is the offset to the next catch clause,
+    // 0 if this is the last clause.
+    //
+    // (param tag_index:i32 next_inst_offset:i32) -> (payload...)
+    catch,
+
+    // () -> ()
+    catch_all,
+
+    // Throws an exception with the specified tag.
+    //
+    // (param tag_index:i32) (operand payload...) -> NEVER_RETURN
+    throw,
+
+    // Rethrows the exception which is being caught, it can only be used within the catch clauses.
+    //
+    // () -> NEVER_RETURN
+    rethrow,
+
+    // The "break_table" instruction is used to construct "match/switch" control flow structures
+    // with dense case values, which dispatches in O(1) instead of a chain of comparisons.
+    //
+    // This instruction is followed by a table of (case_count + 1) "break" instructions, the last one
+    // is the default case. The VM pops the operand `case_index` and jumps to the `case_index`-th
+    // "break" instruction of the table (or the default one if `case_index` is out of range, note that
+    // `case_index` is treated as an unsigned number), which then exits the blocks.
+    //
+    // Since the "break_table" and "break" instructions are all 8 bytes and aligned to 4 bytes,
+    // the address of the `case_index`-th "break" instruction is
+    // `address_of_break_table + 8 + case_index * 8`.
+    //
+    // For example:
+    //
+    // ```bytecode
+    // 0d1000 block(0,0)                  ;; the block for "default"
+    // 0d1012   block(0,0)                ;; the block for "case 1"
+    // 0d1024     block(0,0)              ;; the block for "case 0"
+    // 0d1036       local_load_i32_u(0,0) ;; case_index
+    // 0d1044       break_table(2)        ;;
+    // 0d1052       break(0,26)           ;; case 0  --\      (26 = 1078 - 1052)
+    // 0d1060       break(1,42)           ;; case 1  --|--\   (42 = 1102 - 1060)
+    // 0d1068       break(2,134)          ;; default --|--|--\ (134 = 1202 - 1068)
+    // 0d1076     end                     ;;           |  |  |
+    // 0d1078     ...                     ;; <---------/  |  | the body of "case 0"
+    // 0d1100   end                       ;;              |  |
+    // 0d1102   ...                       ;; <------------/  | the body of "case 1"
+    // 0d1200 end                         ;;                 |
+    // 0d1202 ...                         ;; <---------------/ the body of "default"
+    // ```
+    //
+    // Note: This instruction is available since the image format version 1.1.
+    //
+    // (param case_count:i32) (operand case_index:i32) -> NO_RETURN
+    break_table,
+
+    // The "recur_nez" instruction pops the operand at the top of the operand stack, and performs
+    // the "recur" only if the operand is **not** equal to ZERO (i.e., logical TRUE), otherwise
+    // the execution continues with the next instruction.
+    //
+    // It fuses the common loop tail `block_nez { recur }` into one instruction, e.g.:
+    //
+    // ```bytecode
+    // 0d0000 block(0,0)                ;; the loop
+    // 0d0012   ...                     ;; <----------\ the loop body
+    // 0d0100   local_load_i32_u(0,0)   ;;             | the condition
+    // 0d0108   recur_nez(0,96)         ;; -----------/ (96 = 108 - 12)
+    // 0d0116 end
+    // ```
+    //
+    // Note that the condition operand is popped before the operands are carried to the target,
+    // and the parameters are the same as the "recur" instruction.
+    //
+    // Note: This instruction is available since the image format version 1.1.
+    //
+    // (param layers:i16 start_inst_offset:i32) (operand condition:i32) -> NO_RETURN
+    recur_nez,
+
+    // The "break_nez" instruction pops the operand at the top of the operand stack, and performs
+    // the "break" only if the operand is **not** equal to ZERO (i.e., logical TRUE), otherwise
+    // the execution continues with the next instruction.
+    //
+    // It avoids creating an extra block frame for the "break if" pattern inside loops, e.g.:
+    //
+    // ```bytecode
+    // 0d0000 block(0,0)                ;; the loop
+    // 0d0012   local_load_i32_u(0,0)   ;; the condition
+    // 0d0020   break_nez(0,104)        ;; -----------\ (104 = 124 - 20)
+    // 0d0028   ...                     ;;             | the loop body
+    // 0d0114   recur(0,102)            ;;             | (102 = 114 - 12)
+    // 0d0122 end                       ;;             |
+    // 0d0124 ...                       ;; <----------/
+    // ```
+    //
+    // Note that the condition operand is popped before the operands are carried out of the block,
+    // and the parameters are the same as the "break" instruction.
+    //
+    // Note: This instruction is available since the image format version 1.1.
+    //
+    // (param layers:i16 next_inst_offset:i32) (operand condition:i32) -> NO_RETURN
+    break_nez,
+
     // TCO (Tail Call Optimization)
     // ----------------------------
     // The "recur" instruction is also used to implement Tail Call Optimization (TCO).
@@ -1726,11 +2387,15 @@ pub enum Opcode {
     //
     // ```rust
     // struct closure_function_item {
+    //     function_module_index: i32
     //     function_public_index: i32
     //     captured_data_pointer: i64
     // }
     // ```
     //
+    // Since the image format version 1.1, closures can be created and called by
+    // the instructions "closure_create" and "closure_call".
+    //
     // The `captured_data_pointer` points to a dynamically created structure containing the data captured by the function.
     // For example, if an anonymous function captures an `i32` and a string, the captured data structure might look like:
     //
@@ -1783,6 +2448,8 @@ pub enum Opcode {
     // The "envcall" instruction is used to call VM built-in functions, such as retrieving environment variables,
     // obtaining runtime information, manipulating threads, etc.
     //
+    // The call numbers are defined in the module `envcall`.
+    //
     // (param envcall_num:i32) (operand args...) -> (values)
     envcall,
 
@@ -1809,6 +2476,7 @@ pub enum Opcode {
     // the value is negative (i.e., rax < 0).
     //
     // Note: Unlike the C standard library, there is no "errno" when calling syscalls directly from assembly.
+    // The error numbers are defined in the module `errno`.
     //
     // () (operand args... params_count:i32 syscall_num:i32) -> (return_value:i64 error_number:i32)
     syscall,
@@ -1820,9 +2488,95 @@ pub enum Opcode {
     // Note: Both the "syscall" and "extcall" instructions are optional and may not be available in some environments.
     // The supported VM features can be queried using the "envcall" instruction with the call number `runtime_features`.
     //
+    // The types of the parameters and return value of external functions are described by `CType`
+    // (see the module `ctype`).
+    //
     // (param external_function_index:i32) (operand args...) -> return_value:void/i32/i64/f32/f64
     extcall,
 
+    // Indirect Function Call
+    //
+    // The "call_indirect" instruction calls a function through a function table by the element index.
+    // It is a safer and faster dispatch mechanism (e.g., for implementing vtables) than `call_dynamic`.
+    //
+    // Function tables are defined in the "function table section" of the module (see `FunctionTable`),
+    // all functions in a table have the same type (signature), so the type is checked when loading
+    // the module instead of at runtime.
+    //
+    // If the element index is out of the bounds of the table, the VM traps with
+    // the reason `TrapReason::TableElementOutOfBounds`, and if the element is empty,
+    // the VM traps with the reason `TrapReason::NullTableElement`.
+    //
+    // Note: This instruction is available since the image format version 1.1.
+    //
+    // (param table_index:i32) (operand args... element_index:i32) -> (values)
+    call_indirect,
+
+    // Tail Function Call
+    //
+    // The "call_tail" instruction is similar to the "call" instruction, but it replaces the
+    // stack frame of the current function instead of creating a new one, i.e., the current
+    // function returns and the target function is called with the arguments, the results of
+    // the target function are returned to the caller of the current function.
+    //
+    // Unlike the "recur" instruction which only jumps within the current function, this instruction
+    // guarantees the Tail Call Optimization (TCO) across functions, e.g., mutual recursion and
+    // state machines. The results of the target function must be the same as the current function.
+    //
+    // Note: This instruction is available since the image format version 1.1.
+    //
+    // (param function_public_index:i32) (operand args...) -> NO_RETURN
+    call_tail,
+
+    // The tail call version of "call_dynamic".
+    //
+    // Note: This instruction is available since the image format version 1.1.
+    //
+    // () (operand args... function_module_index:i32 function_public_index:i32) -> NO_RETURN
+    call_dynamic_tail,
+
+    // Closures
+    // --------
+    //
+    // The instructions "closure_create" and "closure_call" formalize the `closure_function_item`
+    // convention (see the instruction "call_dynamic"), so closures are constructed and invoked
+    // uniformly across compilers.
+    //
+    // A closure is represented by three operands on the operand stack:
+    //
+    // ```text
+    // | captured_data_pointer:i64 | <-- top of stack
+    // | function_public_index:i32 |
+    // | function_module_index:i32 |
+    // ```
+    //
+    // and has the following layout (16 bytes, aligned to 8 bytes) when it is stored in memory:
+    //
+    // ```rust
+    // struct closure_function_item {
+    //     function_module_index: i32
+    //     function_public_index: i32
+    //     captured_data_pointer: i64
+    // }
+    // ```
+    //
+    // The target function must append an additional parameter `captured_data_pointer:i64`
+    // to its parameter list, e.g., `fn (a: i32, b: i32, captured_data_pointer: i64)`.
+    // The `captured_data_pointer` is 0 if the closure does not capture any data.
+    //
+    // Note: These instructions are available since the image format version 1.1.
+
+    // Creates a closure with a function of the current module.
+    //
+    // (param function_public_index:i32) (operand captured_data_pointer:i64) -> (function_module_index:i32 function_public_index:i32 captured_data_pointer:i64)
+    closure_create,
+
+    // Calls a closure, the `captured_data_pointer` is passed to the target function
+    // as the last argument.
+    //
+    // () (operand args... function_module_index:i32 function_public_index:i32 captured_data_pointer:i64) -> (values)
+    closure_call,
+
     // Category: Memory
     // -----------------
 
@@ -1859,7 +2613,8 @@ pub enum Opcode {
 
     // Copy a memory chunk from one location to another.
     //
-    // Note: The source and destination memory chunks must not overlap.
+    // Note: The source and destination memory chunks must not overlap,
+    // use the instruction `memory_move` instead if they may overlap.
     //
     // () (operand
     //     source_data_module_index:i32
@@ -1871,6 +2626,118 @@ pub enum Opcode {
     //     size_in_bytes:i64) -> ()
     memory_copy,
 
+    // Futex-style wait.
+    //
+    // Blocks the current thread until it is woken by the instruction `memory_notify`, or
+    // the timeout expires. If the current value of the data is not equal to `expected`,
+    // returns immediately.
+    //
+    // The data is addressed like the atomic instructions (see the category "Atomic"),
+    // and must be aligned to the size of the data type.
+    //
+    // The parameter `timeout_nanoseconds` is the maximum time to wait, a negative number
+    // means waiting forever.
+    //
+    // The result is:
+    // - 0: woken by `memory_notify`.
+    // - 1: the value is not equal to `expected`.
+    // - 2: timed out.
+    //
+    // Note: This instruction is available since the image format version 1.1.
+    //
+    // () (operand
+    //     module_index:i32
+    //     data_public_index:i32
+    //     offset_bytes:i64
+    //     expected:i32
+    //     timeout_nanoseconds:i64) -> result:i32
+    memory_wait_i32,
+
+    // () (operand
+    //     module_index:i32
+    //     data_public_index:i32
+    //     offset_bytes:i64
+    //     expected:i64
+    //     timeout_nanoseconds:i64) -> result:i32
+    memory_wait_i64,
+
+    // Wakes up at most `count` threads which are waiting on the specified data
+    // (by `memory_wait_i32` or `memory_wait_i64`), and returns the number of woken threads.
+    //
+    // Note: This instruction is available since the image format version 1.1.
+    //
+    // () (operand module_index:i32 data_public_index:i32 offset_bytes:i64 count:i32) -> woken_count:i32
+    memory_notify,
+
+    // Allocate a new memory chunk filled with zeros and return a data public index.
+    //
+    // It is equivalent to the instruction `memory_allocate` followed by `memory_fill` with 0,
+    // but the runtime can take advantage of zeroed pages (e.g., `calloc`) to avoid the filling.
+    //
+    // Note: This instruction is available since the image format version 1.1.
+    //
+    // () (operand size_in_bytes:i64 alignment_in_bytes:i16) -> i32
+    memory_allocate_zeroed,
+
+    // Changes the protection of an allocated memory chunk.
+    //
+    // The value of `protection` is one of the `MemoryProtection`:
+    // - 0: read-only
+    // - 1: read-write
+    //
+    // Writing to a read-only memory chunk raises a trap with the reason
+    // `TrapReason::MemoryProtectionViolation`.
+    //
+    // Note: This instruction is available since the image format version 1.1.
+    //
+    // () (operand data_public_index:i32 protection:i32) -> ()
+    memory_protect,
+
+    // Allocate a new memory chunk with the specified flags and return a data public index.
+    //
+    // The `flags` is a combination of the `MemoryAllocationFlags`:
+    // - 0x1: readable
+    // - 0x2: writable
+    // - 0x4: shareable across threads
+    //
+    // The instruction `memory_allocate` is equivalent to this instruction with
+    // the flags `readable | writable`.
+    //
+    // Note: This instruction is available since the image format version 1.1.
+    //
+    // () (operand size_in_bytes:i64 alignment_in_bytes:i16 flags:i32) -> i32
+    memory_allocate_with_flags,
+
+    // Copy a memory chunk from one location to another, the source and destination
+    // memory chunks may overlap (i.e., the "memmove" semantics).
+    //
+    // Note: This instruction is available since the image format version 1.1.
+    //
+    // () (operand
+    //     source_data_module_index:i32
+    //     source_data_public_index:i32
+    //     source_offset_in_bytes:i64
+    //     dest_data_module_index:i32
+    //     dest_data_public_index:i32
+    //     dest_offset_in_bytes:i64
+    //     size_in_bytes:i64) -> ()
+    memory_move,
+
+    // Searches a memory chunk for the first occurrence of a byte value (i.e., the "memchr" semantics).
+    //
+    // Returns the offset of the found byte relative to `offset_in_bytes`,
+    // or -1 if the value is not found within `size_in_bytes` bytes.
+    //
+    // Note: This instruction is available since the image format version 1.1.
+    //
+    // () (operand
+    //     data_module_index:i32
+    //     data_public_index:i32
+    //     offset_in_bytes:i64
+    //     size_in_bytes:i64
+    //     value:i8) -> i64
+    memory_find,
+
     // Category: Machine
     // ------------------
 
@@ -1926,9 +2793,835 @@ pub enum Opcode {
     host_addr_data,        // (param offset_bytes:i16 data_public_index:i32) -> pointer
     host_addr_data_extend, // (param data_public_index:i32) (operand offset_bytes:i64) -> pointer
     host_addr_data_dynamic, // () (operand module_index:i32 data_public_index:i32 offset_bytes:i64) -> pointer
+
+    // Raises a panic with a message.
+    //
+    // The message is a UTF-8 string stored in the specified data item (usually a read-only data).
+    // Unlike the instruction `terminate`, which exits the whole process immediately, the panic
+    // is turned into a trap with the reason `TrapReason::Panic` of the current thread. The runtime
+    // attaches the message and the location (i.e., the module index, function public index and
+    // instruction address) to the trap, so the host or the parent thread can catch it and report it.
+    //
+    // Note: This instruction is available since the image format version 1.1.
+    //
+    // (param data_public_index:i32) -> NERVER_RETURN
+    panic,
+
+    // A breakpoint.
+    //
+    // A debugger-enabled runtime pauses the current thread and hands the control over to
+    // the debugger when this instruction is executed, while a normal runtime treats it as
+    // the instruction `nop`.
+    //
+    // Note: This instruction is available since the image format version 1.1.
+    //
+    // () -> ()
+    debug_break,
+
+    // A profiling probe.
+    //
+    // It is the same as the instruction `nop` in normal execution, while an instrumented
+    // runtime records the counter and timestamp keyed by the `probe_id` when this instruction
+    // is executed. The `probe_id` is assigned by the compiler.
+    //
+    // Note: This instruction is available since the image format version 1.1.
+    //
+    // (param probe_id:i32) -> ()
+    probe,
+
+    // Stack introspection
+    // -------------------
+    //
+    // The following instructions query the call stack of the current thread, they are
+    // used to build backtraces for panics and error types, e.g.:
+    //
+    // ```bytecode
+    // get_call_depth            ;; depth
+    // ;; for level in 0..depth
+    // local_load_i32_s(0, 1)    ;; level (the local variable 1)
+    // get_call_frame            ;; (module_index, function_public_index, instruction_address)
+    // ;; save the frame info
+    // ```
+    //
+    // Notes:
+    // - Only function frames are counted, block frames are excluded.
+    // - These instructions are available since the image format version 1.1.
+
+    // Gets the number of function frames of the current thread, including the current function.
+    //
+    // () -> depth:i32
+    get_call_depth,
+
+    // Gets the information of the specified function frame.
+    //
+    // The `level` 0 is the current function, 1 is the caller, and so on.
+    // The `instruction_address` is the address of the calling instruction
+    // (or the current instruction if the `level` is 0).
+    //
+    // If the `level` is greater than or equal to the call depth, the VM throws an exception.
+    //
+    // () (operand level:i32) -> (module_index:i32 function_public_index:i32 instruction_address:i32)
+    get_call_frame,
+
+    // Host bulk-copy
+    // --------------
+    //
+    // Copies `length_in_bytes` bytes between a host-side memory address (e.g., a buffer
+    // returned by an external function) and a VM data item, without exposing the host-side
+    // address of the VM data (see the instruction `host_addr_data`).
+    //
+    // The VM data is accessed with bounds checking, while the host-side memory is not
+    // checked, so these instructions are still unsafe from the perspective of the host.
+    //
+    // Note: These instructions are available since the image format version 1.1.
+
+    // Copies bytes from the host memory to the VM data.
+    //
+    // () (operand
+    //     source_pointer:i64
+    //     dest_data_module_index:i32
+    //     dest_data_public_index:i32
+    //     dest_offset_in_bytes:i64
+    //     length_in_bytes:i64) -> ()
+    host_copy_to_data,
+
+    // Copies bytes from the VM data to the host memory.
+    //
+    // () (operand
+    //     source_data_module_index:i32
+    //     source_data_public_index:i32
+    //     source_offset_in_bytes:i64
+    //     dest_pointer:i64
+    //     length_in_bytes:i64) -> ()
+    host_copy_from_data,
+
+    // Reflection
+    // ----------
+    //
+    // Finds a function or a data item by its full name, e.g. "network::http::get".
+    // The full name is a UTF-8 string (without the terminating zero) stored in a data item,
+    // and it always uses the actual name of the module (see `SELF_REFERENCE_MODULE_NAME`).
+    //
+    // Only the public (i.e., exported) functions and data items can be found. The result `found`
+    // is 1 if the item is found, otherwise it is 0 and the `module_index` and `public_index`
+    // are undefined.
+    //
+    // The results `module_index` and `public_index` are the same as the results of
+    // the instructions `get_function` and `get_data`, so they can be used by the
+    // "dynamic" instructions, e.g. `call_dynamic` and `data_load_dynamic_i32_s`.
+    //
+    // Note: These instructions are available since the image format version 1.1.
+
+    // () (operand
+    //     name_data_module_index:i32
+    //     name_data_public_index:i32
+    //     name_offset_in_bytes:i64
+    //     name_length_in_bytes:i64) -> (function_module_index:i32 function_public_index:i32 found:i32)
+    find_function_by_name,
+
+    // () (operand
+    //     name_data_module_index:i32
+    //     name_data_public_index:i32
+    //     name_offset_in_bytes:i64
+    //     name_length_in_bytes:i64) -> (data_module_index:i32 data_public_index:i32 found:i32)
+    find_data_by_name,
+
+    // Category: Atomic
+    // -----------------
+
+    // Atomic instructions access i32 and i64 data atomically, they are used by multi-threaded
+    // programs to implement locks and lock-free data structures.
+    //
+    // All atomic instructions access the data by the "dynamic" addressing, i.e., the module index,
+    // data public index and the offset are operands, so they can be used for both data items
+    // (see instruction `get_data`) and allocated memory (the module index is always 0).
+    //
+    // Notes:
+    // - All atomic operations are sequentially consistent (i.e., `SeqCst`).
+    // - The address of the data must be aligned to the size of the data type (4 bytes for i32
+    //   and 8 bytes for i64), otherwise the VM will throw an exception.
+    // - The "read-modify-write" (rmw) instructions push the OLD value onto the operand stack.
+    // - Atomic instructions are available since the image format version 1.1.
+
+    // () (operand module_index:i32 data_public_index:i32 offset_bytes:i64) -> i32
+    atomic_load_i32 = 0x0D_00,
+
+    // () (operand module_index:i32 data_public_index:i32 offset_bytes:i64) -> i64
+    atomic_load_i64,
+
+    // () (operand module_index:i32 data_public_index:i32 offset_bytes:i64 value:i32) -> ()
+    atomic_store_i32,
+
+    // () (operand module_index:i32 data_public_index:i32 offset_bytes:i64 value:i64) -> ()
+    atomic_store_i64,
+
+    // Wrapping addition, pushes the old value.
+    //
+    // () (operand module_index:i32 data_public_index:i32 offset_bytes:i64 value:i32) -> old_value:i32
+    atomic_rmw_add_i32,
+
+    // () (operand module_index:i32 data_public_index:i32 offset_bytes:i64 value:i64) -> old_value:i64
+    atomic_rmw_add_i64,
+
+    // Wrapping subtraction, pushes the old value.
+    //
+    // () (operand module_index:i32 data_public_index:i32 offset_bytes:i64 value:i32) -> old_value:i32
+    atomic_rmw_sub_i32,
+
+    // () (operand module_index:i32 data_public_index:i32 offset_bytes:i64 value:i64) -> old_value:i64
+    atomic_rmw_sub_i64,
+
+    // Bitwise AND, pushes the old value.
+    //
+    // () (operand module_index:i32 data_public_index:i32 offset_bytes:i64 value:i32) -> old_value:i32
+    atomic_rmw_and_i32,
+
+    // () (operand module_index:i32 data_public_index:i32 offset_bytes:i64 value:i64) -> old_value:i64
+    atomic_rmw_and_i64,
+
+    // Bitwise OR, pushes the old value.
+    //
+    // () (operand module_index:i32 data_public_index:i32 offset_bytes:i64 value:i32) -> old_value:i32
+    atomic_rmw_or_i32,
+
+    // () (operand module_index:i32 data_public_index:i32 offset_bytes:i64 value:i64) -> old_value:i64
+    atomic_rmw_or_i64,
+
+    // Bitwise XOR, pushes the old value.
+    //
+    // () (operand module_index:i32 data_public_index:i32 offset_bytes:i64 value:i32) -> old_value:i32
+    atomic_rmw_xor_i32,
+
+    // () (operand module_index:i32 data_public_index:i32 offset_bytes:i64 value:i64) -> old_value:i64
+    atomic_rmw_xor_i64,
+
+    // Replaces the value, pushes the old value.
+    //
+    // () (operand module_index:i32 data_public_index:i32 offset_bytes:i64 value:i32) -> old_value:i32
+    atomic_rmw_exchange_i32,
+
+    // () (operand module_index:i32 data_public_index:i32 offset_bytes:i64 value:i64) -> old_value:i64
+    atomic_rmw_exchange_i64,
+
+    // Compare-and-swap.
+    //
+    // Replaces the value with `replacement` only if the current value equals `expected`,
+    // and pushes the old value. The operation succeeds if the old value equals `expected`.
+    //
+    // () (operand module_index:i32 data_public_index:i32 offset_bytes:i64 expected:i32 replacement:i32) -> old_value:i32
+    atomic_cas_i32,
+
+    // () (operand module_index:i32 data_public_index:i32 offset_bytes:i64 expected:i64 replacement:i64) -> old_value:i64
+    atomic_cas_i64,
+
+    // Category: I128
+    // ---------------
+
+    // 128-bit integers are represented as operand pairs, i.e., two i64 operands,
+    // the low 64 bits first, then the high 64 bits (on the top of the stack):
+    //
+    // ```text
+    // | high:i64 | <-- top of stack
+    // | low:i64  |
+    // | ...      |
+    // ```
+    //
+    // Notes:
+    // - The results of the arithmetic instructions are also operand pairs.
+    // - The results of the comparison instructions are booleans (i.e., 0 or 1, type i64).
+    // - Division and remainder by zero throw an exception, the same as the i64 instructions.
+    // - Instructions of this category are available since the image format version 1.1.
+
+    // Wrapping addition
+    //
+    // () (operand left_low:i64 left_high:i64 right_low:i64 right_high:i64) -> (low:i64 high:i64)
+    add_i128 = 0x0E_00,
+
+    // Wrapping subtraction
+    //
+    // () (operand left_low:i64 left_high:i64 right_low:i64 right_high:i64) -> (low:i64 high:i64)
+    sub_i128,
+
+    // Wrapping multiplication
+    //
+    // () (operand left_low:i64 left_high:i64 right_low:i64 right_high:i64) -> (low:i64 high:i64)
+    mul_i128,
+
+    // () (operand left_low:i64 left_high:i64 right_low:i64 right_high:i64) -> (low:i64 high:i64)
+    div_i128_s,
+    div_i128_u,
+    rem_i128_s,
+    rem_i128_u,
+
+    // () (operand left_low:i64 left_high:i64 right_low:i64 right_high:i64) -> i64
+    eq_i128,
+    ne_i128,
+    lt_i128_s,
+    lt_i128_u,
+    gt_i128_s,
+    gt_i128_u,
+    le_i128_s,
+    le_i128_u,
+    ge_i128_s,
+    ge_i128_u,
+
+    // () (operand number_low:i64 number_high:i64 move_bits:i32) -> (low:i64 high:i64), move_bits = [0, 128)
+    shift_left_i128,
+    shift_right_i128_s,
+    shift_right_i128_u,
+
+    // Category: Checksum
+    // -------------------
+
+    // Checksum instructions compute the checksum of a memory region, the region is addressed
+    // like the instruction `memory_fill`.
+    //
+    // The parameter `initial` is the checksum of the previous regions (0 for the first region),
+    // so that the checksum of discontinuous data (e.g., network packets) can be computed incrementally.
+    //
+    // Notes:
+    // - This is an optional category (see `OpcodeCategory::is_optional`), a runtime may not support it.
+    //   Compilers should detect the support of the runtime before emitting these instructions.
+    // - Instructions of this category are available since the image format version 1.1.
+
+    // CRC-32 (IEEE 802.3, the polynomial 0x04C11DB7), the same as the one used by zlib and PNG.
+    //
+    // () (operand
+    //     data_module_index:i32
+    //     data_public_index:i32
+    //     offset_in_bytes:i64
+    //     size_in_bytes:i64
+    //     initial:i32) -> i32
+    crc32 = 0x0F_00,
+
+    // CRC-32C (Castagnoli, the polynomial 0x1EDC6F41), the same as the one used by iSCSI and ext4.
+    //
+    // () (operand
+    //     data_module_index:i32
+    //     data_public_index:i32
+    //     offset_in_bytes:i64
+    //     size_in_bytes:i64
+    //     initial:i32) -> i32
+    crc32c,
+
+    // Category: Crypto
+    // -----------------
+
+    // Cryptographic instructions mirror the hardware crypto extensions (e.g., AES-NI and SHA-NI
+    // on x86_64, and the Cryptographic Extension on AArch64), they operate on memory regions in place,
+    // the regions are addressed like the instruction `memory_fill`.
+    //
+    // Notes:
+    // - This is an optional category (see `OpcodeCategory::is_optional`), a runtime may not support it.
+    //   The support can be queried using the "envcall" instruction with the call number `runtime_features`.
+    // - Instructions of this category are available since the image format version 1.1.
+
+    // AES Rounds
+    // ----------
+    //
+    // Performs one round of AES on the 16-byte state with the 16-byte round key,
+    // the result is written back to the state.
+    //
+    // - `aes_encrypt_round`: ShiftRows, SubBytes, MixColumns and AddRoundKey (the same as `AESENC`).
+    // - `aes_encrypt_last_round`: ShiftRows, SubBytes and AddRoundKey (the same as `AESENCLAST`).
+    // - `aes_decrypt_round`: InvShiftRows, InvSubBytes, InvMixColumns and AddRoundKey (the same as `AESDEC`).
+    // - `aes_decrypt_last_round`: InvShiftRows, InvSubBytes and AddRoundKey (the same as `AESDECLAST`).
+
+    // () (operand
+    //     state_module_index:i32
+    //     state_data_public_index:i32
+    //     state_offset_in_bytes:i64
+    //     round_key_module_index:i32
+    //     round_key_data_public_index:i32
+    //     round_key_offset_in_bytes:i64) -> ()
+    aes_encrypt_round = 0x10_00,
+
+    // () (operand
+    //     state_module_index:i32
+    //     state_data_public_index:i32
+    //     state_offset_in_bytes:i64
+    //     round_key_module_index:i32
+    //     round_key_data_public_index:i32
+    //     round_key_offset_in_bytes:i64) -> ()
+    aes_encrypt_last_round,
+
+    // () (operand
+    //     state_module_index:i32
+    //     state_data_public_index:i32
+    //     state_offset_in_bytes:i64
+    //     round_key_module_index:i32
+    //     round_key_data_public_index:i32
+    //     round_key_offset_in_bytes:i64) -> ()
+    aes_decrypt_round,
+
+    // () (operand
+    //     state_module_index:i32
+    //     state_data_public_index:i32
+    //     state_offset_in_bytes:i64
+    //     round_key_module_index:i32
+    //     round_key_data_public_index:i32
+    //     round_key_offset_in_bytes:i64) -> ()
+    aes_decrypt_last_round,
+
+    // SHA-256 compression function.
+    //
+    // Updates the 32-byte state (i.e., the eight 32-bit working variables `a..h` in big-endian)
+    // with the 64-byte message block. The message padding is done by the caller.
+    //
+    // () (operand
+    //     state_module_index:i32
+    //     state_data_public_index:i32
+    //     state_offset_in_bytes:i64
+    //     block_module_index:i32
+    //     block_data_public_index:i32
+    //     block_offset_in_bytes:i64) -> ()
+    sha256_compress,
+}
+
+/// The category of opcodes, i.e., the high 8 bits of the opcode.
+#[repr(u8)]
+#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Clone, Copy)]
+pub enum OpcodeCategory {
+    Fundamental = 0x01,
+    LocalVariable,
+    Data,
+    Arithmetic,
+    Bitwise,
+    Math,
+    Conversion,
+    Comparison,
+    ControlFlow,
+    FunctionCall,
+    Memory,
+    Machine,
+    Atomic,
+    I128,
+    Checksum,
+    Crypto,
+}
+
+/// The parameter layout of instructions.
+///
+/// See the section "Instruction Encoding" at the top of this module.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum InstructionFormat {
+    NoParams,  // 16-bit: [opcode 16-bit]
+    I16,       // 32-bit: [opcode 16-bit] - [param i16]
+    I32,       // 64-bit: [opcode 16-bit] - [pading 16-bit] + [param i32]
+    I16I32,    // 64-bit: [opcode 16-bit] - [param i16] + [param i32]
+    I32I32,    // 96-bit: [opcode 16-bit] - [pading 16-bit] + [param i32] + [param i32]
+    I32I32I32, // 128-bit: [opcode 16-bit] - [pading 16-bit] + [param i32] + [param i32] + [param i32]
+}
+
+impl InstructionFormat {
+    pub fn get_length_in_bytes(&self) -> usize {
+        match self {
+            InstructionFormat::NoParams => 2,
+            InstructionFormat::I16 => 4,
+            InstructionFormat::I32 => 8,
+            InstructionFormat::I16I32 => 8,
+            InstructionFormat::I32I32 => 12,
+            InstructionFormat::I32I32I32 => 16,
+        }
+    }
+
+    /// Instructions that contain i32 parameters must be aligned to 4 bytes.
+    pub fn requires_alignment(&self) -> bool {
+        !matches!(self, InstructionFormat::NoParams | InstructionFormat::I16)
+    }
+}
+
+/// The image format version and runtime edition in which an opcode was introduced.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct OpcodeSince {
+    pub image_format_major_version: u16,
+    pub image_format_minor_version: u16,
+    pub edition: &'static str,
+}
+
+impl OpcodeSince {
+    pub fn get_image_format_version(&self) -> ImageFormatVersion {
+        ImageFormatVersion::new(
+            self.image_format_major_version,
+            self.image_format_minor_version,
+        )
+    }
+
+    /// Checks whether a runtime that supports the specified edition and
+    /// image format version can execute the opcode.
+    ///
+    /// Editions are not comparable, so they must be equal. The image format
+    /// version of the target must be greater than or equal to the
+    /// version in which the opcode was introduced.
+    pub fn is_supported_by(
+        &self,
+        edition: &str,
+        image_format_major_version: u16,
+        image_format_minor_version: u16,
+    ) -> bool {
+        let target =
+            ImageFormatVersion::new(image_format_major_version, image_format_minor_version);
+        self.edition == edition && target.supports(&self.get_image_format_version())
+    }
+}
+
+impl OpcodeCategory {
+    /// Optional categories are not required to be supported by all runtimes,
+    /// they usually mirror the hardware extensions of the host.
+    pub fn is_optional(&self) -> bool {
+        matches!(self, OpcodeCategory::Checksum | OpcodeCategory::Crypto)
+    }
+}
+
+impl Display for OpcodeCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            OpcodeCategory::Fundamental => "fundamental",
+            OpcodeCategory::LocalVariable => "local_variable",
+            OpcodeCategory::Data => "data",
+            OpcodeCategory::Arithmetic => "arithmetic",
+            OpcodeCategory::Bitwise => "bitwise",
+            OpcodeCategory::Math => "math",
+            OpcodeCategory::Conversion => "conversion",
+            OpcodeCategory::Comparison => "comparison",
+            OpcodeCategory::ControlFlow => "control_flow",
+            OpcodeCategory::FunctionCall => "function_call",
+            OpcodeCategory::Memory => "memory",
+            OpcodeCategory::Machine => "machine",
+            OpcodeCategory::Atomic => "atomic",
+            OpcodeCategory::I128 => "i128",
+            OpcodeCategory::Checksum => "checksum",
+            OpcodeCategory::Crypto => "crypto",
+        };
+        f.write_str(name)
+    }
 }
 
 impl Opcode {
+    /// Converts a 16-bit number into an opcode.
+    ///
+    /// Returns `None` if the number is not a valid opcode.
+    pub fn from_u16(value: u16) -> Option<Self> {
+        // The last opcode of each category.
+        let last = match value >> 8 {
+            0x01 => Opcode::push_zero,
+            0x02 => Opcode::local_store_f32,
+            0x03 => Opcode::data_store_dynamic_f32,
+            0x04 => Opcode::div_rem_i64_u,
+            0x05 => Opcode::rotate_right_imm_i64,
+            0x06 => Opcode::clamp_i64_u,
+            0x07 => Opcode::demote_f32_to_bf16,
+            0x08 => Opcode::cmp_f64,
+            0x09 => Opcode::break_nez,
+            0x0A => Opcode::closure_call,
+            0x0B => Opcode::memory_find,
+            0x0C => Opcode::find_data_by_name,
+            0x0D => Opcode::atomic_cas_i64,
+            0x0E => Opcode::shift_right_i128_u,
+            0x0F => Opcode::crc32c,
+            0x10 => Opcode::sha256_compress,
+            _ => return None,
+        };
+
+        if value <= last as u16 {
+            // SAFETY: the items of each category are continuous numbers starting from 0x00,
+            // and the value has been checked to be in the range of the category.
+            Some(unsafe { std::mem::transmute::<u16, Opcode>(value) })
+        } else {
+            None
+        }
+    }
+
+    pub fn get_category(&self) -> OpcodeCategory {
+        // SAFETY: the high 8 bits of all opcodes are valid categories.
+        unsafe { std::mem::transmute::<u8, OpcodeCategory>((*self as u16 >> 8) as u8) }
+    }
+
+    pub fn get_format(&self) -> InstructionFormat {
+        match self {
+            // Category: Fundamental
+            Opcode::imm_i32 | Opcode::imm_f32 => InstructionFormat::I32,
+            Opcode::imm_i64 | Opcode::imm_f64 => InstructionFormat::I32I32,
+            Opcode::imm_i32_small => InstructionFormat::I16,
+            // Category: Local Variables
+            Opcode::local_load_i64
+            | Opcode::local_load_i32_s
+            | Opcode::local_load_i32_u
+            | Opcode::local_load_i16_s
+            | Opcode::local_load_i16_u
+            | Opcode::local_load_i8_s
+            | Opcode::local_load_i8_u
+            | Opcode::local_load_f64
+            | Opcode::local_load_f32
+            | Opcode::local_store_i64
+            | Opcode::local_store_i32
+            | Opcode::local_store_i16
+            | Opcode::local_store_i8
+            | Opcode::local_store_f64
+            | Opcode::local_store_f32 => InstructionFormat::I16I32,
+            // Category: Data
+            Opcode::data_load_i64
+            | Opcode::data_load_i32_s
+            | Opcode::data_load_i32_u
+            | Opcode::data_load_i16_s
+            | Opcode::data_load_i16_u
+            | Opcode::data_load_i8_s
+            | Opcode::data_load_i8_u
+            | Opcode::data_load_f64
+            | Opcode::data_load_f32
+            | Opcode::data_store_i64
+            | Opcode::data_store_i32
+            | Opcode::data_store_i16
+            | Opcode::data_store_i8
+            | Opcode::data_store_f64
+            | Opcode::data_store_f32 => InstructionFormat::I16I32,
+            Opcode::data_load_extend_i64
+            | Opcode::data_load_extend_i32_s
+            | Opcode::data_load_extend_i32_u
+            | Opcode::data_load_extend_i16_s
+            | Opcode::data_load_extend_i16_u
+            | Opcode::data_load_extend_i8_s
+            | Opcode::data_load_extend_i8_u
+            | Opcode::data_load_extend_f64
+            | Opcode::data_load_extend_f32
+            | Opcode::data_store_extend_i64
+            | Opcode::data_store_extend_i32
+            | Opcode::data_store_extend_i16
+            | Opcode::data_store_extend_i8
+            | Opcode::data_store_extend_f64
+            | Opcode::data_store_extend_f32 => InstructionFormat::I32,
+            // Category: Arithmetic
+            Opcode::add_imm_i32
+            | Opcode::sub_imm_i32
+            | Opcode::add_imm_i64
+            | Opcode::sub_imm_i64 => InstructionFormat::I16,
+            // Category: Bitwise
+            Opcode::shift_left_imm_i32
+            | Opcode::shift_right_imm_i32_s
+            | Opcode::shift_right_imm_i32_u
+            | Opcode::rotate_left_imm_i32
+            | Opcode::rotate_right_imm_i32
+            | Opcode::shift_left_imm_i64
+            | Opcode::shift_right_imm_i64_s
+            | Opcode::shift_right_imm_i64_u
+            | Opcode::rotate_left_imm_i64
+            | Opcode::rotate_right_imm_i64 => InstructionFormat::I16,
+            Opcode::extract_bits_i32
+            | Opcode::extract_bits_i64
+            | Opcode::insert_bits_i32
+            | Opcode::insert_bits_i64 => InstructionFormat::I16I32,
+            // Category: Control flow
+            Opcode::block | Opcode::block_nez => InstructionFormat::I32I32,
+            Opcode::break_ | Opcode::recur | Opcode::recur_nez | Opcode::break_nez => {
+                InstructionFormat::I16I32
+            }
+            Opcode::block_alt | Opcode::block_try => InstructionFormat::I32I32I32,
+            Opcode::break_alt | Opcode::throw | Opcode::break_table => InstructionFormat::I32,
+            Opcode::catch => InstructionFormat::I32I32,
+            // Category: Function Call
+            Opcode::call
+            | Opcode::envcall
+            | Opcode::extcall
+            | Opcode::call_indirect
+            | Opcode::call_tail
+            | Opcode::closure_create => InstructionFormat::I32,
+            // Category: Machine
+            Opcode::terminate
+            | Opcode::get_function
+            | Opcode::get_data
+            | Opcode::host_addr_function
+            | Opcode::host_addr_data_extend
+            | Opcode::panic
+            | Opcode::probe => InstructionFormat::I32,
+            Opcode::host_addr_data => InstructionFormat::I16I32,
+            //
+            _ => InstructionFormat::NoParams,
+        }
+    }
+
+    /// Returns the image format version and edition in which the opcode was introduced.
+    ///
+    /// Toolchains targeting an older runtime should reject (or lower) the instructions
+    /// whose `since()` is not supported by the target.
+    pub fn since(&self) -> OpcodeSince {
+        let (major, minor) = match self {
+            Opcode::memory_wait_i32
+            | Opcode::memory_wait_i64
+            | Opcode::memory_notify
+            | Opcode::add_overflow_i32_s
+            | Opcode::add_overflow_i32_u
+            | Opcode::add_overflow_i64_s
+            | Opcode::add_overflow_i64_u
+            | Opcode::sub_overflow_i32_s
+            | Opcode::sub_overflow_i32_u
+            | Opcode::sub_overflow_i64_s
+            | Opcode::sub_overflow_i64_u
+            | Opcode::mul_overflow_i32_s
+            | Opcode::mul_overflow_i32_u
+            | Opcode::mul_overflow_i64_s
+            | Opcode::mul_overflow_i64_u
+            | Opcode::add_with_carry_i32
+            | Opcode::add_with_carry_i64
+            | Opcode::sub_with_borrow_i32
+            | Opcode::sub_with_borrow_i64
+            | Opcode::mul_hi_i64_s
+            | Opcode::mul_hi_i64_u
+            | Opcode::mul_wide_i32_s
+            | Opcode::mul_wide_i32_u
+            | Opcode::extract_bits_i32
+            | Opcode::extract_bits_i64
+            | Opcode::insert_bits_i32
+            | Opcode::insert_bits_i64
+            | Opcode::convert_f32_to_i32_s_sat
+            | Opcode::convert_f32_to_i32_u_sat
+            | Opcode::convert_f64_to_i32_s_sat
+            | Opcode::convert_f64_to_i32_u_sat
+            | Opcode::convert_f32_to_i64_s_sat
+            | Opcode::convert_f32_to_i64_u_sat
+            | Opcode::convert_f64_to_i64_s_sat
+            | Opcode::convert_f64_to_i64_u_sat
+            | Opcode::convert_f32_to_i32_s_checked
+            | Opcode::convert_f32_to_i32_u_checked
+            | Opcode::convert_f64_to_i32_s_checked
+            | Opcode::convert_f64_to_i32_u_checked
+            | Opcode::convert_f32_to_i64_s_checked
+            | Opcode::convert_f32_to_i64_u_checked
+            | Opcode::convert_f64_to_i64_s_checked
+            | Opcode::convert_f64_to_i64_u_checked
+            | Opcode::atan2_f32
+            | Opcode::atan2_f64
+            | Opcode::sinh_f32
+            | Opcode::sinh_f64
+            | Opcode::cosh_f32
+            | Opcode::cosh_f64
+            | Opcode::tanh_f32
+            | Opcode::tanh_f64
+            | Opcode::asinh_f32
+            | Opcode::asinh_f64
+            | Opcode::acosh_f32
+            | Opcode::acosh_f64
+            | Opcode::atanh_f32
+            | Opcode::atanh_f64
+            | Opcode::hypot_f32
+            | Opcode::hypot_f64
+            | Opcode::exp_m1_f32
+            | Opcode::exp_m1_f64
+            | Opcode::ln_1p_f32
+            | Opcode::ln_1p_f64
+            | Opcode::min_i32_s
+            | Opcode::min_i32_u
+            | Opcode::max_i32_s
+            | Opcode::max_i32_u
+            | Opcode::min_i64_s
+            | Opcode::min_i64_u
+            | Opcode::max_i64_s
+            | Opcode::max_i64_u
+            | Opcode::clamp_i32_s
+            | Opcode::clamp_i32_u
+            | Opcode::clamp_i64_s
+            | Opcode::clamp_i64_u
+            | Opcode::cmp_i32_s
+            | Opcode::cmp_i32_u
+            | Opcode::cmp_i64_s
+            | Opcode::cmp_i64_u
+            | Opcode::cmp_f32
+            | Opcode::cmp_f64
+            | Opcode::dup
+            | Opcode::drop
+            | Opcode::swap
+            | Opcode::promote_f16_to_f32
+            | Opcode::demote_f32_to_f16
+            | Opcode::promote_bf16_to_f32
+            | Opcode::demote_f32_to_bf16
+            | Opcode::shift_left_imm_i32
+            | Opcode::shift_right_imm_i32_s
+            | Opcode::shift_right_imm_i32_u
+            | Opcode::rotate_left_imm_i32
+            | Opcode::rotate_right_imm_i32
+            | Opcode::shift_left_imm_i64
+            | Opcode::shift_right_imm_i64_s
+            | Opcode::shift_right_imm_i64_u
+            | Opcode::rotate_left_imm_i64
+            | Opcode::rotate_right_imm_i64
+            | Opcode::call_indirect
+            | Opcode::call_tail
+            | Opcode::call_dynamic_tail
+            | Opcode::closure_create
+            | Opcode::closure_call
+            | Opcode::block_try
+            | Opcode::catch
+            | Opcode::catch_all
+            | Opcode::throw
+            | Opcode::rethrow
+            | Opcode::panic
+            | Opcode::debug_break
+            | Opcode::probe
+            | Opcode::get_call_depth
+            | Opcode::get_call_frame
+            | Opcode::memory_allocate_zeroed
+            | Opcode::memory_protect
+            | Opcode::memory_allocate_with_flags
+            | Opcode::host_copy_to_data
+            | Opcode::host_copy_from_data
+            | Opcode::find_function_by_name
+            | Opcode::find_data_by_name
+            | Opcode::imm_i32_small
+            | Opcode::push_zero
+            | Opcode::break_table
+            | Opcode::recur_nez
+            | Opcode::break_nez
+            | Opcode::div_rem_i32_s
+            | Opcode::div_rem_i32_u
+            | Opcode::div_rem_i64_s
+            | Opcode::div_rem_i64_u
+            | Opcode::memory_move
+            | Opcode::memory_find => (1, 1),
+            _ => match self.get_category() {
+                OpcodeCategory::Atomic
+                | OpcodeCategory::I128
+                | OpcodeCategory::Checksum
+                | OpcodeCategory::Crypto => (1, 1),
+                // the initial instruction set.
+                _ => (1, 0),
+            },
+        };
+
+        OpcodeSince {
+            image_format_major_version: major,
+            image_format_minor_version: minor,
+            edition: "2025",
+        }
+    }
+
+    /// Returns the edition in which the opcode was removed, or `None` if the opcode
+    /// is still available in the latest edition.
+    ///
+    /// No opcode has been removed yet.
+    pub fn removed_in(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Checks whether the opcode is available in the specified edition, i.e., the edition
+    /// is not earlier than the one in which the opcode was introduced, and is earlier than
+    /// the one in which the opcode was removed (see `EDITION_HISTORY`).
+    ///
+    /// This is used by the compiler to check the instructions when compiling a module
+    /// under a different edition. Unknown editions are not supported.
+    pub fn available_in(&self, edition: &Edition) -> bool {
+        let position = |name: &str| EDITION_HISTORY.iter().position(|item| *item == name);
+
+        let Some(index) = edition.get_release_index() else {
+            return false;
+        };
+
+        let introduced = position(self.since().edition).is_some_and(|start| index >= start);
+        let removed = self
+            .removed_in()
+            .is_some_and(|name| position(name).is_some_and(|end| index >= end));
+
+        introduced && !removed
+    }
+
     pub fn get_name(&self) -> &'static str {
         match self {
             // Category: Fundamental
@@ -1937,8 +3630,13 @@ impl Opcode {
             Opcode::imm_i64 => "imm_i64",
             Opcode::imm_f32 => "imm_f32",
             Opcode::imm_f64 => "imm_f64",
+            Opcode::dup => "dup",
+            Opcode::drop => "drop",
+            Opcode::swap => "swap",
+            Opcode::imm_i32_small => "imm_i32_small",
+            Opcode::push_zero => "push_zero",
             // Category: Local Variables
-            Opcode::local_load_i64 => "local_load_64",
+            Opcode::local_load_i64 => "local_load_i64",
             Opcode::local_load_i32_s => "local_load_i32_s",
             Opcode::local_load_i32_u => "local_load_i32_u",
             Opcode::local_load_i16_s => "local_load_i16_s",
@@ -2026,6 +3724,30 @@ impl Opcode {
             Opcode::sub_f64 => "sub_f64",
             Opcode::mul_f64 => "mul_f64",
             Opcode::div_f64 => "div_f64",
+            Opcode::add_overflow_i32_s => "add_overflow_i32_s",
+            Opcode::add_overflow_i32_u => "add_overflow_i32_u",
+            Opcode::add_overflow_i64_s => "add_overflow_i64_s",
+            Opcode::add_overflow_i64_u => "add_overflow_i64_u",
+            Opcode::sub_overflow_i32_s => "sub_overflow_i32_s",
+            Opcode::sub_overflow_i32_u => "sub_overflow_i32_u",
+            Opcode::sub_overflow_i64_s => "sub_overflow_i64_s",
+            Opcode::sub_overflow_i64_u => "sub_overflow_i64_u",
+            Opcode::mul_overflow_i32_s => "mul_overflow_i32_s",
+            Opcode::mul_overflow_i32_u => "mul_overflow_i32_u",
+            Opcode::mul_overflow_i64_s => "mul_overflow_i64_s",
+            Opcode::mul_overflow_i64_u => "mul_overflow_i64_u",
+            Opcode::add_with_carry_i32 => "add_with_carry_i32",
+            Opcode::add_with_carry_i64 => "add_with_carry_i64",
+            Opcode::sub_with_borrow_i32 => "sub_with_borrow_i32",
+            Opcode::sub_with_borrow_i64 => "sub_with_borrow_i64",
+            Opcode::mul_hi_i64_s => "mul_hi_i64_s",
+            Opcode::mul_hi_i64_u => "mul_hi_i64_u",
+            Opcode::mul_wide_i32_s => "mul_wide_i32_s",
+            Opcode::mul_wide_i32_u => "mul_wide_i32_u",
+            Opcode::div_rem_i32_s => "div_rem_i32_s",
+            Opcode::div_rem_i32_u => "div_rem_i32_u",
+            Opcode::div_rem_i64_s => "div_rem_i64_s",
+            Opcode::div_rem_i64_u => "div_rem_i64_u",
             // Category: Bitwise
             Opcode::and => "and",
             Opcode::or => "or",
@@ -2044,6 +3766,20 @@ impl Opcode {
             Opcode::count_leading_ones_i64 => "count_leading_ones_i64",
             Opcode::count_trailing_zeros_i64 => "count_trailing_zeros_i64",
             Opcode::count_ones_i64 => "count_ones_i64",
+            Opcode::extract_bits_i32 => "extract_bits_i32",
+            Opcode::extract_bits_i64 => "extract_bits_i64",
+            Opcode::insert_bits_i32 => "insert_bits_i32",
+            Opcode::insert_bits_i64 => "insert_bits_i64",
+            Opcode::shift_left_imm_i32 => "shift_left_imm_i32",
+            Opcode::shift_right_imm_i32_s => "shift_right_imm_i32_s",
+            Opcode::shift_right_imm_i32_u => "shift_right_imm_i32_u",
+            Opcode::rotate_left_imm_i32 => "rotate_left_imm_i32",
+            Opcode::rotate_right_imm_i32 => "rotate_right_imm_i32",
+            Opcode::shift_left_imm_i64 => "shift_left_imm_i64",
+            Opcode::shift_right_imm_i64_s => "shift_right_imm_i64_s",
+            Opcode::shift_right_imm_i64_u => "shift_right_imm_i64_u",
+            Opcode::rotate_left_imm_i64 => "rotate_left_imm_i64",
+            Opcode::rotate_right_imm_i64 => "rotate_right_imm_i64",
             Opcode::shift_left_i64 => "shift_left_i64",
             Opcode::shift_right_i64_s => "shift_right_i64_s",
             Opcode::shift_right_i64_u => "shift_right_i64_u",
@@ -2106,6 +3842,38 @@ impl Opcode {
             Opcode::atan_f64 => "atan_f64",
             Opcode::pow_f64 => "pow_f64",
             Opcode::log_f64 => "log_f64",
+            Opcode::atan2_f32 => "atan2_f32",
+            Opcode::atan2_f64 => "atan2_f64",
+            Opcode::sinh_f32 => "sinh_f32",
+            Opcode::sinh_f64 => "sinh_f64",
+            Opcode::cosh_f32 => "cosh_f32",
+            Opcode::cosh_f64 => "cosh_f64",
+            Opcode::tanh_f32 => "tanh_f32",
+            Opcode::tanh_f64 => "tanh_f64",
+            Opcode::asinh_f32 => "asinh_f32",
+            Opcode::asinh_f64 => "asinh_f64",
+            Opcode::acosh_f32 => "acosh_f32",
+            Opcode::acosh_f64 => "acosh_f64",
+            Opcode::atanh_f32 => "atanh_f32",
+            Opcode::atanh_f64 => "atanh_f64",
+            Opcode::hypot_f32 => "hypot_f32",
+            Opcode::hypot_f64 => "hypot_f64",
+            Opcode::exp_m1_f32 => "exp_m1_f32",
+            Opcode::exp_m1_f64 => "exp_m1_f64",
+            Opcode::ln_1p_f32 => "ln_1p_f32",
+            Opcode::ln_1p_f64 => "ln_1p_f64",
+            Opcode::min_i32_s => "min_i32_s",
+            Opcode::min_i32_u => "min_i32_u",
+            Opcode::max_i32_s => "max_i32_s",
+            Opcode::max_i32_u => "max_i32_u",
+            Opcode::min_i64_s => "min_i64_s",
+            Opcode::min_i64_u => "min_i64_u",
+            Opcode::max_i64_s => "max_i64_s",
+            Opcode::max_i64_u => "max_i64_u",
+            Opcode::clamp_i32_s => "clamp_i32_s",
+            Opcode::clamp_i32_u => "clamp_i32_u",
+            Opcode::clamp_i64_s => "clamp_i64_s",
+            Opcode::clamp_i64_u => "clamp_i64_u",
             // Category: Conversion
             Opcode::truncate_i64_to_i32 => "truncate_i64_to_i32",
             Opcode::extend_i32_s_to_i64 => "extend_i32_s_to_i64",
@@ -2128,6 +3896,26 @@ impl Opcode {
             Opcode::convert_i32_u_to_f64 => "convert_i32_u_to_f64",
             Opcode::convert_i64_s_to_f64 => "convert_i64_s_to_f64",
             Opcode::convert_i64_u_to_f64 => "convert_i64_u_to_f64",
+            Opcode::convert_f32_to_i32_s_sat => "convert_f32_to_i32_s_sat",
+            Opcode::convert_f32_to_i32_u_sat => "convert_f32_to_i32_u_sat",
+            Opcode::convert_f64_to_i32_s_sat => "convert_f64_to_i32_s_sat",
+            Opcode::convert_f64_to_i32_u_sat => "convert_f64_to_i32_u_sat",
+            Opcode::convert_f32_to_i64_s_sat => "convert_f32_to_i64_s_sat",
+            Opcode::convert_f32_to_i64_u_sat => "convert_f32_to_i64_u_sat",
+            Opcode::convert_f64_to_i64_s_sat => "convert_f64_to_i64_s_sat",
+            Opcode::convert_f64_to_i64_u_sat => "convert_f64_to_i64_u_sat",
+            Opcode::convert_f32_to_i32_s_checked => "convert_f32_to_i32_s_checked",
+            Opcode::convert_f32_to_i32_u_checked => "convert_f32_to_i32_u_checked",
+            Opcode::convert_f64_to_i32_s_checked => "convert_f64_to_i32_s_checked",
+            Opcode::convert_f64_to_i32_u_checked => "convert_f64_to_i32_u_checked",
+            Opcode::convert_f32_to_i64_s_checked => "convert_f32_to_i64_s_checked",
+            Opcode::convert_f32_to_i64_u_checked => "convert_f32_to_i64_u_checked",
+            Opcode::convert_f64_to_i64_s_checked => "convert_f64_to_i64_s_checked",
+            Opcode::convert_f64_to_i64_u_checked => "convert_f64_to_i64_u_checked",
+            Opcode::promote_f16_to_f32 => "promote_f16_to_f32",
+            Opcode::demote_f32_to_f16 => "demote_f32_to_f16",
+            Opcode::promote_bf16_to_f32 => "promote_bf16_to_f32",
+            Opcode::demote_f32_to_bf16 => "demote_f32_to_bf16",
             // Category: Comparison
             Opcode::eqz_i32 => "eqz_i32",
             Opcode::nez_i32 => "nez_i32",
@@ -2165,6 +3953,12 @@ impl Opcode {
             Opcode::gt_f64 => "gt_f64",
             Opcode::le_f64 => "le_f64",
             Opcode::ge_f64 => "ge_f64",
+            Opcode::cmp_i32_s => "cmp_i32_s",
+            Opcode::cmp_i32_u => "cmp_i32_u",
+            Opcode::cmp_i64_s => "cmp_i64_s",
+            Opcode::cmp_i64_u => "cmp_i64_u",
+            Opcode::cmp_f32 => "cmp_f32",
+            Opcode::cmp_f64 => "cmp_f64",
             // Category: Control flow
             Opcode::end => "end",
             Opcode::block => "block",
@@ -2173,18 +3967,39 @@ impl Opcode {
             Opcode::block_alt => "block_alt",
             Opcode::break_alt => "break_alt",
             Opcode::block_nez => "block_nez",
+            Opcode::block_try => "block_try",
+            Opcode::catch => "catch",
+            Opcode::catch_all => "catch_all",
+            Opcode::throw => "throw",
+            Opcode::rethrow => "rethrow",
+            Opcode::break_table => "break_table",
+            Opcode::recur_nez => "recur_nez",
+            Opcode::break_nez => "break_nez",
             // Category: Function Call
             Opcode::call => "call",
             Opcode::call_dynamic => "call_dynamic",
             Opcode::envcall => "envcall",
             Opcode::syscall => "syscall",
             Opcode::extcall => "extcall",
+            Opcode::call_indirect => "call_indirect",
+            Opcode::call_tail => "call_tail",
+            Opcode::call_dynamic_tail => "call_dynamic_tail",
+            Opcode::closure_create => "closure_create",
+            Opcode::closure_call => "closure_call",
             // Category: Memory
             Opcode::memory_allocate => "memory_allocate",
             Opcode::memory_reallocate => "memory_reallocate",
             Opcode::memory_free => "memory_free",
             Opcode::memory_fill => "memory_fill",
             Opcode::memory_copy => "memory_copy",
+            Opcode::memory_wait_i32 => "memory_wait_i32",
+            Opcode::memory_wait_i64 => "memory_wait_i64",
+            Opcode::memory_notify => "memory_notify",
+            Opcode::memory_allocate_zeroed => "memory_allocate_zeroed",
+            Opcode::memory_protect => "memory_protect",
+            Opcode::memory_allocate_with_flags => "memory_allocate_with_flags",
+            Opcode::memory_move => "memory_move",
+            Opcode::memory_find => "memory_find",
             // Category: Machine
             Opcode::terminate => "terminate",
             Opcode::get_function => "get_function",
@@ -2194,6 +4009,64 @@ impl Opcode {
             Opcode::host_addr_data => "host_addr_data",
             Opcode::host_addr_data_extend => "host_addr_data_extend",
             Opcode::host_addr_data_dynamic => "host_addr_data_dynamic",
+            Opcode::panic => "panic",
+            Opcode::debug_break => "debug_break",
+            Opcode::probe => "probe",
+            Opcode::get_call_depth => "get_call_depth",
+            Opcode::get_call_frame => "get_call_frame",
+            Opcode::host_copy_to_data => "host_copy_to_data",
+            Opcode::host_copy_from_data => "host_copy_from_data",
+            Opcode::find_function_by_name => "find_function_by_name",
+            Opcode::find_data_by_name => "find_data_by_name",
+            // Category: Atomic
+            Opcode::atomic_load_i32 => "atomic_load_i32",
+            Opcode::atomic_load_i64 => "atomic_load_i64",
+            Opcode::atomic_store_i32 => "atomic_store_i32",
+            Opcode::atomic_store_i64 => "atomic_store_i64",
+            Opcode::atomic_rmw_add_i32 => "atomic_rmw_add_i32",
+            Opcode::atomic_rmw_add_i64 => "atomic_rmw_add_i64",
+            Opcode::atomic_rmw_sub_i32 => "atomic_rmw_sub_i32",
+            Opcode::atomic_rmw_sub_i64 => "atomic_rmw_sub_i64",
+            Opcode::atomic_rmw_and_i32 => "atomic_rmw_and_i32",
+            Opcode::atomic_rmw_and_i64 => "atomic_rmw_and_i64",
+            Opcode::atomic_rmw_or_i32 => "atomic_rmw_or_i32",
+            Opcode::atomic_rmw_or_i64 => "atomic_rmw_or_i64",
+            Opcode::atomic_rmw_xor_i32 => "atomic_rmw_xor_i32",
+            Opcode::atomic_rmw_xor_i64 => "atomic_rmw_xor_i64",
+            Opcode::atomic_rmw_exchange_i32 => "atomic_rmw_exchange_i32",
+            Opcode::atomic_rmw_exchange_i64 => "atomic_rmw_exchange_i64",
+            Opcode::atomic_cas_i32 => "atomic_cas_i32",
+            Opcode::atomic_cas_i64 => "atomic_cas_i64",
+            // Category: I128
+            Opcode::add_i128 => "add_i128",
+            Opcode::sub_i128 => "sub_i128",
+            Opcode::mul_i128 => "mul_i128",
+            Opcode::div_i128_s => "div_i128_s",
+            Opcode::div_i128_u => "div_i128_u",
+            Opcode::rem_i128_s => "rem_i128_s",
+            Opcode::rem_i128_u => "rem_i128_u",
+            Opcode::eq_i128 => "eq_i128",
+            Opcode::ne_i128 => "ne_i128",
+            Opcode::lt_i128_s => "lt_i128_s",
+            Opcode::lt_i128_u => "lt_i128_u",
+            Opcode::gt_i128_s => "gt_i128_s",
+            Opcode::gt_i128_u => "gt_i128_u",
+            Opcode::le_i128_s => "le_i128_s",
+            Opcode::le_i128_u => "le_i128_u",
+            Opcode::ge_i128_s => "ge_i128_s",
+            Opcode::ge_i128_u => "ge_i128_u",
+            Opcode::shift_left_i128 => "shift_left_i128",
+            Opcode::shift_right_i128_s => "shift_right_i128_s",
+            Opcode::shift_right_i128_u => "shift_right_i128_u",
+            // Category: Checksum
+            Opcode::crc32 => "crc32",
+            Opcode::crc32c => "crc32c",
+            // Category: Crypto
+            Opcode::aes_encrypt_round => "aes_encrypt_round",
+            Opcode::aes_encrypt_last_round => "aes_encrypt_last_round",
+            Opcode::aes_decrypt_round => "aes_decrypt_round",
+            Opcode::aes_decrypt_last_round => "aes_decrypt_last_round",
+            Opcode::sha256_compress => "sha256_compress",
         }
     }
 
@@ -2205,6 +4078,11 @@ impl Opcode {
             "imm_i64" => Opcode::imm_i64,
             "imm_f32" => Opcode::imm_f32,
             "imm_f64" => Opcode::imm_f64,
+            "dup" => Opcode::dup,
+            "drop" => Opcode::drop,
+            "swap" => Opcode::swap,
+            "imm_i32_small" => Opcode::imm_i32_small,
+            "push_zero" => Opcode::push_zero,
             // Category: Local Variables
             "local_load_i64" => Opcode::local_load_i64,
             "local_load_i32_s" => Opcode::local_load_i32_s,
@@ -2294,6 +4172,30 @@ impl Opcode {
             "sub_f64" => Opcode::sub_f64,
             "mul_f64" => Opcode::mul_f64,
             "div_f64" => Opcode::div_f64,
+            "add_overflow_i32_s" => Opcode::add_overflow_i32_s,
+            "add_overflow_i32_u" => Opcode::add_overflow_i32_u,
+            "add_overflow_i64_s" => Opcode::add_overflow_i64_s,
+            "add_overflow_i64_u" => Opcode::add_overflow_i64_u,
+            "sub_overflow_i32_s" => Opcode::sub_overflow_i32_s,
+            "sub_overflow_i32_u" => Opcode::sub_overflow_i32_u,
+            "sub_overflow_i64_s" => Opcode::sub_overflow_i64_s,
+            "sub_overflow_i64_u" => Opcode::sub_overflow_i64_u,
+            "mul_overflow_i32_s" => Opcode::mul_overflow_i32_s,
+            "mul_overflow_i32_u" => Opcode::mul_overflow_i32_u,
+            "mul_overflow_i64_s" => Opcode::mul_overflow_i64_s,
+            "mul_overflow_i64_u" => Opcode::mul_overflow_i64_u,
+            "add_with_carry_i32" => Opcode::add_with_carry_i32,
+            "add_with_carry_i64" => Opcode::add_with_carry_i64,
+            "sub_with_borrow_i32" => Opcode::sub_with_borrow_i32,
+            "sub_with_borrow_i64" => Opcode::sub_with_borrow_i64,
+            "mul_hi_i64_s" => Opcode::mul_hi_i64_s,
+            "mul_hi_i64_u" => Opcode::mul_hi_i64_u,
+            "mul_wide_i32_s" => Opcode::mul_wide_i32_s,
+            "mul_wide_i32_u" => Opcode::mul_wide_i32_u,
+            "div_rem_i32_s" => Opcode::div_rem_i32_s,
+            "div_rem_i32_u" => Opcode::div_rem_i32_u,
+            "div_rem_i64_s" => Opcode::div_rem_i64_s,
+            "div_rem_i64_u" => Opcode::div_rem_i64_u,
             // Category: Bitwise
             "and" => Opcode::and,
             "or" => Opcode::or,
@@ -2312,6 +4214,20 @@ impl Opcode {
             "count_leading_ones_i64" => Opcode::count_leading_ones_i64,
             "count_trailing_zeros_i64" => Opcode::count_trailing_zeros_i64,
             "count_ones_i64" => Opcode::count_ones_i64,
+            "extract_bits_i32" => Opcode::extract_bits_i32,
+            "extract_bits_i64" => Opcode::extract_bits_i64,
+            "insert_bits_i32" => Opcode::insert_bits_i32,
+            "insert_bits_i64" => Opcode::insert_bits_i64,
+            "shift_left_imm_i32" => Opcode::shift_left_imm_i32,
+            "shift_right_imm_i32_s" => Opcode::shift_right_imm_i32_s,
+            "shift_right_imm_i32_u" => Opcode::shift_right_imm_i32_u,
+            "rotate_left_imm_i32" => Opcode::rotate_left_imm_i32,
+            "rotate_right_imm_i32" => Opcode::rotate_right_imm_i32,
+            "shift_left_imm_i64" => Opcode::shift_left_imm_i64,
+            "shift_right_imm_i64_s" => Opcode::shift_right_imm_i64_s,
+            "shift_right_imm_i64_u" => Opcode::shift_right_imm_i64_u,
+            "rotate_left_imm_i64" => Opcode::rotate_left_imm_i64,
+            "rotate_right_imm_i64" => Opcode::rotate_right_imm_i64,
             "shift_left_i64" => Opcode::shift_left_i64,
             "shift_right_i64_s" => Opcode::shift_right_i64_s,
             "shift_right_i64_u" => Opcode::shift_right_i64_u,
@@ -2374,6 +4290,38 @@ impl Opcode {
             "atan_f64" => Opcode::atan_f64,
             "pow_f64" => Opcode::pow_f64,
             "log_f64" => Opcode::log_f64,
+            "atan2_f32" => Opcode::atan2_f32,
+            "atan2_f64" => Opcode::atan2_f64,
+            "sinh_f32" => Opcode::sinh_f32,
+            "sinh_f64" => Opcode::sinh_f64,
+            "cosh_f32" => Opcode::cosh_f32,
+            "cosh_f64" => Opcode::cosh_f64,
+            "tanh_f32" => Opcode::tanh_f32,
+            "tanh_f64" => Opcode::tanh_f64,
+            "asinh_f32" => Opcode::asinh_f32,
+            "asinh_f64" => Opcode::asinh_f64,
+            "acosh_f32" => Opcode::acosh_f32,
+            "acosh_f64" => Opcode::acosh_f64,
+            "atanh_f32" => Opcode::atanh_f32,
+            "atanh_f64" => Opcode::atanh_f64,
+            "hypot_f32" => Opcode::hypot_f32,
+            "hypot_f64" => Opcode::hypot_f64,
+            "exp_m1_f32" => Opcode::exp_m1_f32,
+            "exp_m1_f64" => Opcode::exp_m1_f64,
+            "ln_1p_f32" => Opcode::ln_1p_f32,
+            "ln_1p_f64" => Opcode::ln_1p_f64,
+            "min_i32_s" => Opcode::min_i32_s,
+            "min_i32_u" => Opcode::min_i32_u,
+            "max_i32_s" => Opcode::max_i32_s,
+            "max_i32_u" => Opcode::max_i32_u,
+            "min_i64_s" => Opcode::min_i64_s,
+            "min_i64_u" => Opcode::min_i64_u,
+            "max_i64_s" => Opcode::max_i64_s,
+            "max_i64_u" => Opcode::max_i64_u,
+            "clamp_i32_s" => Opcode::clamp_i32_s,
+            "clamp_i32_u" => Opcode::clamp_i32_u,
+            "clamp_i64_s" => Opcode::clamp_i64_s,
+            "clamp_i64_u" => Opcode::clamp_i64_u,
             // Category: Conversion
             "truncate_i64_to_i32" => Opcode::truncate_i64_to_i32,
             "extend_i32_s_to_i64" => Opcode::extend_i32_s_to_i64,
@@ -2396,6 +4344,26 @@ impl Opcode {
             "convert_i32_u_to_f64" => Opcode::convert_i32_u_to_f64,
             "convert_i64_s_to_f64" => Opcode::convert_i64_s_to_f64,
             "convert_i64_u_to_f64" => Opcode::convert_i64_u_to_f64,
+            "convert_f32_to_i32_s_sat" => Opcode::convert_f32_to_i32_s_sat,
+            "convert_f32_to_i32_u_sat" => Opcode::convert_f32_to_i32_u_sat,
+            "convert_f64_to_i32_s_sat" => Opcode::convert_f64_to_i32_s_sat,
+            "convert_f64_to_i32_u_sat" => Opcode::convert_f64_to_i32_u_sat,
+            "convert_f32_to_i64_s_sat" => Opcode::convert_f32_to_i64_s_sat,
+            "convert_f32_to_i64_u_sat" => Opcode::convert_f32_to_i64_u_sat,
+            "convert_f64_to_i64_s_sat" => Opcode::convert_f64_to_i64_s_sat,
+            "convert_f64_to_i64_u_sat" => Opcode::convert_f64_to_i64_u_sat,
+            "convert_f32_to_i32_s_checked" => Opcode::convert_f32_to_i32_s_checked,
+            "convert_f32_to_i32_u_checked" => Opcode::convert_f32_to_i32_u_checked,
+            "convert_f64_to_i32_s_checked" => Opcode::convert_f64_to_i32_s_checked,
+            "convert_f64_to_i32_u_checked" => Opcode::convert_f64_to_i32_u_checked,
+            "convert_f32_to_i64_s_checked" => Opcode::convert_f32_to_i64_s_checked,
+            "convert_f32_to_i64_u_checked" => Opcode::convert_f32_to_i64_u_checked,
+            "convert_f64_to_i64_s_checked" => Opcode::convert_f64_to_i64_s_checked,
+            "convert_f64_to_i64_u_checked" => Opcode::convert_f64_to_i64_u_checked,
+            "promote_f16_to_f32" => Opcode::promote_f16_to_f32,
+            "demote_f32_to_f16" => Opcode::demote_f32_to_f16,
+            "promote_bf16_to_f32" => Opcode::promote_bf16_to_f32,
+            "demote_f32_to_bf16" => Opcode::demote_f32_to_bf16,
             // Category: Comparison
             "eqz_i32" => Opcode::eqz_i32,
             "nez_i32" => Opcode::nez_i32,
@@ -2433,6 +4401,12 @@ impl Opcode {
             "gt_f64" => Opcode::gt_f64,
             "le_f64" => Opcode::le_f64,
             "ge_f64" => Opcode::ge_f64,
+            "cmp_i32_s" => Opcode::cmp_i32_s,
+            "cmp_i32_u" => Opcode::cmp_i32_u,
+            "cmp_i64_s" => Opcode::cmp_i64_s,
+            "cmp_i64_u" => Opcode::cmp_i64_u,
+            "cmp_f32" => Opcode::cmp_f32,
+            "cmp_f64" => Opcode::cmp_f64,
             // Category: Control flow
             "end" => Opcode::end,
             "block" => Opcode::block,
@@ -2441,18 +4415,39 @@ impl Opcode {
             "block_alt" => Opcode::block_alt,
             "break_alt" => Opcode::break_alt,
             "block_nez" => Opcode::block_nez,
+            "block_try" => Opcode::block_try,
+            "catch" => Opcode::catch,
+            "catch_all" => Opcode::catch_all,
+            "throw" => Opcode::throw,
+            "rethrow" => Opcode::rethrow,
+            "break_table" => Opcode::break_table,
+            "recur_nez" => Opcode::recur_nez,
+            "break_nez" => Opcode::break_nez,
             // Category: Function Call
             "call" => Opcode::call,
             "call_dynamic" => Opcode::call_dynamic,
             "envcall" => Opcode::envcall,
             "syscall" => Opcode::syscall,
             "extcall" => Opcode::extcall,
+            "call_indirect" => Opcode::call_indirect,
+            "call_tail" => Opcode::call_tail,
+            "call_dynamic_tail" => Opcode::call_dynamic_tail,
+            "closure_create" => Opcode::closure_create,
+            "closure_call" => Opcode::closure_call,
             // Category: Memory
             "memory_allocate" => Opcode::memory_allocate,
             "memory_reallocate" => Opcode::memory_reallocate,
             "memory_free" => Opcode::memory_free,
             "memory_fill" => Opcode::memory_fill,
             "memory_copy" => Opcode::memory_copy,
+            "memory_wait_i32" => Opcode::memory_wait_i32,
+            "memory_wait_i64" => Opcode::memory_wait_i64,
+            "memory_notify" => Opcode::memory_notify,
+            "memory_allocate_zeroed" => Opcode::memory_allocate_zeroed,
+            "memory_protect" => Opcode::memory_protect,
+            "memory_allocate_with_flags" => Opcode::memory_allocate_with_flags,
+            "memory_move" => Opcode::memory_move,
+            "memory_find" => Opcode::memory_find,
             // Category: Machine
             "terminate" => Opcode::terminate,
             "get_function" => Opcode::get_function,
@@ -2462,6 +4457,64 @@ impl Opcode {
             "host_addr_data" => Opcode::host_addr_data,
             "host_addr_data_extend" => Opcode::host_addr_data_extend,
             "host_addr_data_dynamic" => Opcode::host_addr_data_dynamic,
+            "panic" => Opcode::panic,
+            "debug_break" => Opcode::debug_break,
+            "probe" => Opcode::probe,
+            "get_call_depth" => Opcode::get_call_depth,
+            "get_call_frame" => Opcode::get_call_frame,
+            "host_copy_to_data" => Opcode::host_copy_to_data,
+            "host_copy_from_data" => Opcode::host_copy_from_data,
+            "find_function_by_name" => Opcode::find_function_by_name,
+            "find_data_by_name" => Opcode::find_data_by_name,
+            // Category: Atomic
+            "atomic_load_i32" => Opcode::atomic_load_i32,
+            "atomic_load_i64" => Opcode::atomic_load_i64,
+            "atomic_store_i32" => Opcode::atomic_store_i32,
+            "atomic_store_i64" => Opcode::atomic_store_i64,
+            "atomic_rmw_add_i32" => Opcode::atomic_rmw_add_i32,
+            "atomic_rmw_add_i64" => Opcode::atomic_rmw_add_i64,
+            "atomic_rmw_sub_i32" => Opcode::atomic_rmw_sub_i32,
+            "atomic_rmw_sub_i64" => Opcode::atomic_rmw_sub_i64,
+            "atomic_rmw_and_i32" => Opcode::atomic_rmw_and_i32,
+            "atomic_rmw_and_i64" => Opcode::atomic_rmw_and_i64,
+            "atomic_rmw_or_i32" => Opcode::atomic_rmw_or_i32,
+            "atomic_rmw_or_i64" => Opcode::atomic_rmw_or_i64,
+            "atomic_rmw_xor_i32" => Opcode::atomic_rmw_xor_i32,
+            "atomic_rmw_xor_i64" => Opcode::atomic_rmw_xor_i64,
+            "atomic_rmw_exchange_i32" => Opcode::atomic_rmw_exchange_i32,
+            "atomic_rmw_exchange_i64" => Opcode::atomic_rmw_exchange_i64,
+            "atomic_cas_i32" => Opcode::atomic_cas_i32,
+            "atomic_cas_i64" => Opcode::atomic_cas_i64,
+            // Category: I128
+            "add_i128" => Opcode::add_i128,
+            "sub_i128" => Opcode::sub_i128,
+            "mul_i128" => Opcode::mul_i128,
+            "div_i128_s" => Opcode::div_i128_s,
+            "div_i128_u" => Opcode::div_i128_u,
+            "rem_i128_s" => Opcode::rem_i128_s,
+            "rem_i128_u" => Opcode::rem_i128_u,
+            "eq_i128" => Opcode::eq_i128,
+            "ne_i128" => Opcode::ne_i128,
+            "lt_i128_s" => Opcode::lt_i128_s,
+            "lt_i128_u" => Opcode::lt_i128_u,
+            "gt_i128_s" => Opcode::gt_i128_s,
+            "gt_i128_u" => Opcode::gt_i128_u,
+            "le_i128_s" => Opcode::le_i128_s,
+            "le_i128_u" => Opcode::le_i128_u,
+            "ge_i128_s" => Opcode::ge_i128_s,
+            "ge_i128_u" => Opcode::ge_i128_u,
+            "shift_left_i128" => Opcode::shift_left_i128,
+            "shift_right_i128_s" => Opcode::shift_right_i128_s,
+            "shift_right_i128_u" => Opcode::shift_right_i128_u,
+            // Category: Checksum
+            "crc32" => Opcode::crc32,
+            "crc32c" => Opcode::crc32c,
+            // Category: Crypto
+            "aes_encrypt_round" => Opcode::aes_encrypt_round,
+            "aes_encrypt_last_round" => Opcode::aes_encrypt_last_round,
+            "aes_decrypt_round" => Opcode::aes_decrypt_round,
+            "aes_decrypt_last_round" => Opcode::aes_decrypt_last_round,
+            "sha256_compress" => Opcode::sha256_compress,
             //
             _ => panic!("Unknown instruction \"{}\".", name),
         }
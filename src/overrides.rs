@@ -0,0 +1,382 @@
+// Copyright (c) 2025 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions.
+// For more details, see the LICENSE, LICENSE.additional, and CONTRIBUTING files.
+
+// Dependency Overrides
+// --------------------
+//
+// The overrides section of the application (or the root module) replaces a named module
+// everywhere in the dependency tree with a local path or an alternate source, so that
+// developers can test fixes to deep transitive dependencies without editing every manifest, e.g.:
+//
+// ```ason
+// overrides: [
+//     "sha2": module::local({
+//         path: "~/projects/sha2"
+//     })
+// ]
+// ```
+//
+// Only the source (i.e., the path, URL and Git reference, version, or archive) is replaced,
+// the parameters, condition, kind and `optional` declared by each dependent are kept.
+// Overrides are applied by the application only, the overrides of dependencies are ignored.
+//
+// Local Overrides
+// ---------------
+//
+// To debug a module locally, developers can redirect it to a local path without
+// editing the committed manifests, the local overrides are loaded from:
+//
+// - The user config file, a map of module names and paths, e.g.:
+//
+//   ```ason
+//   local_overrides: [
+//       "sha2": "~/projects/sha2"
+//   ]
+//   ```
+//
+// - The environment variable `ANC_LOCAL_OVERRIDES`, the items "NAME=PATH" are
+//   separated by ';', e.g. "sha2=~/projects/sha2;md5=../md5".
+//
+// The precedence (from high to low) is the environment variable, the user config
+// and the overrides of the application (or the workspace), i.e., a module
+// overridden in multiple layers uses the path of the highest layer.
+// The paths are the same as `DependencyLocal::path`, see the module `path`.
+
+use std::{collections::HashMap, fmt::Display};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    DependencyCondition, DependencyKind, DependencyLocal, DependencyParameterValue,
+    ModuleDependency,
+};
+
+pub const LOCAL_OVERRIDES_ENVIRONMENT_VARIABLE: &str = "ANC_LOCAL_OVERRIDES";
+pub const LOCAL_OVERRIDES_ITEM_SEPARATOR: char = ';';
+
+/// It is serialized as a map of module names and replacements.
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct DependencyOverrides {
+    pub overrides: HashMap<String, ModuleDependency>,
+}
+
+/// The local overrides, see the section "Local Overrides".
+/// It is serialized as a map of module names and paths.
+#[derive(Debug, PartialEq, Eq, Clone, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct LocalOverrides {
+    pub paths: HashMap<String, String>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum OverrideError {
+    /// The replacement is `Runtime` or `Current`.
+    InvalidReplacement { module_name: String },
+
+    /// The item of the local overrides is not "NAME=PATH", or the name or the path is empty.
+    MalformedLocalOverride { item: String },
+}
+
+impl Display for OverrideError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OverrideError::InvalidReplacement { module_name } => write!(
+                f,
+                "The override of module \"{}\" must be a local, remote, share or vendored module.",
+                module_name
+            ),
+            OverrideError::MalformedLocalOverride { item } => write!(
+                f,
+                "Malformed local override \"{}\", expected \"NAME=PATH\".",
+                item
+            ),
+        }
+    }
+}
+
+impl std::error::Error for OverrideError {}
+
+type DependencySettings<'a> = (
+    &'a mut HashMap<String, DependencyParameterValue>,
+    &'a mut DependencyCondition,
+    &'a mut DependencyKind,
+    &'a mut bool,
+);
+
+fn get_settings_mut(dependency: &mut ModuleDependency) -> Option<DependencySettings<'_>> {
+    match dependency {
+        ModuleDependency::Local(item) => Some((
+            &mut item.parameters,
+            &mut item.condition,
+            &mut item.kind,
+            &mut item.optional,
+        )),
+        ModuleDependency::Remote(item) => Some((
+            &mut item.parameters,
+            &mut item.condition,
+            &mut item.kind,
+            &mut item.optional,
+        )),
+        ModuleDependency::Share(item) => Some((
+            &mut item.parameters,
+            &mut item.condition,
+            &mut item.kind,
+            &mut item.optional,
+        )),
+        ModuleDependency::Vendored(item) => Some((
+            &mut item.parameters,
+            &mut item.condition,
+            &mut item.kind,
+            &mut item.optional,
+        )),
+        ModuleDependency::Runtime | ModuleDependency::Current => None,
+    }
+}
+
+impl DependencyOverrides {
+    pub fn validate(&self) -> Result<(), OverrideError> {
+        match self.overrides.iter().find(|(_, replacement)| {
+            matches!(
+                replacement,
+                ModuleDependency::Runtime | ModuleDependency::Current
+            )
+        }) {
+            Some((module_name, _)) => Err(OverrideError::InvalidReplacement {
+                module_name: module_name.to_owned(),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    pub fn get_override(&self, module_name: &str) -> Option<&ModuleDependency> {
+        self.overrides.get(module_name)
+    }
+
+    /// Returns the dependency with the source replaced by the override,
+    /// or a copy of the original dependency if the module is not overridden
+    /// (or it is a `Runtime` or `Current` module).
+    pub fn apply(&self, module_name: &str, original: &ModuleDependency) -> ModuleDependency {
+        let mut original = original.clone();
+        let Some(mut replacement) = self.get_override(module_name).cloned() else {
+            return original;
+        };
+
+        match (
+            get_settings_mut(&mut original),
+            get_settings_mut(&mut replacement),
+        ) {
+            (Some(from), Some(to)) => {
+                std::mem::swap(from.0, to.0);
+                std::mem::swap(from.1, to.1);
+                std::mem::swap(from.2, to.2);
+                std::mem::swap(from.3, to.3);
+                replacement
+            }
+            _ => original,
+        }
+    }
+}
+
+impl LocalOverrides {
+    /// Parses the value of the environment variable `ANC_LOCAL_OVERRIDES`,
+    /// e.g. "sha2=~/projects/sha2;md5=../md5", the empty items are ignored.
+    pub fn from_environment_string(text: &str) -> Result<Self, OverrideError> {
+        let mut paths = HashMap::new();
+        for item in text
+            .split(LOCAL_OVERRIDES_ITEM_SEPARATOR)
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+        {
+            match item.split_once('=') {
+                Some((name, path)) if !name.trim().is_empty() && !path.trim().is_empty() => {
+                    paths.insert(name.trim().to_owned(), path.trim().to_owned());
+                }
+                _ => {
+                    return Err(OverrideError::MalformedLocalOverride {
+                        item: item.to_owned(),
+                    })
+                }
+            }
+        }
+        Ok(Self { paths })
+    }
+
+    /// Reads the environment variable `ANC_LOCAL_OVERRIDES`,
+    /// returns empty overrides if the variable is absent.
+    pub fn from_environment() -> Result<Self, OverrideError> {
+        match std::env::var(LOCAL_OVERRIDES_ENVIRONMENT_VARIABLE) {
+            Ok(text) => Self::from_environment_string(&text),
+            Err(_) => Ok(Self::default()),
+        }
+    }
+
+    /// Returns the overrides of both layers, `higher` takes precedence.
+    pub fn merge(&self, higher: &LocalOverrides) -> LocalOverrides {
+        let mut paths = self.paths.clone();
+        paths.extend(higher.paths.clone());
+        LocalOverrides { paths }
+    }
+
+    pub fn get_path(&self, module_name: &str) -> Option<&str> {
+        self.paths.get(module_name).map(String::as_str)
+    }
+}
+
+impl DependencyOverrides {
+    /// Returns the overrides with the local overrides applied on top,
+    /// see the section "Local Overrides".
+    pub fn with_local_overrides(&self, local: &LocalOverrides) -> DependencyOverrides {
+        let mut overrides = self.overrides.clone();
+        for (module_name, path) in &local.paths {
+            overrides.insert(
+                module_name.to_owned(),
+                ModuleDependency::Local(Box::new(DependencyLocal {
+                    path: path.to_owned(),
+                    parameters: HashMap::new(),
+                    condition: DependencyCondition::True,
+                    kind: DependencyKind::Normal,
+                    optional: false,
+                })),
+            );
+        }
+        DependencyOverrides { overrides }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        overrides::{DependencyOverrides, LocalOverrides, OverrideError},
+        DependencyCondition, DependencyKind, DependencyLocal, DependencyParameterValue,
+        DependencyShare, ModuleDependency,
+    };
+
+    #[test]
+    fn test_apply_override() {
+        let mut overrides = HashMap::new();
+        overrides.insert(
+            "sha2".to_owned(),
+            ModuleDependency::Local(Box::new(DependencyLocal {
+                path: "~/projects/sha2".to_owned(),
+                parameters: HashMap::new(),
+                condition: DependencyCondition::True,
+                kind: DependencyKind::Normal,
+                optional: false,
+            })),
+        );
+        let overrides = DependencyOverrides { overrides };
+        assert_eq!(overrides.validate(), Ok(()));
+
+        let mut parameters = HashMap::new();
+        parameters.insert(
+            "enable_asm".to_owned(),
+            DependencyParameterValue::Bool(true),
+        );
+        let original = ModuleDependency::Share(Box::new(DependencyShare {
+            version: "1.2".parse().unwrap(),
+            checksum: None,
+            parameters: parameters.clone(),
+            condition: DependencyCondition::False,
+            kind: DependencyKind::Dev,
+            optional: true,
+        }));
+
+        assert_eq!(
+            overrides.apply("sha2", &original),
+            ModuleDependency::Local(Box::new(DependencyLocal {
+                path: "~/projects/sha2".to_owned(),
+                parameters,
+                condition: DependencyCondition::False,
+                kind: DependencyKind::Dev,
+                optional: true,
+            }))
+        );
+        assert_eq!(overrides.apply("md5", &original), original);
+
+        let mut invalid = HashMap::new();
+        invalid.insert("sha2".to_owned(), ModuleDependency::Runtime);
+        assert_eq!(
+            DependencyOverrides { overrides: invalid }.validate(),
+            Err(OverrideError::InvalidReplacement {
+                module_name: "sha2".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn test_local_overrides() {
+        let config = LocalOverrides {
+            paths: HashMap::from([
+                ("sha2".to_owned(), "~/projects/sha2".to_owned()),
+                ("md5".to_owned(), "../md5".to_owned()),
+            ]),
+        };
+        let environment =
+            LocalOverrides::from_environment_string(" sha2 = /tmp/sha2;; xml=C:/xml ;").unwrap();
+        assert_eq!(environment.get_path("sha2"), Some("/tmp/sha2"));
+        assert_eq!(environment.get_path("xml"), Some("C:/xml"));
+        assert_eq!(
+            LocalOverrides::from_environment_string("").unwrap(),
+            LocalOverrides::default()
+        );
+        assert_eq!(
+            LocalOverrides::from_environment_string("sha2=/tmp/sha2;md5"),
+            Err(OverrideError::MalformedLocalOverride {
+                item: "md5".to_owned()
+            })
+        );
+        assert_eq!(
+            LocalOverrides::from_environment_string("=/tmp/sha2"),
+            Err(OverrideError::MalformedLocalOverride {
+                item: "=/tmp/sha2".to_owned()
+            })
+        );
+
+        let local = config.merge(&environment);
+        assert_eq!(local.get_path("sha2"), Some("/tmp/sha2"));
+        assert_eq!(local.get_path("md5"), Some("../md5"));
+        assert_eq!(local.get_path("zlib"), None);
+
+        let local_dependency = |path: &str| {
+            ModuleDependency::Local(Box::new(DependencyLocal {
+                path: path.to_owned(),
+                parameters: HashMap::new(),
+                condition: DependencyCondition::True,
+                kind: DependencyKind::Normal,
+                optional: false,
+            }))
+        };
+        let manifest = DependencyOverrides {
+            overrides: HashMap::from([
+                ("sha2".to_owned(), local_dependency("vendor/sha2")),
+                ("zlib".to_owned(), local_dependency("vendor/zlib")),
+            ]),
+        };
+        let overrides = manifest.with_local_overrides(&local);
+        assert_eq!(
+            overrides.get_override("sha2"),
+            Some(&local_dependency("/tmp/sha2"))
+        );
+        assert_eq!(
+            overrides.get_override("md5"),
+            Some(&local_dependency("../md5"))
+        );
+        assert_eq!(
+            overrides.get_override("xml"),
+            Some(&local_dependency("C:/xml"))
+        );
+        assert_eq!(
+            overrides.get_override("zlib"),
+            Some(&local_dependency("vendor/zlib"))
+        );
+        assert_eq!(overrides.validate(), Ok(()));
+    }
+}
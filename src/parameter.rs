@@ -0,0 +1,651 @@
+// Copyright (c) 2025 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions.
+// For more details, see the LICENSE, LICENSE.additional, and CONTRIBUTING files.
+
+// Parameter Inheritance
+// ---------------------
+//
+// A parameter of a dependency can inherit the value of a property of the parent module,
+// i.e., `param::from("PROPERTY_NAME")`, e.g.:
+//
+// ```ason
+// parameters: [
+//     "enable_simd": param::from("enable_fast")
+// ]
+// ```
+//
+// A property of the parent module may itself be inherited from another property
+// of the parent module, so the references are followed until a value is found,
+// e.g. "enable_simd" -> "enable_fast" -> `param::bool(true)`.
+// A missing property or a circular reference is an error.
+//
+// See also the sections "Flag Unification" and "Dependency Parameter Conflicts" of the crate,
+// which are implemented by `UnifiedFlags` and `check_parameter_conflicts`.
+
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fmt::Display,
+};
+
+use crate::{DependencyParameterValue, PropertyValue};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ParameterError {
+    /// The parameter refers to a property which does not exist in the parent module.
+    UndefinedProperty { parameter: String, property: String },
+
+    /// The chain of references, e.g. `["a", "b", "a"]`.
+    CircularReference {
+        parameter: String,
+        chain: Vec<String>,
+    },
+
+    /// The enabled flag is not a flag (or a group option) declared by the module.
+    UndefinedFlag { name: String },
+
+    /// More than one option of a group are enabled, the options are in alphabetical order.
+    ConflictingGroupOptions { group: String, options: Vec<String> },
+
+    /// The selected value of a choice property is not one of the allowed values.
+    InvalidChoice {
+        name: String,
+        value: String,
+        allowed: Vec<String>,
+    },
+}
+
+impl Display for ParameterError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParameterError::UndefinedProperty {
+                parameter,
+                property,
+            } => write!(
+                f,
+                "Parameter \"{}\" refers to the undefined property \"{}\".",
+                parameter, property
+            ),
+            ParameterError::CircularReference { parameter, chain } => write!(
+                f,
+                "Circular reference in parameter \"{}\": {}.",
+                parameter,
+                chain.join(" -> ")
+            ),
+            ParameterError::UndefinedFlag { name } => write!(f, "Undefined flag \"{}\".", name),
+            ParameterError::ConflictingGroupOptions { group, options } => write!(
+                f,
+                "Only one option of group \"{}\" can be enabled, but found: {}.",
+                group,
+                options.join(", ")
+            ),
+            ParameterError::InvalidChoice {
+                name,
+                value,
+                allowed,
+            } => write!(
+                f,
+                "The value \"{}\" of property \"{}\" is not one of: {}.",
+                value,
+                name,
+                allowed.join(", ")
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParameterError {}
+
+/// A group option is passed as a boolean value (i.e., whether it is checked),
+/// and a choice is passed as the selected value.
+impl From<PropertyValue> for DependencyParameterValue {
+    fn from(value: PropertyValue) -> Self {
+        match value {
+            PropertyValue::String(value) => DependencyParameterValue::String(value),
+            PropertyValue::Number(value) => DependencyParameterValue::Number(value),
+            PropertyValue::Flag(value) => DependencyParameterValue::Bool(value),
+            PropertyValue::Group(_, checked) => DependencyParameterValue::Bool(checked),
+            PropertyValue::StringList(values) => DependencyParameterValue::StringList(values),
+            PropertyValue::Choice(_, selected) => DependencyParameterValue::String(selected),
+        }
+    }
+}
+
+/// Checks that the selected value of the choice property is one of the allowed values.
+pub fn check_choice(name: &str, allowed: &[String], value: &str) -> Result<(), ParameterError> {
+    if allowed.iter().any(|item| item == value) {
+        Ok(())
+    } else {
+        Err(ParameterError::InvalidChoice {
+            name: name.to_owned(),
+            value: value.to_owned(),
+            allowed: allowed.to_vec(),
+        })
+    }
+}
+
+/// Follows the chain of `From` references of the parameter,
+/// see the section "Parameter Inheritance".
+fn resolve_parameter_value(
+    parameter: &str,
+    value: &DependencyParameterValue,
+    parent_properties: &HashMap<String, DependencyParameterValue>,
+) -> Result<DependencyParameterValue, ParameterError> {
+    let mut chain: Vec<String> = vec![];
+    let mut current = value;
+
+    while let DependencyParameterValue::From(property) = current {
+        if chain.contains(property) {
+            chain.push(property.to_owned());
+            return Err(ParameterError::CircularReference {
+                parameter: parameter.to_owned(),
+                chain,
+            });
+        }
+        chain.push(property.to_owned());
+
+        current =
+            parent_properties
+                .get(property)
+                .ok_or_else(|| ParameterError::UndefinedProperty {
+                    parameter: parameter.to_owned(),
+                    property: property.to_owned(),
+                })?;
+    }
+
+    Ok(current.clone())
+}
+
+/// Substitutes the `From` parameters with the values of the properties of the parent module,
+/// the result contains no `From` value.
+///
+/// The properties of the parent module are given as parameter values, because they
+/// may refer to other properties, use `DependencyParameterValue::from(PropertyValue)`
+/// to convert the resolved properties.
+pub fn resolve_parameters(
+    parameters: &HashMap<String, DependencyParameterValue>,
+    parent_properties: &HashMap<String, DependencyParameterValue>,
+) -> Result<HashMap<String, DependencyParameterValue>, ParameterError> {
+    parameters
+        .iter()
+        .map(|(name, value)| {
+            resolve_parameter_value(name, value, parent_properties)
+                .map(|value| (name.to_owned(), value))
+        })
+        .collect()
+}
+
+/// The union of the flags (including the group options) requested for a module
+/// across the dependency graph, see the section "Flag Unification" of the crate.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct UnifiedFlags {
+    pub enabled: BTreeSet<String>,
+}
+
+impl UnifiedFlags {
+    /// The requests should be resolved by `resolve_parameters` first,
+    /// the `From`, string and number parameters are ignored.
+    pub fn from_requests<'a>(
+        requests: impl IntoIterator<Item = &'a HashMap<String, DependencyParameterValue>>,
+    ) -> Self {
+        let enabled = requests
+            .into_iter()
+            .flat_map(|parameters| parameters.iter())
+            .filter(|(_, value)| matches!(value, DependencyParameterValue::Bool(true)))
+            .map(|(name, _)| name.to_owned())
+            .collect();
+
+        Self { enabled }
+    }
+
+    pub fn is_enabled(&self, name: &str) -> bool {
+        self.enabled.contains(name)
+    }
+
+    /// Returns the build configuration of the module, i.e., the declared properties
+    /// (with the default values) with the unified flags enabled.
+    ///
+    /// If an option of a group is enabled, the other options of the group are unchecked,
+    /// otherwise the group keeps the default selection.
+    /// The selected values of the choice properties are checked, see `check_choice`.
+    pub fn apply(
+        &self,
+        declared_properties: &HashMap<String, PropertyValue>,
+    ) -> Result<HashMap<String, PropertyValue>, ParameterError> {
+        let mut enabled_options: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+
+        for name in &self.enabled {
+            match declared_properties.get(name) {
+                Some(PropertyValue::Flag(_)) => {}
+                Some(PropertyValue::Group(group, _)) => {
+                    enabled_options
+                        .entry(group.as_str())
+                        .or_default()
+                        .push(name.to_owned());
+                }
+                _ => {
+                    return Err(ParameterError::UndefinedFlag {
+                        name: name.to_owned(),
+                    })
+                }
+            }
+        }
+
+        if let Some((group, options)) = enabled_options
+            .iter()
+            .find(|(_, options)| options.len() > 1)
+        {
+            return Err(ParameterError::ConflictingGroupOptions {
+                group: group.to_string(),
+                options: options.to_owned(),
+            });
+        }
+
+        for (name, value) in declared_properties {
+            if let PropertyValue::Choice(allowed, selected) = value {
+                check_choice(name, allowed, selected)?;
+            }
+        }
+
+        let properties = declared_properties
+            .iter()
+            .map(|(name, value)| {
+                let value = match value {
+                    PropertyValue::Flag(default) => {
+                        PropertyValue::Flag(*default || self.is_enabled(name))
+                    }
+                    PropertyValue::Group(group, default) => {
+                        let checked = if enabled_options.contains_key(group.as_str()) {
+                            self.is_enabled(name)
+                        } else {
+                            *default
+                        };
+                        PropertyValue::Group(group.to_owned(), checked)
+                    }
+                    _ => value.clone(),
+                };
+                (name.to_owned(), value)
+            })
+            .collect();
+
+        Ok(properties)
+    }
+}
+
+/// The string, number or string list parameter of a module is requested with different values
+/// by the dependents, see the section "Dependency Parameter Conflicts" of the crate.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ParameterConflict {
+    pub module_name: String,
+
+    pub parameter: String,
+
+    /// The names of the dependents and the requested values, in the order of the requests.
+    pub requests: Vec<(String, DependencyParameterValue)>,
+}
+
+fn format_parameter_value(value: &DependencyParameterValue) -> String {
+    match value {
+        DependencyParameterValue::String(value) => format!("{:?}", value),
+        DependencyParameterValue::Number(value) => value.to_string(),
+        DependencyParameterValue::Bool(value) => value.to_string(),
+        DependencyParameterValue::StringList(values) => format!("{:?}", values),
+        DependencyParameterValue::From(property) => format!("from({:?})", property),
+    }
+}
+
+/// Formats the conflict as a multi-line diagnostic, e.g.:
+///
+/// ```text
+/// Conflicting values of parameter "level" for module "common_module":
+///   "module_a" requests 1
+///   "module_b" requests 2
+/// ```
+impl Display for ParameterConflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Conflicting values of parameter \"{}\" for module \"{}\":",
+            self.parameter, self.module_name
+        )?;
+        for (dependent, value) in &self.requests {
+            write!(
+                f,
+                "\n  \"{}\" requests {}",
+                dependent,
+                format_parameter_value(value)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParameterConflict {}
+
+/// Checks the string, number and string list parameters requested by the dependents (i.e., the pairs of
+/// the dependent name and the parameters) for the same module, returns the conflicts
+/// in the alphabetical order of the parameter names.
+///
+/// The requests should be resolved by `resolve_parameters` first, the `From` and
+/// boolean parameters are ignored (the flags are unified, see `UnifiedFlags`).
+pub fn check_parameter_conflicts(
+    module_name: &str,
+    requests: &[(&str, &HashMap<String, DependencyParameterValue>)],
+) -> Result<(), Vec<ParameterConflict>> {
+    let mut values: BTreeMap<&str, Vec<(String, DependencyParameterValue)>> = BTreeMap::new();
+
+    for (dependent, parameters) in requests {
+        for (name, value) in parameters.iter() {
+            if matches!(
+                value,
+                DependencyParameterValue::String(_)
+                    | DependencyParameterValue::Number(_)
+                    | DependencyParameterValue::StringList(_)
+            ) {
+                values
+                    .entry(name.as_str())
+                    .or_default()
+                    .push((dependent.to_string(), value.clone()));
+            }
+        }
+    }
+
+    let conflicts = values
+        .into_iter()
+        .filter(|(_, requests)| requests.iter().any(|(_, value)| *value != requests[0].1))
+        .map(|(parameter, requests)| ParameterConflict {
+            module_name: module_name.to_owned(),
+            parameter: parameter.to_owned(),
+            requests,
+        })
+        .collect::<Vec<_>>();
+
+    if conflicts.is_empty() {
+        Ok(())
+    } else {
+        Err(conflicts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        parameter::{
+            check_parameter_conflicts, resolve_parameters, ParameterConflict, ParameterError,
+            UnifiedFlags,
+        },
+        DependencyParameterValue, PropertyValue,
+    };
+
+    #[test]
+    fn test_resolve_parameters() {
+        let mut parent_properties = HashMap::new();
+        parent_properties.insert(
+            "enable_fast".to_owned(),
+            DependencyParameterValue::from(PropertyValue::Flag(true)),
+        );
+        parent_properties.insert(
+            "use_simd".to_owned(),
+            DependencyParameterValue::From("enable_fast".to_owned()),
+        );
+        parent_properties.insert(
+            "loop_a".to_owned(),
+            DependencyParameterValue::From("loop_b".to_owned()),
+        );
+        parent_properties.insert(
+            "loop_b".to_owned(),
+            DependencyParameterValue::From("loop_a".to_owned()),
+        );
+
+        let mut parameters = HashMap::new();
+        parameters.insert(
+            "enable_simd".to_owned(),
+            DependencyParameterValue::From("use_simd".to_owned()),
+        );
+        parameters.insert("level".to_owned(), DependencyParameterValue::Number(2));
+
+        let mut expected = HashMap::new();
+        expected.insert(
+            "enable_simd".to_owned(),
+            DependencyParameterValue::Bool(true),
+        );
+        expected.insert("level".to_owned(), DependencyParameterValue::Number(2));
+        assert_eq!(
+            resolve_parameters(&parameters, &parent_properties),
+            Ok(expected)
+        );
+
+        let mut parameters = HashMap::new();
+        parameters.insert(
+            "name".to_owned(),
+            DependencyParameterValue::From("missing".to_owned()),
+        );
+        assert_eq!(
+            resolve_parameters(&parameters, &parent_properties),
+            Err(ParameterError::UndefinedProperty {
+                parameter: "name".to_owned(),
+                property: "missing".to_owned()
+            })
+        );
+
+        let mut parameters = HashMap::new();
+        parameters.insert(
+            "name".to_owned(),
+            DependencyParameterValue::From("loop_a".to_owned()),
+        );
+        assert_eq!(
+            resolve_parameters(&parameters, &parent_properties),
+            Err(ParameterError::CircularReference {
+                parameter: "name".to_owned(),
+                chain: vec![
+                    "loop_a".to_owned(),
+                    "loop_b".to_owned(),
+                    "loop_a".to_owned()
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn test_unify_flags() {
+        let mut declared = HashMap::new();
+        declared.insert("flag_x".to_owned(), PropertyValue::Flag(false));
+        declared.insert("flag_y".to_owned(), PropertyValue::Flag(false));
+        declared.insert("flag_z".to_owned(), PropertyValue::Flag(false));
+        declared.insert(
+            "backend_gl".to_owned(),
+            PropertyValue::Group("backend".to_owned(), true),
+        );
+        declared.insert(
+            "backend_vk".to_owned(),
+            PropertyValue::Group("backend".to_owned(), false),
+        );
+        declared.insert("level".to_owned(), PropertyValue::Number(1));
+
+        let mut module_a = HashMap::new();
+        module_a.insert("flag_x".to_owned(), DependencyParameterValue::Bool(true));
+        module_a.insert("flag_z".to_owned(), DependencyParameterValue::Bool(false));
+
+        let mut module_b = HashMap::new();
+        module_b.insert("flag_y".to_owned(), DependencyParameterValue::Bool(true));
+        module_b.insert(
+            "backend_vk".to_owned(),
+            DependencyParameterValue::Bool(true),
+        );
+        module_b.insert("level".to_owned(), DependencyParameterValue::Number(2));
+
+        let unified = UnifiedFlags::from_requests([&module_a, &module_b]);
+        assert_eq!(
+            unified.enabled.iter().collect::<Vec<_>>(),
+            vec!["backend_vk", "flag_x", "flag_y"]
+        );
+
+        let properties = unified.apply(&declared).unwrap();
+        assert_eq!(properties["flag_x"], PropertyValue::Flag(true));
+        assert_eq!(properties["flag_y"], PropertyValue::Flag(true));
+        assert_eq!(properties["flag_z"], PropertyValue::Flag(false));
+        assert_eq!(
+            properties["backend_gl"],
+            PropertyValue::Group("backend".to_owned(), false)
+        );
+        assert_eq!(
+            properties["backend_vk"],
+            PropertyValue::Group("backend".to_owned(), true)
+        );
+        assert_eq!(properties["level"], PropertyValue::Number(1));
+
+        // the default selection of the group is kept
+        let properties = UnifiedFlags::from_requests([&module_a])
+            .apply(&declared)
+            .unwrap();
+        assert_eq!(
+            properties["backend_gl"],
+            PropertyValue::Group("backend".to_owned(), true)
+        );
+
+        let mut module_c = HashMap::new();
+        module_c.insert(
+            "backend_gl".to_owned(),
+            DependencyParameterValue::Bool(true),
+        );
+        assert_eq!(
+            UnifiedFlags::from_requests([&module_b, &module_c]).apply(&declared),
+            Err(ParameterError::ConflictingGroupOptions {
+                group: "backend".to_owned(),
+                options: vec!["backend_gl".to_owned(), "backend_vk".to_owned()]
+            })
+        );
+
+        let mut module_d = HashMap::new();
+        module_d.insert("level".to_owned(), DependencyParameterValue::Bool(true));
+        assert_eq!(
+            UnifiedFlags::from_requests([&module_d]).apply(&declared),
+            Err(ParameterError::UndefinedFlag {
+                name: "level".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn test_choice_and_string_list_properties() {
+        let backends = vec!["cpu".to_owned(), "cuda".to_owned()];
+        assert_eq!(
+            DependencyParameterValue::from(PropertyValue::Choice(
+                backends.clone(),
+                "cuda".to_owned()
+            )),
+            DependencyParameterValue::String("cuda".to_owned())
+        );
+        assert_eq!(
+            DependencyParameterValue::from(PropertyValue::StringList(backends.clone())),
+            DependencyParameterValue::StringList(backends.clone())
+        );
+
+        // the lists are compared item by item, "cpu,cuda" is not ["cpu", "cuda"].
+        let mut module_a = HashMap::new();
+        module_a.insert(
+            "backends".to_owned(),
+            DependencyParameterValue::StringList(backends.clone()),
+        );
+        let mut module_b = HashMap::new();
+        module_b.insert(
+            "backends".to_owned(),
+            DependencyParameterValue::StringList(vec!["cpu,cuda".to_owned()]),
+        );
+        assert!(
+            check_parameter_conflicts("common", &[("a", &module_a), ("a2", &module_a)]).is_ok()
+        );
+        let conflicts =
+            check_parameter_conflicts("common", &[("a", &module_a), ("b", &module_b)]).unwrap_err();
+        assert_eq!(
+            conflicts[0].to_string(),
+            "Conflicting values of parameter \"backends\" for module \"common\":
+  \"a\" requests [\"cpu\", \"cuda\"]
+  \"b\" requests [\"cpu,cuda\"]"
+        );
+
+        let mut declared = HashMap::new();
+        declared.insert(
+            "backend".to_owned(),
+            PropertyValue::Choice(backends.clone(), "cpu".to_owned()),
+        );
+        declared.insert(
+            "formats".to_owned(),
+            PropertyValue::StringList(vec!["png".to_owned()]),
+        );
+        assert_eq!(
+            UnifiedFlags::default().apply(&declared),
+            Ok(declared.clone())
+        );
+
+        declared.insert(
+            "backend".to_owned(),
+            PropertyValue::Choice(backends.clone(), "metal".to_owned()),
+        );
+        let error = UnifiedFlags::default().apply(&declared).unwrap_err();
+        assert_eq!(
+            error,
+            ParameterError::InvalidChoice {
+                name: "backend".to_owned(),
+                value: "metal".to_owned(),
+                allowed: backends
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "The value \"metal\" of property \"backend\" is not one of: cpu, cuda."
+        );
+    }
+
+    #[test]
+    fn test_check_parameter_conflicts() {
+        let mut module_a = HashMap::new();
+        module_a.insert("level".to_owned(), DependencyParameterValue::Number(1));
+        module_a.insert(
+            "name".to_owned(),
+            DependencyParameterValue::String("abc".to_owned()),
+        );
+        module_a.insert("flag_x".to_owned(), DependencyParameterValue::Bool(true));
+
+        let mut module_b = HashMap::new();
+        module_b.insert("level".to_owned(), DependencyParameterValue::Number(2));
+        module_b.insert(
+            "name".to_owned(),
+            DependencyParameterValue::String("abc".to_owned()),
+        );
+        module_b.insert("flag_x".to_owned(), DependencyParameterValue::Bool(false));
+
+        assert_eq!(
+            check_parameter_conflicts("common_module", &[("module_a", &module_a)]),
+            Ok(())
+        );
+
+        let conflicts = check_parameter_conflicts(
+            "common_module",
+            &[("module_a", &module_a), ("module_b", &module_b)],
+        )
+        .unwrap_err();
+        assert_eq!(
+            conflicts,
+            vec![ParameterConflict {
+                module_name: "common_module".to_owned(),
+                parameter: "level".to_owned(),
+                requests: vec![
+                    ("module_a".to_owned(), DependencyParameterValue::Number(1)),
+                    ("module_b".to_owned(), DependencyParameterValue::Number(2))
+                ]
+            }]
+        );
+        assert_eq!(
+            conflicts[0].to_string(),
+            "Conflicting values of parameter \"level\" for module \"common_module\":
+  \"module_a\" requests 1
+  \"module_b\" requests 2"
+        );
+    }
+}
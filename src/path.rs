@@ -0,0 +1,231 @@
+// Copyright (c) 2025 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions.
+// For more details, see the LICENSE, LICENSE.additional, and CONTRIBUTING files.
+
+// Local Paths
+// -----------
+//
+// The path of a local dependency (i.e., `DependencyLocal::path`) is relative to
+// the application (or module project) folder, and it is written with '/' as
+// the separator on all platforms, e.g. "../modules/sha2".
+//
+// - '\' is accepted as a separator, e.g. "..\modules\sha2".
+// - A path starting with "~/" (or the path "~") is relative to the home directory
+//   of the current user, the form "~USER" is not supported.
+// - An absolute path starts with '/' or a drive letter, e.g. "C:/modules/sha2".
+//
+// Since local modules lack version information, two local dependencies of the same
+// module must have the same path (see the section "About the Version of Shared Modules"
+// of the crate), the paths are compared in the normalized form, i.e., the separators
+// are '/', the repeated separators, the trailing separator and "." are removed,
+// and ".." is resolved lexically, e.g. "./modules//../sha2/" is "sha2".
+// Symbolic links are not followed.
+
+use std::{
+    fmt::Display,
+    path::{Component, Path, PathBuf},
+};
+
+use crate::DependencyLocal;
+
+pub const HOME_DIRECTORY_PREFIX: &str = "~";
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum PathError {
+    /// The path starts with "~USER".
+    UnsupportedTilde { path: String },
+
+    /// The home directory of the current user can not be determined.
+    HomeDirectoryNotFound,
+}
+
+impl Display for PathError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PathError::UnsupportedTilde { path } => write!(
+                f,
+                "Unsupported path \"{}\", only \"~/\" is supported for the home directory.",
+                path
+            ),
+            PathError::HomeDirectoryNotFound => {
+                f.write_str("Can not determine the home directory of the current user.")
+            }
+        }
+    }
+}
+
+impl std::error::Error for PathError {}
+
+/// Returns the root part of the path (with '/' separators), e.g. "/", "C:/" and "~/",
+/// or an empty string for relative paths.
+fn get_root(path: &str) -> &str {
+    let bytes = path.as_bytes();
+    if path.starts_with('/') {
+        &path[..1]
+    } else if bytes.len() >= 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':' {
+        if bytes.get(2) == Some(&b'/') {
+            &path[..3]
+        } else {
+            &path[..2]
+        }
+    } else if path == HOME_DIRECTORY_PREFIX {
+        path
+    } else if path.starts_with("~/") {
+        &path[..2]
+    } else {
+        ""
+    }
+}
+
+/// Normalizes the path lexically, see the section "Local Paths".
+pub fn normalize_path(path: &str) -> String {
+    let path = path.replace('\\', "/");
+    let root = get_root(&path);
+
+    let mut parts: Vec<&str> = vec![];
+    for part in path[root.len()..].split('/') {
+        match part {
+            "" | "." => {}
+            ".." => {
+                if parts.last().is_some_and(|last| *last != "..") {
+                    parts.pop();
+                } else if root.is_empty() {
+                    parts.push(part);
+                }
+                // ".." of the root is the root itself.
+            }
+            _ => parts.push(part),
+        }
+    }
+
+    let relative = parts.join("/");
+    match (root, relative.is_empty()) {
+        ("", true) => ".".to_owned(),
+        ("~/", true) => HOME_DIRECTORY_PREFIX.to_owned(),
+        (_, true) => root.to_owned(),
+        (_, false) => format!("{}{}", root, relative),
+    }
+}
+
+/// Resolves "." and ".." of the path lexically.
+fn normalize_components(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if matches!(
+                    normalized.components().next_back(),
+                    Some(Component::Normal(_))
+                ) {
+                    normalized.pop();
+                } else if !normalized.has_root() {
+                    normalized.push(component);
+                }
+            }
+            _ => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+fn get_home_directory() -> Result<PathBuf, PathError> {
+    ["HOME", "USERPROFILE"]
+        .iter()
+        .filter_map(std::env::var_os)
+        .find(|value| !value.is_empty())
+        .map(PathBuf::from)
+        .ok_or(PathError::HomeDirectoryNotFound)
+}
+
+impl DependencyLocal {
+    /// Returns the normalized path, see the section "Local Paths".
+    pub fn get_normalized_path(&self) -> String {
+        normalize_path(&self.path)
+    }
+
+    /// Returns the full path of the dependency with the platform separators,
+    /// the relative path is resolved against `base_dir` (i.e., the application
+    /// or module project folder), see the section "Local Paths".
+    pub fn resolve(&self, base_dir: &Path) -> Result<PathBuf, PathError> {
+        let path = self.get_normalized_path();
+
+        let full_path = if path == HOME_DIRECTORY_PREFIX {
+            get_home_directory()?
+        } else if let Some(relative) = path.strip_prefix("~/") {
+            get_home_directory()?.join(relative)
+        } else if path.starts_with(HOME_DIRECTORY_PREFIX) {
+            return Err(PathError::UnsupportedTilde {
+                path: self.path.to_owned(),
+            });
+        } else {
+            // `join` keeps the absolute path as it is.
+            base_dir.join(path)
+        };
+
+        Ok(normalize_components(&full_path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, path::Path};
+
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        path::{normalize_path, PathError},
+        DependencyCondition, DependencyKind, DependencyLocal,
+    };
+
+    fn local(path: &str) -> DependencyLocal {
+        DependencyLocal {
+            path: path.to_owned(),
+            parameters: HashMap::new(),
+            condition: DependencyCondition::True,
+            kind: DependencyKind::Normal,
+            optional: false,
+        }
+    }
+
+    #[test]
+    fn test_normalize_path() {
+        assert_eq!(normalize_path("./modules//../sha2/"), "sha2");
+        assert_eq!(normalize_path("..\\modules\\sha2"), "../modules/sha2");
+        assert_eq!(normalize_path("../../a/../b"), "../../b");
+        assert_eq!(normalize_path("."), ".");
+        assert_eq!(normalize_path("a/.."), ".");
+        assert_eq!(normalize_path("/../usr/./lib/"), "/usr/lib");
+        assert_eq!(normalize_path("C:\\modules\\..\\sha2"), "C:/sha2");
+        assert_eq!(normalize_path("~/projects/../sha2"), "~/sha2");
+        assert_eq!(normalize_path("~/.."), "~");
+        assert_eq!(normalize_path("~user/sha2"), "~user/sha2");
+
+        assert_eq!(
+            local("modules/./sha2").get_normalized_path(),
+            local("modules/sha2/").get_normalized_path()
+        );
+    }
+
+    #[test]
+    fn test_resolve_local_path() {
+        let base_dir = Path::new("/home/user/app");
+        assert_eq!(
+            local("../modules/sha2").resolve(base_dir),
+            Ok(Path::new("/home/user/modules/sha2").to_path_buf())
+        );
+        assert_eq!(local("./sha2").resolve(base_dir), Ok(base_dir.join("sha2")));
+        assert_eq!(
+            local("/opt/sha2").resolve(base_dir),
+            Ok(Path::new("/opt/sha2").to_path_buf())
+        );
+        assert_eq!(
+            local("~user/sha2").resolve(base_dir),
+            Err(PathError::UnsupportedTilde {
+                path: "~user/sha2".to_owned()
+            })
+        );
+    }
+}
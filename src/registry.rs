@@ -0,0 +1,203 @@
+// Copyright (c) 2025 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions.
+// For more details, see the LICENSE, LICENSE.additional, and CONTRIBUTING files.
+
+// Registries
+// ----------
+//
+// A registry is a Git repository providing the module index, the shared modules
+// (i.e., `ModuleDependency::Share`) are resolved from registries.
+// Users can add multiple registries, e.g.:
+//
+// ```ason
+// registries: [
+//     {
+//         name: "central"
+//         url: "https://github.com/hemashushu/xiaoxuan-registry.git"
+//     }
+//     {
+//         name: "corp"
+//         url: "https://git.example.com/registry.git"
+//         priority: 10
+//         namespace: Option::Some("corp")
+//         public_key: Option::Some("ed25519:d75a9801...")
+//     }
+// ]
+// ```
+//
+// Resolution Order
+// ----------------
+//
+// - A module name with a namespace prefix, e.g. "corp/module_name", is resolved
+//   only from the registries with the namespace "corp".
+// - Other module names are resolved from the registries without a namespace.
+// - The registries are tried in the descending order of priority, registries with
+//   the same priority are tried in the order of declaration.
+
+use std::{cmp::Reverse, fmt::Display};
+
+use serde::{Deserialize, Serialize};
+
+use crate::signing::PublicKey;
+
+#[cfg(feature = "ed25519")]
+use crate::signing::{SignatureError, SignedIndexEntry};
+
+pub const NAMESPACE_SEPARATOR: char = '/';
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename = "registry")]
+pub struct RegistryConfig {
+    /// The unique name of the registry, e.g. "central".
+    pub name: String,
+
+    /// The Git repository URL of the module index.
+    pub url: String,
+
+    /// Optional.
+    /// The default value is 0, the registry with higher priority is tried first.
+    #[serde(default)]
+    pub priority: u32,
+
+    /// Optional.
+    /// The modules named "NAMESPACE/module_name" are resolved only from
+    /// the registries with this namespace.
+    pub namespace: Option<String>,
+
+    /// Optional.
+    /// The public key which the index entries are signed with,
+    /// see the module `signing`.
+    pub public_key: Option<PublicKey>,
+}
+
+/// It is serialized as a list of `RegistryConfig`.
+#[derive(Debug, PartialEq, Eq, Clone, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct RegistrySet {
+    pub registries: Vec<RegistryConfig>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum RegistryError {
+    DuplicateName { name: String },
+}
+
+impl Display for RegistryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RegistryError::DuplicateName { name } => {
+                write!(f, "Duplicate registry name \"{}\".", name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for RegistryError {}
+
+/// Splits a module name into the namespace and the name,
+/// e.g. "corp/module_name" to `(Some("corp"), "module_name")`.
+pub fn split_module_name(full_name: &str) -> (Option<&str>, &str) {
+    match full_name.split_once(NAMESPACE_SEPARATOR) {
+        Some((namespace, name)) => (Some(namespace), name),
+        None => (None, full_name),
+    }
+}
+
+#[cfg(feature = "ed25519")]
+impl RegistryConfig {
+    /// Verifies the signature of the index entry, the entries of the registry
+    /// without a public key are not verified.
+    pub fn verify_entry(&self, entry: &SignedIndexEntry) -> Result<(), SignatureError> {
+        match &self.public_key {
+            Some(public_key) => entry.verify(public_key),
+            None => Ok(()),
+        }
+    }
+}
+
+impl RegistrySet {
+    pub fn new(registries: Vec<RegistryConfig>) -> Result<Self, RegistryError> {
+        for (index, registry) in registries.iter().enumerate() {
+            if registries[..index]
+                .iter()
+                .any(|item| item.name == registry.name)
+            {
+                return Err(RegistryError::DuplicateName {
+                    name: registry.name.clone(),
+                });
+            }
+        }
+
+        Ok(Self { registries })
+    }
+
+    pub fn get_registry(&self, name: &str) -> Option<&RegistryConfig> {
+        self.registries.iter().find(|item| item.name == name)
+    }
+
+    /// Returns the registries to try for the module, see the section "Resolution Order".
+    pub fn get_resolution_order(&self, full_module_name: &str) -> Vec<&RegistryConfig> {
+        let (namespace, _) = split_module_name(full_module_name);
+
+        let mut registries = self
+            .registries
+            .iter()
+            .filter(|item| item.namespace.as_deref() == namespace)
+            .collect::<Vec<_>>();
+
+        // the sorting is stable, so the order of declaration is kept for the same priority.
+        registries.sort_by_key(|item| Reverse(item.priority));
+        registries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::registry::{split_module_name, RegistryConfig, RegistryError, RegistrySet};
+
+    fn registry(name: &str, priority: u32, namespace: Option<&str>) -> RegistryConfig {
+        RegistryConfig {
+            name: name.to_owned(),
+            url: format!("https://example.com/{}.git", name),
+            priority,
+            namespace: namespace.map(|item| item.to_owned()),
+            public_key: None,
+        }
+    }
+
+    #[test]
+    fn test_resolution_order() {
+        let set = RegistrySet::new(vec![
+            registry("central", 0, None),
+            registry("mirror", 0, None),
+            registry("fast", 5, None),
+            registry("corp", 0, Some("corp")),
+        ])
+        .unwrap();
+
+        let names = |module_name: &str| {
+            set.get_resolution_order(module_name)
+                .iter()
+                .map(|item| item.name.as_str())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(names("sha2"), vec!["fast", "central", "mirror"]);
+        assert_eq!(names("corp/sha2"), vec!["corp"]);
+        assert_eq!(names("other/sha2"), Vec::<&str>::new());
+
+        assert_eq!(split_module_name("corp/sha2"), (Some("corp"), "sha2"));
+        assert_eq!(split_module_name("sha2"), (None, "sha2"));
+
+        assert_eq!(
+            RegistrySet::new(vec![registry("a", 0, None), registry("a", 1, None)]),
+            Err(RegistryError::DuplicateName {
+                name: "a".to_owned()
+            })
+        );
+    }
+}
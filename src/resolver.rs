@@ -0,0 +1,1169 @@
+// Copyright (c) 2025 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions.
+// For more details, see the LICENSE, LICENSE.additional, and CONTRIBUTING files.
+
+// Dependency Resolution
+// ---------------------
+//
+// The resolver walks the dependency tree from the root module (i.e., the application)
+// and produces the dependency graph and the build configuration of each module,
+// applying the rules of the crate in the following order:
+//
+// 1. The dependency is skipped if it is a `Runtime` module, a dev dependency of
+//    a non-root module (or of the root module without `include_dev_dependencies`),
+//    its condition evaluates to `false`, or it is optional and not activated,
+//    see `DependencyCondition::evaluate` and `FeatureActivation`.
+// 2. The root's overrides replace the sources, see `DependencyOverrides`.
+// 3. The `From` parameters are resolved, see `parameter::resolve_parameters`.
+// 4. The requests of the same module are merged, see the sections "Version Conflicts",
+//    "Zero Major Version", "Flag Unification" and "Dependency Parameter Conflicts"
+//    of the crate:
+//    - the highest available version satisfying all requirements is selected for
+//      shared modules, see `version::select_version`, the yanked versions are
+//      excluded, and the deprecated versions are reported in `Resolution::warnings`,
+//      see the section "Yanked and Deprecated Versions" of the module `signing`;
+//    - the other modules must have the same source (i.e., the path, URL and
+//      Git reference, or the archive);
+//    - the flags are unified and the string and number parameters must not conflict.
+// 5. The graph must not contain cycles.
+//
+// Since the build configuration of a module decides which dependencies of the module
+// are included (i.e., by conditions and optional dependencies), and the selected version
+// decides its manifest, the tree is walked repeatedly until the selected versions and
+// the build configurations no longer change.
+
+use std::{
+    collections::{BTreeMap, HashMap, VecDeque},
+    fmt::Display,
+};
+
+use crate::{
+    condition::EvalError,
+    graph::{DependencyEdge, DependencyGraph, DependencyNode, GraphError, ModuleId},
+    overrides::DependencyOverrides,
+    parameter::{
+        check_choice, check_parameter_conflicts, resolve_parameters, ParameterConflict,
+        ParameterError, UnifiedFlags,
+    },
+    sbom::ModuleMetadata,
+    signing::{DeprecationNotice, IndexEntry},
+    version::{select_version, VersionConflict, VersionRequirement},
+    DependencyKind, DependencyParameterValue, EffectiveVersion, FeatureActivation,
+    ModuleDependency, PropertyValue, TargetInfo,
+};
+
+/// The maximum number of walks before the resolution is considered unstable.
+const MAX_RESOLVE_ITERATIONS: usize = 32;
+
+/// The part of a module manifest which is used by the resolver.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct ModuleManifest {
+    pub name: String,
+
+    /// The declared properties with the default values.
+    pub properties: HashMap<String, PropertyValue>,
+
+    pub dependencies: HashMap<String, ModuleDependency>,
+
+    pub features: FeatureActivation,
+
+    /// The license, authors and the source repository, see the module `sbom`.
+    pub metadata: ModuleMetadata,
+}
+
+/// Provides the available versions and the manifests of modules, e.g. from
+/// the registries, the local file system, or the offline cache.
+pub trait ModuleProvider {
+    /// Returns the available versions of the shared module.
+    fn get_available_versions(&self, module_name: &str) -> Vec<EffectiveVersion>;
+
+    /// Returns the manifest of the module, the version is `Some` for shared modules only.
+    /// Returns `None` if the module is not found.
+    fn get_manifest(
+        &self,
+        module_name: &str,
+        source: &ModuleDependency,
+        version: Option<&EffectiveVersion>,
+    ) -> Option<ModuleManifest>;
+
+    /// Returns the index entry of the shared module, which carries the yanked flag
+    /// and the deprecation notice.
+    /// The default implementation returns `None`, i.e., no versions are yanked or deprecated.
+    fn get_index_entry(
+        &self,
+        _module_name: &str,
+        _version: &EffectiveVersion,
+    ) -> Option<IndexEntry> {
+        None
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ResolveOptions {
+    /// The target which the conditions are evaluated against.
+    pub target: TargetInfo,
+
+    /// The overrides of the root module, including the local overrides
+    /// (see `DependencyOverrides::with_local_overrides`).
+    pub overrides: DependencyOverrides,
+
+    /// Whether to include the dev dependencies of the root module (e.g., for unit tests),
+    /// the dev dependencies of other modules are never included.
+    pub include_dev_dependencies: bool,
+}
+
+impl Default for ResolveOptions {
+    fn default() -> Self {
+        Self {
+            target: TargetInfo::host(),
+            overrides: DependencyOverrides::default(),
+            include_dev_dependencies: false,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Resolution {
+    /// The root module is the node with the source `ModuleDependency::Current`.
+    pub graph: DependencyGraph,
+
+    /// The build configuration (i.e., the unified properties) of each module,
+    /// except the root module.
+    pub properties: BTreeMap<String, HashMap<String, PropertyValue>>,
+
+    /// The metadata of each module (including the root module), see the module `sbom`.
+    pub metadata: BTreeMap<String, ModuleMetadata>,
+
+    /// The problems which do not fail the resolution, ordered by the module name.
+    pub warnings: Vec<ResolveWarning>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum ResolveWarning {
+    /// The selected version of the shared module is deprecated.
+    Deprecated {
+        module_name: String,
+        version: EffectiveVersion,
+        notice: DeprecationNotice,
+    },
+}
+
+impl Display for ResolveWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ResolveWarning::Deprecated {
+                module_name,
+                version,
+                notice,
+            } => {
+                write!(
+                    f,
+                    "Module \"{}\" version \"{}\" is deprecated: {}",
+                    module_name, version, notice.message
+                )?;
+                match (&notice.replacement, &notice.replacement_version) {
+                    (Some(replacement), Some(version)) => {
+                        write!(
+                            f,
+                            " (use \"{}\" version \"{}\" instead)",
+                            replacement, version
+                        )
+                    }
+                    (Some(replacement), None) => write!(f, " (use \"{}\" instead)", replacement),
+                    (None, Some(version)) => write!(f, " (use version \"{}\" instead)", version),
+                    (None, None) => Ok(()),
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum ResolveError {
+    ModuleNotFound {
+        module_name: String,
+    },
+
+    /// The requirements are pairs of the dependent name and the requirement.
+    VersionConflict {
+        module_name: String,
+        requirements: Vec<(String, VersionRequirement)>,
+        conflict: VersionConflict,
+    },
+
+    /// The only versions satisfying the requirements are yanked,
+    /// the version is the highest one of them.
+    YankedVersion {
+        module_name: String,
+        version: EffectiveVersion,
+    },
+
+    /// The module is requested from different sources by the dependents.
+    SourceConflict {
+        module_name: String,
+        dependents: Vec<String>,
+    },
+
+    Condition {
+        module_name: String,
+        dependency_name: String,
+        error: EvalError,
+    },
+
+    /// The error of resolving or unifying the parameters of the module.
+    Parameter {
+        module_name: String,
+        error: ParameterError,
+    },
+
+    /// The parameter is not declared by the module, or the type does not match.
+    InvalidParameter {
+        module_name: String,
+        dependent: String,
+        parameter: String,
+    },
+
+    ParameterConflicts {
+        conflicts: Vec<ParameterConflict>,
+    },
+
+    Graph(GraphError),
+
+    /// The selected versions or the build configurations keep changing,
+    /// e.g., a flag disables the dependency which enables the flag.
+    Unstable,
+}
+
+impl Display for ResolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ResolveError::ModuleNotFound { module_name } => {
+                write!(f, "Module \"{}\" is not found.", module_name)
+            }
+            ResolveError::VersionConflict {
+                module_name,
+                requirements,
+                conflict,
+            } => {
+                write!(
+                    f,
+                    "Version conflict of module \"{}\": {}",
+                    module_name, conflict
+                )?;
+                for (dependent, requirement) in requirements {
+                    write!(f, "\n  \"{}\" requires \"{}\"", dependent, requirement)?;
+                }
+                Ok(())
+            }
+            ResolveError::YankedVersion {
+                module_name,
+                version,
+            } => write!(
+                f,
+                "Module \"{}\" version \"{}\" has been yanked, and no other version matches.",
+                module_name, version
+            ),
+            ResolveError::SourceConflict {
+                module_name,
+                dependents,
+            } => write!(
+                f,
+                "Module \"{}\" is required from different sources by: {}.",
+                module_name,
+                dependents.join(", ")
+            ),
+            ResolveError::Condition {
+                module_name,
+                dependency_name,
+                error,
+            } => write!(
+                f,
+                "Failed to evaluate the condition of dependency \"{}\" of module \"{}\": {}",
+                dependency_name, module_name, error
+            ),
+            ResolveError::Parameter { module_name, error } => {
+                write!(
+                    f,
+                    "Invalid parameters of module \"{}\": {}",
+                    module_name, error
+                )
+            }
+            ResolveError::InvalidParameter {
+                module_name,
+                dependent,
+                parameter,
+            } => write!(
+                f,
+                "Module \"{}\" passes the parameter \"{}\" which is not declared by module \"{}\" \
+                or has a different type.",
+                dependent, parameter, module_name
+            ),
+            ResolveError::ParameterConflicts { conflicts } => {
+                let items = conflicts
+                    .iter()
+                    .map(|conflict| conflict.to_string())
+                    .collect::<Vec<_>>();
+                f.write_str(&items.join("\n"))
+            }
+            ResolveError::Graph(error) => write!(f, "{}", error),
+            ResolveError::Unstable => f.write_str("The dependency resolution does not converge."),
+        }
+    }
+}
+
+impl std::error::Error for ResolveError {}
+
+/// A dependency declared by a dependent.
+struct Request {
+    dependent: String,
+    kind: DependencyKind,
+    dependency: ModuleDependency,
+
+    /// The resolved parameters, i.e., no `From` values.
+    parameters: HashMap<String, DependencyParameterValue>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+struct Selection {
+    source: ModuleDependency,
+    version: Option<EffectiveVersion>,
+}
+
+#[derive(Debug, PartialEq, Default)]
+struct State {
+    selections: BTreeMap<String, Selection>,
+    properties: BTreeMap<String, HashMap<String, PropertyValue>>,
+}
+
+struct Walk {
+    requests: BTreeMap<String, Vec<Request>>,
+    manifests: BTreeMap<String, ModuleManifest>,
+}
+
+struct Resolver<'a, P: ModuleProvider> {
+    root: &'a ModuleManifest,
+    provider: &'a P,
+    options: &'a ResolveOptions,
+}
+
+impl<P: ModuleProvider> Resolver<'_, P> {
+    fn select(&self, module_name: &str, requests: &[Request]) -> Result<Selection, ResolveError> {
+        let first = &requests[0].dependency;
+
+        if !requests
+            .iter()
+            .all(|request| first.is_same_source(&request.dependency))
+        {
+            return Err(ResolveError::SourceConflict {
+                module_name: module_name.to_owned(),
+                dependents: requests
+                    .iter()
+                    .map(|request| request.dependent.to_owned())
+                    .collect(),
+            });
+        }
+
+        if !matches!(first, ModuleDependency::Share(_)) {
+            return Ok(Selection {
+                source: first.clone(),
+                version: None,
+            });
+        }
+
+        let mut requirements = vec![];
+        for request in requests {
+            if let ModuleDependency::Share(share) = &request.dependency {
+                requirements.push((
+                    request.dependent.to_owned(),
+                    share.get_version_requirement().clone(),
+                ));
+            }
+        }
+
+        let requirement_values = requirements
+            .iter()
+            .map(|(_, requirement)| requirement.clone())
+            .collect::<Vec<_>>();
+
+        let (yanked, available): (Vec<_>, Vec<_>) = self
+            .provider
+            .get_available_versions(module_name)
+            .into_iter()
+            .partition(|version| {
+                self.provider
+                    .get_index_entry(module_name, version)
+                    .is_some_and(|entry| entry.yanked)
+            });
+
+        let version =
+            select_version(&requirement_values, &available).map_err(
+                |conflict| match select_version(&requirement_values, &yanked) {
+                    Ok(version)
+                        if matches!(conflict, VersionConflict::NoMatchingVersion { .. }) =>
+                    {
+                        ResolveError::YankedVersion {
+                            module_name: module_name.to_owned(),
+                            version,
+                        }
+                    }
+                    _ => ResolveError::VersionConflict {
+                        module_name: module_name.to_owned(),
+                        requirements,
+                        conflict,
+                    },
+                },
+            )?;
+
+        Ok(Selection {
+            source: first.clone(),
+            version: Some(version),
+        })
+    }
+
+    /// Returns the build configuration of the module.
+    fn unify(
+        &self,
+        module_name: &str,
+        manifest: &ModuleManifest,
+        requests: &[Request],
+    ) -> Result<HashMap<String, PropertyValue>, ResolveError> {
+        let parameter_sets = requests
+            .iter()
+            .map(|request| (request.dependent.as_str(), &request.parameters))
+            .collect::<Vec<_>>();
+        check_parameter_conflicts(module_name, &parameter_sets)
+            .map_err(|conflicts| ResolveError::ParameterConflicts { conflicts })?;
+
+        let mut properties =
+            UnifiedFlags::from_requests(requests.iter().map(|request| &request.parameters))
+                .apply(&manifest.properties)
+                .map_err(|error| ResolveError::Parameter {
+                    module_name: module_name.to_owned(),
+                    error,
+                })?;
+
+        for request in requests {
+            for (name, value) in &request.parameters {
+                match (properties.get_mut(name), value) {
+                    (
+                        Some(PropertyValue::String(current)),
+                        DependencyParameterValue::String(value),
+                    ) => *current = value.to_owned(),
+                    (
+                        Some(PropertyValue::Choice(allowed, current)),
+                        DependencyParameterValue::String(value),
+                    ) => {
+                        check_choice(name, allowed, value).map_err(|error| {
+                            ResolveError::Parameter {
+                                module_name: module_name.to_owned(),
+                                error,
+                            }
+                        })?;
+                        *current = value.to_owned();
+                    }
+                    (
+                        Some(PropertyValue::Number(current)),
+                        DependencyParameterValue::Number(value),
+                    ) => *current = *value,
+                    (
+                        Some(PropertyValue::StringList(current)),
+                        DependencyParameterValue::StringList(values),
+                    ) => *current = values.to_owned(),
+                    (
+                        Some(PropertyValue::Flag(_) | PropertyValue::Group(_, _)),
+                        DependencyParameterValue::Bool(_),
+                    ) => {}
+                    _ => {
+                        return Err(ResolveError::InvalidParameter {
+                            module_name: module_name.to_owned(),
+                            dependent: request.dependent.to_owned(),
+                            parameter: name.to_owned(),
+                        })
+                    }
+                }
+            }
+        }
+
+        Ok(properties)
+    }
+
+    /// Walks the tree with the selected versions and the build configurations
+    /// of the previous walk.
+    fn walk(&self, state: &State) -> Result<Walk, ResolveError> {
+        let mut requests: BTreeMap<String, Vec<Request>> = BTreeMap::new();
+        let mut manifests = BTreeMap::new();
+        manifests.insert(self.root.name.to_owned(), self.root.clone());
+
+        let mut queue = VecDeque::from([self.root.name.to_owned()]);
+
+        while let Some(module_name) = queue.pop_front() {
+            let manifest = manifests[&module_name].clone();
+            let is_root = module_name == self.root.name;
+
+            let properties = match state.properties.get(&module_name) {
+                Some(properties) if !is_root => properties.clone(),
+                _ => manifest.properties.clone(),
+            };
+            let parent_properties = properties
+                .iter()
+                .map(|(name, value)| {
+                    (
+                        name.to_owned(),
+                        DependencyParameterValue::from(value.clone()),
+                    )
+                })
+                .collect::<HashMap<_, _>>();
+
+            let mut names = manifest.dependencies.keys().collect::<Vec<_>>();
+            names.sort();
+
+            for name in names {
+                let dependency = &manifest.dependencies[name];
+                let (Some(condition), Some(parameters)) =
+                    (dependency.get_condition(), dependency.get_parameters())
+                else {
+                    continue;
+                };
+
+                let kind = dependency.get_kind();
+                if kind == DependencyKind::Dev
+                    && !(is_root && self.options.include_dev_dependencies)
+                {
+                    continue;
+                }
+
+                let included = condition
+                    .evaluate_for_target(&properties, &self.options.target)
+                    .map_err(|error| ResolveError::Condition {
+                        module_name: module_name.to_owned(),
+                        dependency_name: name.to_owned(),
+                        error,
+                    })?;
+                if !included
+                    || (dependency.is_optional()
+                        && !manifest.features.is_activated(name, &properties))
+                {
+                    continue;
+                }
+
+                let parameters =
+                    resolve_parameters(parameters, &parent_properties).map_err(|error| {
+                        ResolveError::Parameter {
+                            module_name: name.to_owned(),
+                            error,
+                        }
+                    })?;
+
+                let module_requests = requests.entry(name.to_owned()).or_default();
+                module_requests.push(Request {
+                    dependent: module_name.to_owned(),
+                    kind,
+                    dependency: self.options.overrides.apply(name, dependency),
+                    parameters,
+                });
+
+                if !manifests.contains_key(name) {
+                    let selection = match state.selections.get(name) {
+                        Some(selection) => selection.clone(),
+                        None => self.select(name, module_requests)?,
+                    };
+                    let manifest = self
+                        .provider
+                        .get_manifest(name, &selection.source, selection.version.as_ref())
+                        .ok_or_else(|| ResolveError::ModuleNotFound {
+                            module_name: name.to_owned(),
+                        })?;
+                    manifests.insert(name.to_owned(), manifest);
+                    queue.push_back(name.to_owned());
+                }
+            }
+        }
+
+        Ok(Walk {
+            requests,
+            manifests,
+        })
+    }
+
+    fn get_next_state(&self, walk: &Walk) -> Result<State, ResolveError> {
+        let mut state = State::default();
+
+        for (module_name, requests) in &walk.requests {
+            // a dependency on the root module is a cycle, which is reported by the graph.
+            if *module_name == self.root.name {
+                continue;
+            }
+
+            let selection = self.select(module_name, requests)?;
+            let properties = self.unify(module_name, &walk.manifests[module_name], requests)?;
+            state.selections.insert(module_name.to_owned(), selection);
+            state.properties.insert(module_name.to_owned(), properties);
+        }
+
+        Ok(state)
+    }
+
+    fn get_warnings(&self, state: &State) -> Vec<ResolveWarning> {
+        state
+            .selections
+            .iter()
+            .filter_map(|(module_name, selection)| {
+                let version = selection.version?;
+                let notice = self
+                    .provider
+                    .get_index_entry(module_name, &version)?
+                    .deprecation?;
+                Some(ResolveWarning::Deprecated {
+                    module_name: module_name.to_owned(),
+                    version,
+                    notice,
+                })
+            })
+            .collect()
+    }
+
+    fn build_graph(&self, walk: &Walk, state: &State) -> Result<DependencyGraph, ResolveError> {
+        let mut graph = DependencyGraph::new();
+        graph.add_node(DependencyNode {
+            id: ModuleId::new(&self.root.name),
+            source: ModuleDependency::Current,
+            version: None,
+        });
+
+        for (module_name, selection) in &state.selections {
+            graph.add_node(DependencyNode {
+                id: ModuleId::new(module_name),
+                source: selection.source.clone(),
+                version: selection.version,
+            });
+        }
+
+        for (module_name, requests) in &walk.requests {
+            for request in requests {
+                let requirement = match &request.dependency {
+                    ModuleDependency::Share(share) => Some(share.get_version_requirement().clone()),
+                    _ => None,
+                };
+                graph
+                    .add_edge(DependencyEdge {
+                        from: ModuleId::new(&request.dependent),
+                        to: ModuleId::new(module_name),
+                        kind: request.kind,
+                        requirement,
+                    })
+                    .map_err(ResolveError::Graph)?;
+            }
+        }
+
+        graph.topological_sort().map_err(ResolveError::Graph)?;
+        Ok(graph)
+    }
+}
+
+/// Resolves the dependency tree of the root module, see the section "Dependency Resolution".
+pub fn resolve(
+    root: &ModuleManifest,
+    provider: &impl ModuleProvider,
+    options: &ResolveOptions,
+) -> Result<Resolution, ResolveError> {
+    let resolver = Resolver {
+        root,
+        provider,
+        options,
+    };
+
+    let mut state = State::default();
+
+    for _ in 0..MAX_RESOLVE_ITERATIONS {
+        let walk = resolver.walk(&state)?;
+        let next_state = resolver.get_next_state(&walk)?;
+
+        if next_state == state {
+            let graph = resolver.build_graph(&walk, &state)?;
+            let warnings = resolver.get_warnings(&state);
+            let metadata = walk
+                .manifests
+                .into_iter()
+                .map(|(module_name, manifest)| (module_name, manifest.metadata))
+                .collect();
+            return Ok(Resolution {
+                graph,
+                properties: state.properties,
+                metadata,
+                warnings,
+            });
+        }
+
+        state = next_state;
+    }
+
+    Err(ResolveError::Unstable)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        checksum::{Checksum, ChecksumAlgorithm},
+        graph::{GraphError, ModuleId},
+        parameter::ParameterError,
+        resolver::{
+            resolve, ModuleManifest, ModuleProvider, ResolveError, ResolveOptions, ResolveWarning,
+        },
+        sbom::ModuleMetadata,
+        signing::{DeprecationNotice, IndexEntry},
+        version::VersionConflict,
+        DependencyCondition, DependencyConditionCheck, DependencyKind, DependencyLocal,
+        DependencyParameterValue, DependencyShare, EffectiveVersion, FeatureActivation,
+        ModuleDependency, PropertyValue, TargetInfo, RUNTIME_EDITION,
+    };
+
+    #[derive(Default)]
+    struct TestProvider {
+        versions: HashMap<String, Vec<EffectiveVersion>>,
+
+        /// The key is "NAME" for the modules without version, or "NAME@VERSION".
+        manifests: HashMap<String, ModuleManifest>,
+
+        /// The key is "NAME@VERSION".
+        entries: HashMap<String, IndexEntry>,
+    }
+
+    impl TestProvider {
+        fn add(&mut self, version: Option<&str>, manifest: ModuleManifest) {
+            let key = match version {
+                Some(version) => {
+                    self.versions
+                        .entry(manifest.name.to_owned())
+                        .or_default()
+                        .push(EffectiveVersion::from_version_string(version).unwrap());
+                    format!("{}@{}", manifest.name, version)
+                }
+                None => manifest.name.to_owned(),
+            };
+            self.manifests.insert(key, manifest);
+        }
+    }
+
+    impl ModuleProvider for TestProvider {
+        fn get_available_versions(&self, module_name: &str) -> Vec<EffectiveVersion> {
+            self.versions.get(module_name).cloned().unwrap_or_default()
+        }
+
+        fn get_manifest(
+            &self,
+            module_name: &str,
+            _source: &ModuleDependency,
+            version: Option<&EffectiveVersion>,
+        ) -> Option<ModuleManifest> {
+            let key = match version {
+                Some(version) => format!("{}@{}", module_name, version),
+                None => module_name.to_owned(),
+            };
+            self.manifests.get(&key).cloned()
+        }
+
+        fn get_index_entry(
+            &self,
+            module_name: &str,
+            version: &EffectiveVersion,
+        ) -> Option<IndexEntry> {
+            self.entries
+                .get(&format!("{}@{}", module_name, version))
+                .cloned()
+        }
+    }
+
+    fn manifest(name: &str, dependencies: Vec<(&str, ModuleDependency)>) -> ModuleManifest {
+        ModuleManifest {
+            name: name.to_owned(),
+            dependencies: dependencies
+                .into_iter()
+                .map(|(name, dependency)| (name.to_owned(), dependency))
+                .collect(),
+            ..ModuleManifest::default()
+        }
+    }
+
+    fn share(version: &str, parameters: Vec<(&str, DependencyParameterValue)>) -> ModuleDependency {
+        ModuleDependency::Share(Box::new(DependencyShare {
+            version: version.parse().unwrap(),
+            checksum: None,
+            parameters: parameters
+                .into_iter()
+                .map(|(name, value)| (name.to_owned(), value))
+                .collect(),
+            condition: DependencyCondition::True,
+            kind: DependencyKind::Normal,
+            optional: false,
+        }))
+    }
+
+    fn local(path: &str) -> ModuleDependency {
+        ModuleDependency::Local(Box::new(DependencyLocal {
+            path: path.to_owned(),
+            parameters: HashMap::new(),
+            condition: DependencyCondition::True,
+            kind: DependencyKind::Normal,
+            optional: false,
+        }))
+    }
+
+    fn options() -> ResolveOptions {
+        ResolveOptions {
+            target: TargetInfo {
+                os: "linux".to_owned(),
+                arch: "x86_64".to_owned(),
+                pointer_width: 64,
+                edition: RUNTIME_EDITION,
+            },
+            ..ResolveOptions::default()
+        }
+    }
+
+    #[test]
+    fn test_resolve() {
+        let mut provider = TestProvider::default();
+        provider.add(
+            Some("1.0.0"),
+            manifest(
+                "module_a",
+                vec![(
+                    "common",
+                    share(
+                        "1.1",
+                        vec![("flag_x", DependencyParameterValue::Bool(true))],
+                    ),
+                )],
+            ),
+        );
+        provider.add(
+            None,
+            manifest(
+                "module_b",
+                vec![(
+                    "common",
+                    share(
+                        "1.2",
+                        vec![
+                            ("flag_y", DependencyParameterValue::Bool(true)),
+                            ("level", DependencyParameterValue::Number(3)),
+                        ],
+                    ),
+                )],
+            ),
+        );
+
+        let mut common = manifest("common", vec![]);
+        common
+            .properties
+            .insert("flag_x".to_owned(), PropertyValue::Flag(false));
+        common
+            .properties
+            .insert("flag_y".to_owned(), PropertyValue::Flag(false));
+        common
+            .properties
+            .insert("level".to_owned(), PropertyValue::Number(1));
+        // the optional dependency "zlib" is activated by "flag_x".
+        let mut zlib = local("zlib");
+        if let ModuleDependency::Local(dependency) = &mut zlib {
+            dependency.optional = true;
+        }
+        common.dependencies.insert("zlib".to_owned(), zlib);
+        // the dependency "winapi" is included on Windows only.
+        let mut winapi = local("winapi");
+        if let ModuleDependency::Local(dependency) = &mut winapi {
+            dependency.condition =
+                DependencyCondition::All(vec![DependencyConditionCheck::TargetOs(
+                    "windows".to_owned(),
+                )]);
+        }
+        common.dependencies.insert("winapi".to_owned(), winapi);
+        common.features = FeatureActivation {
+            flags: HashMap::from([("flag_x".to_owned(), vec!["zlib".to_owned()])]),
+        };
+
+        for version in ["1.0.0", "1.1.5", "1.2.3", "2.0.0"] {
+            provider.add(Some(version), common.clone());
+        }
+        provider.add(None, manifest("zlib", vec![]));
+
+        let mut app = manifest(
+            "app",
+            vec![("module_a", share("1.0", vec![])), ("module_b", local("b"))],
+        );
+        app.dependencies.insert("test_utils".to_owned(), {
+            let mut dependency = local("test_utils");
+            if let ModuleDependency::Local(item) = &mut dependency {
+                item.kind = DependencyKind::Dev;
+            }
+            dependency
+        });
+
+        let resolution = resolve(&app, &provider, &options()).unwrap();
+
+        let order = resolution
+            .graph
+            .topological_sort()
+            .unwrap()
+            .iter()
+            .map(|id| id.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(order, vec!["zlib", "common", "module_a", "module_b", "app"]);
+
+        assert_eq!(
+            resolution
+                .graph
+                .get_node(&ModuleId::new("common"))
+                .unwrap()
+                .version,
+            Some(EffectiveVersion::new(1, 2, 3))
+        );
+
+        let properties = &resolution.properties["common"];
+        assert_eq!(properties["flag_x"], PropertyValue::Flag(true));
+        assert_eq!(properties["flag_y"], PropertyValue::Flag(true));
+        assert_eq!(properties["level"], PropertyValue::Number(3));
+
+        // the dev dependency of the root module
+        provider.add(None, manifest("test_utils", vec![]));
+        let options = ResolveOptions {
+            include_dev_dependencies: true,
+            ..options()
+        };
+        let resolution = resolve(&app, &provider, &options).unwrap();
+        assert!(resolution
+            .graph
+            .get_node(&ModuleId::new("test_utils"))
+            .is_some());
+    }
+
+    #[test]
+    fn test_resolve_conflicts() {
+        let mut provider = TestProvider::default();
+        provider.add(
+            None,
+            manifest("module_a", vec![("common", share("1.0", vec![]))]),
+        );
+        provider.add(
+            None,
+            manifest("module_b", vec![("common", share("2.0", vec![]))]),
+        );
+        provider.add(Some("1.0.0"), manifest("common", vec![]));
+        provider.add(Some("2.0.0"), manifest("common", vec![]));
+
+        let app = manifest(
+            "app",
+            vec![("module_a", local("a")), ("module_b", local("b"))],
+        );
+        assert!(matches!(
+            resolve(&app, &provider, &options()),
+            Err(ResolveError::VersionConflict {
+                conflict: VersionConflict::IncompatibleRequirements { .. },
+                ..
+            })
+        ));
+
+        // different sources
+        let mut provider = TestProvider::default();
+        provider.add(
+            None,
+            manifest("module_a", vec![("common", local("common_1"))]),
+        );
+        provider.add(
+            None,
+            manifest("module_b", vec![("common", local("common_2"))]),
+        );
+        provider.add(None, manifest("common", vec![]));
+        assert_eq!(
+            resolve(&app, &provider, &options()),
+            Err(ResolveError::SourceConflict {
+                module_name: "common".to_owned(),
+                dependents: vec!["module_a".to_owned(), "module_b".to_owned()]
+            })
+        );
+
+        // cycle
+        let mut provider = TestProvider::default();
+        provider.add(None, manifest("module_a", vec![("module_b", local("b"))]));
+        provider.add(None, manifest("module_b", vec![("module_a", local("a"))]));
+        let app = manifest("app", vec![("module_a", local("a"))]);
+        assert_eq!(
+            resolve(&app, &provider, &options()),
+            Err(ResolveError::Graph(GraphError::Cycle {
+                modules: vec![
+                    ModuleId::new("module_a"),
+                    ModuleId::new("module_b"),
+                    ModuleId::new("module_a")
+                ]
+            }))
+        );
+    }
+
+    #[test]
+    fn test_resolve_choice_property() {
+        let backends = vec!["cpu".to_owned(), "cuda".to_owned()];
+        let mut provider = TestProvider::default();
+        provider.add(
+            Some("1.0.0"),
+            ModuleManifest {
+                properties: HashMap::from([(
+                    "backend".to_owned(),
+                    PropertyValue::Choice(backends.clone(), "cpu".to_owned()),
+                )]),
+                ..manifest("tensor", vec![])
+            },
+        );
+
+        let app = |backend: &str| {
+            manifest(
+                "app",
+                vec![(
+                    "tensor",
+                    share(
+                        "1.0",
+                        vec![(
+                            "backend",
+                            DependencyParameterValue::String(backend.to_owned()),
+                        )],
+                    ),
+                )],
+            )
+        };
+
+        let resolution = resolve(&app("cuda"), &provider, &options()).unwrap();
+        assert_eq!(
+            resolution.properties["tensor"]["backend"],
+            PropertyValue::Choice(backends.clone(), "cuda".to_owned())
+        );
+
+        assert_eq!(
+            resolve(&app("metal"), &provider, &options()),
+            Err(ResolveError::Parameter {
+                module_name: "tensor".to_owned(),
+                error: ParameterError::InvalidChoice {
+                    name: "backend".to_owned(),
+                    value: "metal".to_owned(),
+                    allowed: backends
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_resolve_string_list_property() {
+        let mut provider = TestProvider::default();
+        provider.add(
+            Some("1.0.0"),
+            ModuleManifest {
+                properties: HashMap::from([(
+                    "formats".to_owned(),
+                    PropertyValue::StringList(vec!["png".to_owned()]),
+                )]),
+                ..manifest("image", vec![])
+            },
+        );
+
+        let app = |formats: DependencyParameterValue| {
+            manifest(
+                "app",
+                vec![("image", share("1.0", vec![("formats", formats)]))],
+            )
+        };
+
+        let formats = vec!["png".to_owned(), "jpeg".to_owned()];
+        let resolution = resolve(
+            &app(DependencyParameterValue::StringList(formats.clone())),
+            &provider,
+            &options(),
+        )
+        .unwrap();
+        assert_eq!(
+            resolution.properties["image"]["formats"],
+            PropertyValue::StringList(formats)
+        );
+
+        assert_eq!(
+            resolve(
+                &app(DependencyParameterValue::String("png,jpeg".to_owned())),
+                &provider,
+                &options()
+            ),
+            Err(ResolveError::InvalidParameter {
+                module_name: "image".to_owned(),
+                dependent: "app".to_owned(),
+                parameter: "formats".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn test_resolve_yanked_and_deprecated() {
+        let mut provider = TestProvider::default();
+        for version in ["1.0.0", "1.1.0", "1.1.1"] {
+            provider.add(Some(version), manifest("common", vec![]));
+        }
+
+        let entry =
+            |version: &str, yanked: bool, deprecation: Option<DeprecationNotice>| IndexEntry {
+                name: "common".to_owned(),
+                version: EffectiveVersion::from_version_string(version).unwrap(),
+                checksum: Checksum::compute(ChecksumAlgorithm::Sha256, b""),
+                yanked,
+                deprecation,
+                metadata: ModuleMetadata::default(),
+            };
+        let notice = DeprecationNotice {
+            message: "unmaintained".to_owned(),
+            replacement: Some("common2".to_owned()),
+            replacement_version: Some("1.0".to_owned()),
+        };
+        provider
+            .entries
+            .insert("common@1.1.1".to_owned(), entry("1.1.1", true, None));
+        provider.entries.insert(
+            "common@1.1.0".to_owned(),
+            entry("1.1.0", false, Some(notice.clone())),
+        );
+
+        // the yanked version 1.1.1 is skipped
+        let app = manifest("app", vec![("common", share("1.1", vec![]))]);
+        let resolution = resolve(&app, &provider, &options()).unwrap();
+        assert_eq!(
+            resolution
+                .graph
+                .get_node(&ModuleId::new("common"))
+                .unwrap()
+                .version,
+            Some(EffectiveVersion::new(1, 1, 0))
+        );
+        assert_eq!(
+            resolution.warnings,
+            vec![ResolveWarning::Deprecated {
+                module_name: "common".to_owned(),
+                version: EffectiveVersion::new(1, 1, 0),
+                notice,
+            }]
+        );
+        assert_eq!(
+            resolution.warnings[0].to_string(),
+            "Module \"common\" version \"1.1.0\" is deprecated: unmaintained \
+            (use \"common2\" version \"1.0\" instead)"
+        );
+
+        // only the yanked version matches
+        let app = manifest("app", vec![("common", share("=1.1.1", vec![]))]);
+        assert_eq!(
+            resolve(&app, &provider, &options()),
+            Err(ResolveError::YankedVersion {
+                module_name: "common".to_owned(),
+                version: EffectiveVersion::new(1, 1, 1),
+            })
+        );
+    }
+}
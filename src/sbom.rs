@@ -0,0 +1,375 @@
+// Copyright (c) 2025 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions.
+// For more details, see the LICENSE, LICENSE.additional, and CONTRIBUTING files.
+
+// Module Metadata
+// ---------------
+//
+// A module can declare its license, authors and the source repository
+// in the manifest, and the registry copies them into the index entry, e.g.:
+//
+// ```ason
+// metadata: {
+//     license: Option::Some("MIT OR Apache-2.0")
+//     authors: ["Hemashushu <hippospark@gmail.com>"]
+//     repository: Option::Some("https://github.com/hemashushu/sha2.git")
+// }
+// ```
+//
+// The license is an SPDX license expression (see the SPDX specification, annex D),
+// i.e., license IDs (e.g. "MIT", "GPL-2.0+" and "LicenseRef-Custom") combined with
+// "AND", "OR", "WITH" (followed by an exception ID) and parentheses.
+// Only the syntax is checked, the IDs are not checked against the SPDX license list.
+//
+// SBOM Export
+// -----------
+//
+// The function `export_sbom` produces a software bill of materials of a resolved
+// dependency graph, it lists each module (in the topological order, i.e., the
+// dependencies first) with the selected version, the source, the declared checksum,
+// the metadata and the names of its direct dependencies.
+// The report can be serialized by serde, e.g. to ASON or JSON.
+
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    checksum::Checksum,
+    graph::{get_source_label, GraphError},
+    resolver::Resolution,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Default, Serialize, Deserialize)]
+#[serde(rename = "metadata")]
+pub struct ModuleMetadata {
+    /// Optional.
+    /// The SPDX license expression, e.g. "MIT OR Apache-2.0".
+    pub license: Option<String>,
+
+    /// Optional.
+    /// The default value is [].
+    #[serde(default)]
+    pub authors: Vec<String>,
+
+    /// Optional.
+    /// The URL of the source repository.
+    pub repository: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum LicenseError {
+    Empty,
+
+    UnexpectedToken { position: usize, found: String },
+
+    UnexpectedEnd,
+}
+
+impl Display for LicenseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LicenseError::Empty => f.write_str("The license expression is empty."),
+            LicenseError::UnexpectedToken { position, found } => write!(
+                f,
+                "Unexpected token \"{}\" at position {} of the license expression.",
+                found, position
+            ),
+            LicenseError::UnexpectedEnd => f.write_str("Unexpected end of the license expression."),
+        }
+    }
+}
+
+impl std::error::Error for LicenseError {}
+
+/// Returns the tokens with their positions, i.e., "(", ")" and the words.
+fn tokenize_license(expression: &str) -> Vec<(usize, &str)> {
+    let mut tokens = vec![];
+    let mut start = None;
+
+    for (position, c) in expression.char_indices() {
+        if c.is_whitespace() || c == '(' || c == ')' {
+            if let Some(start) = start.take() {
+                tokens.push((start, &expression[start..position]));
+            }
+            if !c.is_whitespace() {
+                tokens.push((position, &expression[position..position + 1]));
+            }
+        } else if start.is_none() {
+            start = Some(position);
+        }
+    }
+
+    if let Some(start) = start {
+        tokens.push((start, &expression[start..]));
+    }
+    tokens
+}
+
+fn is_license_id(word: &str) -> bool {
+    let id = word.strip_suffix('+').unwrap_or(word);
+    !id.is_empty()
+        && !["AND", "OR", "WITH"].contains(&id)
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '.' || c == ':')
+}
+
+struct LicenseParser<'a> {
+    tokens: Vec<(usize, &'a str)>,
+    index: usize,
+}
+
+impl LicenseParser<'_> {
+    fn unexpected(&self) -> LicenseError {
+        match self.tokens.get(self.index) {
+            Some((position, found)) => LicenseError::UnexpectedToken {
+                position: *position,
+                found: found.to_string(),
+            },
+            None => LicenseError::UnexpectedEnd,
+        }
+    }
+
+    fn peek(&self) -> Option<&str> {
+        self.tokens.get(self.index).map(|(_, token)| *token)
+    }
+
+    /// expression = term { ("AND" | "OR") term }
+    fn parse_expression(&mut self) -> Result<(), LicenseError> {
+        self.parse_term()?;
+        while matches!(self.peek(), Some("AND" | "OR")) {
+            self.index += 1;
+            self.parse_term()?;
+        }
+        Ok(())
+    }
+
+    /// term = "(" expression ")" | id [ "WITH" id ]
+    fn parse_term(&mut self) -> Result<(), LicenseError> {
+        match self.peek() {
+            Some("(") => {
+                self.index += 1;
+                self.parse_expression()?;
+                if self.peek() != Some(")") {
+                    return Err(self.unexpected());
+                }
+                self.index += 1;
+            }
+            Some(word) if is_license_id(word) => {
+                self.index += 1;
+                if self.peek() == Some("WITH") {
+                    self.index += 1;
+                    match self.peek() {
+                        Some(word) if is_license_id(word) && !word.ends_with('+') => {
+                            self.index += 1
+                        }
+                        _ => return Err(self.unexpected()),
+                    }
+                }
+            }
+            _ => return Err(self.unexpected()),
+        }
+        Ok(())
+    }
+}
+
+/// Checks the syntax of the SPDX license expression, see the section "Module Metadata".
+pub fn validate_license_expression(expression: &str) -> Result<(), LicenseError> {
+    let tokens = tokenize_license(expression);
+    if tokens.is_empty() {
+        return Err(LicenseError::Empty);
+    }
+
+    let mut parser = LicenseParser { tokens, index: 0 };
+    parser.parse_expression()?;
+    if parser.index < parser.tokens.len() {
+        return Err(parser.unexpected());
+    }
+    Ok(())
+}
+
+impl ModuleMetadata {
+    pub fn validate(&self) -> Result<(), LicenseError> {
+        match &self.license {
+            Some(license) => validate_license_expression(license),
+            None => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename = "component")]
+pub struct SbomComponent {
+    pub name: String,
+
+    /// The selected version of the shared module.
+    pub version: Option<String>,
+
+    /// The source, e.g. "share", "local:../sha2" and "remote:URL#tag:v1.0.0".
+    pub source: String,
+
+    /// The checksum declared by the dependency.
+    pub checksum: Option<Checksum>,
+
+    pub metadata: ModuleMetadata,
+
+    /// The names of the direct dependencies.
+    pub dependencies: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename = "sbom")]
+pub struct SbomReport {
+    pub components: Vec<SbomComponent>,
+}
+
+/// Produces the SBOM of the resolved dependency graph, see the section "SBOM Export".
+pub fn export_sbom(resolution: &Resolution) -> Result<SbomReport, GraphError> {
+    let graph = &resolution.graph;
+
+    let components = graph
+        .topological_sort()?
+        .iter()
+        .filter_map(|id| graph.get_node(id))
+        .map(|node| SbomComponent {
+            name: node.id.name.to_owned(),
+            version: node.version.map(|version| version.to_string()),
+            source: get_source_label(&node.source),
+            checksum: node.source.get_checksum().cloned(),
+            metadata: resolution
+                .metadata
+                .get(&node.id.name)
+                .cloned()
+                .unwrap_or_default(),
+            dependencies: graph
+                .get_dependencies(&node.id)
+                .map(|edge| edge.to.name.to_owned())
+                .collect(),
+        })
+        .collect();
+
+    Ok(SbomReport { components })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        checksum::{Checksum, ChecksumAlgorithm},
+        graph::{DependencyEdge, DependencyGraph, DependencyNode, ModuleId},
+        resolver::Resolution,
+        sbom::{
+            export_sbom, validate_license_expression, LicenseError, ModuleMetadata, SbomComponent,
+        },
+        DependencyCondition, DependencyKind, DependencyShare, EffectiveVersion, ModuleDependency,
+    };
+
+    #[test]
+    fn test_validate_license_expression() {
+        for expression in [
+            "MIT",
+            "MIT OR Apache-2.0",
+            "GPL-2.0+ WITH Classpath-exception-2.0",
+            "(MIT OR Apache-2.0) AND LicenseRef-Custom",
+            "((BSD-3-Clause))",
+        ] {
+            assert_eq!(validate_license_expression(expression), Ok(()));
+        }
+
+        assert_eq!(validate_license_expression("  "), Err(LicenseError::Empty));
+        assert_eq!(
+            validate_license_expression("MIT OR"),
+            Err(LicenseError::UnexpectedEnd)
+        );
+        assert_eq!(
+            validate_license_expression("MIT Apache-2.0"),
+            Err(LicenseError::UnexpectedToken {
+                position: 4,
+                found: "Apache-2.0".to_owned()
+            })
+        );
+        assert_eq!(
+            validate_license_expression("(MIT"),
+            Err(LicenseError::UnexpectedEnd)
+        );
+        assert_eq!(
+            validate_license_expression("MIT WITH AND"),
+            Err(LicenseError::UnexpectedToken {
+                position: 9,
+                found: "AND".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn test_export_sbom() {
+        let checksum = Checksum::compute(ChecksumAlgorithm::Sha256, b"");
+
+        let mut graph = DependencyGraph::new();
+        graph.add_node(DependencyNode {
+            id: ModuleId::new("app"),
+            source: ModuleDependency::Current,
+            version: None,
+        });
+        graph.add_node(DependencyNode {
+            id: ModuleId::new("sha2"),
+            source: ModuleDependency::Share(Box::new(DependencyShare {
+                version: "1.2".parse().unwrap(),
+                checksum: Some(checksum.clone()),
+                parameters: Default::default(),
+                condition: DependencyCondition::True,
+                kind: DependencyKind::Normal,
+                optional: false,
+            })),
+            version: Some(EffectiveVersion::new(1, 2, 3)),
+        });
+        graph
+            .add_edge(DependencyEdge {
+                from: ModuleId::new("app"),
+                to: ModuleId::new("sha2"),
+                kind: DependencyKind::Normal,
+                requirement: None,
+            })
+            .unwrap();
+
+        let sha2_metadata = ModuleMetadata {
+            license: Some("MIT OR Apache-2.0".to_owned()),
+            authors: vec!["Hemashushu".to_owned()],
+            repository: Some("https://github.com/hemashushu/sha2.git".to_owned()),
+        };
+        let resolution = Resolution {
+            graph,
+            properties: BTreeMap::new(),
+            metadata: BTreeMap::from([("sha2".to_owned(), sha2_metadata.clone())]),
+            warnings: vec![],
+        };
+
+        assert_eq!(
+            export_sbom(&resolution).unwrap().components,
+            vec![
+                SbomComponent {
+                    name: "sha2".to_owned(),
+                    version: Some("1.2.3".to_owned()),
+                    source: "share".to_owned(),
+                    checksum: Some(checksum),
+                    metadata: sha2_metadata,
+                    dependencies: vec![],
+                },
+                SbomComponent {
+                    name: "app".to_owned(),
+                    version: None,
+                    source: "root".to_owned(),
+                    checksum: None,
+                    metadata: ModuleMetadata::default(),
+                    dependencies: vec!["sha2".to_owned()],
+                },
+            ]
+        );
+    }
+}
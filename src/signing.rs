@@ -0,0 +1,546 @@
+// Copyright (c) 2025 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions.
+// For more details, see the LICENSE, LICENSE.additional, and CONTRIBUTING files.
+
+// Index Signing
+// -------------
+//
+// A registry (see the module `registry`) can sign the entries of its index
+// with an Ed25519 key (see RFC 8032), and the clients reject the entries whose
+// signature does not match the public key of the registry, e.g.:
+//
+// ```ason
+// {
+//     entry: {
+//         name: "sha2"
+//         version: "1.2.3"
+//         checksum: "sha256:e3b0c442..."
+//     }
+//     signature: "ed25519:92a009a9..."
+// }
+// ```
+//
+// The public keys and the signatures are written as "ed25519:HEX", where HEX is
+// the lowercase hexadecimal string of the 32-byte public key or the 64-byte signature.
+//
+// The signatures are verified by the crate `ed25519-dalek` (the feature `ed25519`,
+// which is enabled by default), the weak public keys and the non-canonical
+// signatures are rejected.
+//
+// The signed message is the binary encoding of the fields, so that two different
+// entries never produce the same message:
+//
+// - A string is the length (u32) followed by the UTF-8 bytes.
+// - A boolean is a byte, 0 or 1.
+// - An optional value is a byte, 0 (absent) or 1 (present, followed by the value).
+// - A list is the number of items (u32) followed by the items.
+//
+// The integers are little-endian (see the module `endian`), and the fields are:
+// the string "anc-index-entry", the module name, the version, the checksum, the yanked
+// flag, the license, the authors and the repository of the metadata, and the optional
+// deprecation notice, i.e., the message, the optional replacement and the optional
+// replacement version. The version and the checksum are written as strings.
+//
+// Yanked and Deprecated Versions
+// ------------------------------
+//
+// The publisher can withdraw a version (i.e., yank it) or deprecate a version:
+//
+// - The resolver never selects a yanked version, if the only matching versions are yanked,
+//   the error `ResolveError::YankedVersion` is raised.
+// - The resolver selects a deprecated version as usual, and reports the deprecation
+//   notice in `Resolution::warnings`.
+//
+// Since the yanked flag and the deprecation notice are signed, the registry re-signs
+// the entry when they change.
+
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    checksum::{decode_hex, encode_hex, Checksum},
+    endian::write_u32,
+    sbom::ModuleMetadata,
+    EffectiveVersion,
+};
+
+pub const SIGNATURE_ALGORITHM_NAME: &str = "ed25519";
+pub const PUBLIC_KEY_LENGTH_IN_BYTES: usize = 32;
+pub const SIGNATURE_LENGTH_IN_BYTES: usize = 64;
+
+const INDEX_ENTRY_MESSAGE_HEADER: &str = "anc-index-entry";
+
+/// It is serialized as a string, e.g. "ed25519:d75a9801...".
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub struct PublicKey(pub [u8; PUBLIC_KEY_LENGTH_IN_BYTES]);
+
+/// It is serialized as a string, e.g. "ed25519:e5564300...".
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub struct Signature(pub [u8; SIGNATURE_LENGTH_IN_BYTES]);
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename = "entry")]
+pub struct IndexEntry {
+    pub name: String,
+    pub version: EffectiveVersion,
+
+    /// The checksum of the module source.
+    pub checksum: Checksum,
+
+    /// Optional.
+    /// The default value is false.
+    /// A yanked version is withdrawn by the publisher, it is not selected by
+    /// the resolver, see the section "Yanked and Deprecated Versions".
+    #[serde(default)]
+    pub yanked: bool,
+
+    /// Optional.
+    pub deprecation: Option<DeprecationNotice>,
+
+    /// Optional.
+    /// The metadata copied from the module manifest, see the module `sbom`.
+    #[serde(default)]
+    pub metadata: ModuleMetadata,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename = "deprecation")]
+pub struct DeprecationNotice {
+    pub message: String,
+
+    /// Optional.
+    /// The name of the suggested replacement module.
+    pub replacement: Option<String>,
+
+    /// Optional.
+    /// The suggested version requirement of the replacement module (or of the
+    /// same module if `replacement` is absent), e.g. "2.0".
+    pub replacement_version: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename = "signed_entry")]
+pub struct SignedIndexEntry {
+    pub entry: IndexEntry,
+    pub signature: Signature,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum SignatureError {
+    /// The string is not in the format "ed25519:HEX",
+    /// or the length of the key or the signature is incorrect.
+    Malformed {
+        text: String,
+    },
+
+    UnsupportedAlgorithm {
+        algorithm: String,
+    },
+
+    /// The public key is not a valid point of the curve.
+    InvalidPublicKey,
+
+    /// The signature does not match the message and the public key.
+    VerificationFailed,
+}
+
+impl Display for SignatureError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SignatureError::Malformed { text } => {
+                write!(f, "Malformed public key or signature \"{}\".", text)
+            }
+            SignatureError::UnsupportedAlgorithm { algorithm } => {
+                write!(f, "Unsupported signature algorithm \"{}\".", algorithm)
+            }
+            SignatureError::InvalidPublicKey => f.write_str("Invalid public key."),
+            SignatureError::VerificationFailed => f.write_str("Signature verification failed."),
+        }
+    }
+}
+
+impl std::error::Error for SignatureError {}
+
+fn write_key_string(f: &mut std::fmt::Formatter<'_>, bytes: &[u8]) -> std::fmt::Result {
+    write!(f, "{}:{}", SIGNATURE_ALGORITHM_NAME, encode_hex(bytes))
+}
+
+fn parse_key_string<const N: usize>(text: &str) -> Result<[u8; N], SignatureError> {
+    let malformed = || SignatureError::Malformed {
+        text: text.to_owned(),
+    };
+
+    let (name, hex) = text.split_once(':').ok_or_else(malformed)?;
+    if name != SIGNATURE_ALGORITHM_NAME {
+        return Err(SignatureError::UnsupportedAlgorithm {
+            algorithm: name.to_owned(),
+        });
+    }
+
+    decode_hex(hex)
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(malformed)
+}
+
+impl PublicKey {
+    pub fn from_key_string(text: &str) -> Result<Self, SignatureError> {
+        parse_key_string(text).map(Self)
+    }
+
+    /// Verifies the Ed25519 signature of the message, see RFC 8032.
+    #[cfg(feature = "ed25519")]
+    pub fn verify(&self, message: &[u8], signature: &Signature) -> Result<(), SignatureError> {
+        let public_key = ed25519_dalek::VerifyingKey::from_bytes(&self.0)
+            .map_err(|_| SignatureError::InvalidPublicKey)?;
+        public_key
+            .verify_strict(message, &ed25519_dalek::Signature::from_bytes(&signature.0))
+            .map_err(|_| SignatureError::VerificationFailed)
+    }
+}
+
+impl Signature {
+    pub fn from_signature_string(text: &str) -> Result<Self, SignatureError> {
+        parse_key_string(text).map(Self)
+    }
+}
+
+impl Display for PublicKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_key_string(f, &self.0)
+    }
+}
+
+impl Display for Signature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_key_string(f, &self.0)
+    }
+}
+
+impl From<PublicKey> for String {
+    fn from(value: PublicKey) -> Self {
+        value.to_string()
+    }
+}
+
+impl TryFrom<String> for PublicKey {
+    type Error = SignatureError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        PublicKey::from_key_string(&value)
+    }
+}
+
+impl From<Signature> for String {
+    fn from(value: Signature) -> Self {
+        value.to_string()
+    }
+}
+
+impl TryFrom<String> for Signature {
+    type Error = SignatureError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Signature::from_signature_string(&value)
+    }
+}
+
+/// Writes the signed message, see the section "Index Signing".
+struct MessageWriter {
+    bytes: Vec<u8>,
+}
+
+impl MessageWriter {
+    fn write_length(&mut self, length: usize) {
+        write_u32(&mut self.bytes, length as u32);
+    }
+
+    fn write_string(&mut self, value: &str) {
+        self.write_length(value.len());
+        self.bytes.extend_from_slice(value.as_bytes());
+    }
+
+    fn write_bool(&mut self, value: bool) {
+        self.bytes.push(value as u8);
+    }
+
+    fn write_optional_string(&mut self, value: Option<&str>) {
+        self.write_bool(value.is_some());
+        if let Some(value) = value {
+            self.write_string(value);
+        }
+    }
+
+    fn write_strings(&mut self, values: &[String]) {
+        self.write_length(values.len());
+        for value in values {
+            self.write_string(value);
+        }
+    }
+}
+
+impl IndexEntry {
+    /// Returns the signed message, see the section "Index Signing".
+    pub fn get_signing_message(&self) -> Vec<u8> {
+        let mut writer = MessageWriter { bytes: vec![] };
+        writer.write_string(INDEX_ENTRY_MESSAGE_HEADER);
+        writer.write_string(&self.name);
+        writer.write_string(&self.version.to_string());
+        writer.write_string(&self.checksum.to_string());
+        writer.write_bool(self.yanked);
+        writer.write_optional_string(self.metadata.license.as_deref());
+        writer.write_strings(&self.metadata.authors);
+        writer.write_optional_string(self.metadata.repository.as_deref());
+
+        writer.write_bool(self.deprecation.is_some());
+        if let Some(notice) = &self.deprecation {
+            writer.write_string(&notice.message);
+            writer.write_optional_string(notice.replacement.as_deref());
+            writer.write_optional_string(notice.replacement_version.as_deref());
+        }
+
+        writer.bytes
+    }
+}
+
+#[cfg(feature = "ed25519")]
+impl SignedIndexEntry {
+    pub fn verify(&self, public_key: &PublicKey) -> Result<(), SignatureError> {
+        public_key.verify(&self.entry.get_signing_message(), &self.signature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        checksum::{Checksum, ChecksumAlgorithm},
+        sbom::ModuleMetadata,
+        signing::{DeprecationNotice, IndexEntry, PublicKey, Signature, SignatureError},
+        EffectiveVersion,
+    };
+
+    #[cfg(feature = "ed25519")]
+    use crate::signing::SignedIndexEntry;
+
+    fn from_hex<const N: usize>(hex: &str) -> [u8; N] {
+        std::array::from_fn(|index| u8::from_str_radix(&hex[index * 2..index * 2 + 2], 16).unwrap())
+    }
+
+    /// Signs the message with the secret key (seed), see RFC 8032.
+    #[cfg(feature = "ed25519")]
+    fn sign(seed: &[u8; 32], message: &[u8]) -> (PublicKey, Signature) {
+        use ed25519_dalek::Signer;
+
+        let signing_key = ed25519_dalek::SigningKey::from_bytes(seed);
+        (
+            PublicKey(signing_key.verifying_key().to_bytes()),
+            Signature(signing_key.sign(message).to_bytes()),
+        )
+    }
+
+    #[test]
+    fn test_key_string() {
+        let public_key = PublicKey(from_hex(
+            "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+        ));
+        assert_eq!(
+            public_key.to_string(),
+            "ed25519:3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c"
+        );
+        assert_eq!(
+            PublicKey::from_key_string(&public_key.to_string()),
+            Ok(public_key)
+        );
+        assert_eq!(
+            PublicKey::from_key_string("rsa:00"),
+            Err(SignatureError::UnsupportedAlgorithm {
+                algorithm: "rsa".to_owned()
+            })
+        );
+        assert_eq!(
+            Signature::from_signature_string("ed25519:00"),
+            Err(SignatureError::Malformed {
+                text: "ed25519:00".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn test_signing_message() {
+        let entry = IndexEntry {
+            name: "sha2".to_owned(),
+            version: EffectiveVersion::from_version_string("1.2.3").unwrap(),
+            checksum: Checksum::compute(ChecksumAlgorithm::Sha256, b""),
+            yanked: false,
+            deprecation: None,
+            metadata: ModuleMetadata::default(),
+        };
+
+        let message = entry.get_signing_message();
+        assert_eq!(&message[..4], &15u32.to_le_bytes());
+        assert_eq!(&message[4..19], b"anc-index-entry");
+        assert_eq!(&message[19..27], b"\x04\x00\x00\x00sha2");
+
+        // the entries which differ only in the separators or the absent values
+        // produce different messages.
+        let with = |change: fn(&mut IndexEntry)| {
+            let mut entry = entry.clone();
+            change(&mut entry);
+            entry.get_signing_message()
+        };
+
+        assert_ne!(
+            with(|entry| entry.metadata.authors = vec!["Alice, Bob".to_owned()]),
+            with(|entry| entry.metadata.authors = vec!["Alice".to_owned(), "Bob".to_owned()])
+        );
+        assert_ne!(
+            message,
+            with(|entry| entry.metadata.license = Some(String::new()))
+        );
+        assert_ne!(
+            message,
+            with(|entry| entry.metadata.repository = Some(String::new()))
+        );
+        assert_ne!(
+            with(|entry| entry.metadata.license = Some("MIT\nApache-2.0".to_owned())),
+            with(|entry| {
+                entry.metadata.license = Some("MIT".to_owned());
+                entry.metadata.authors = vec!["Apache-2.0".to_owned()];
+            })
+        );
+
+        fn deprecate(replacement: Option<&str>) -> DeprecationNotice {
+            DeprecationNotice {
+                message: String::new(),
+                replacement: replacement.map(str::to_owned),
+                replacement_version: None,
+            }
+        }
+        assert_ne!(
+            message,
+            with(|entry| entry.deprecation = Some(deprecate(None)))
+        );
+        assert_ne!(
+            with(|entry| entry.deprecation = Some(deprecate(None))),
+            with(|entry| entry.deprecation = Some(deprecate(Some(""))))
+        );
+    }
+
+    #[cfg(feature = "ed25519")]
+    #[test]
+    fn test_verify() {
+        // RFC 8032, section 7.1, test 1 and test 2.
+        let public_key = PublicKey::from_key_string(
+            "ed25519:d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+        )
+        .unwrap();
+        let signature = Signature::from_signature_string(
+            "ed25519:e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b",
+        )
+        .unwrap();
+        assert_eq!(public_key.verify(b"", &signature), Ok(()));
+        assert_eq!(
+            public_key.verify(b"\x72", &signature),
+            Err(SignatureError::VerificationFailed)
+        );
+
+        let public_key = PublicKey(from_hex(
+            "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+        ));
+        let signature = Signature(from_hex(
+            "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da\
+            085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00",
+        ));
+        assert_eq!(public_key.verify(b"\x72", &signature), Ok(()));
+        assert_eq!(
+            sign(
+                &from_hex("4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb"),
+                b"\x72"
+            ),
+            (public_key, signature)
+        );
+
+        // the non-canonical signature, i.e., S + L.
+        let order: [u8; 32] =
+            from_hex("edd3f55c1a631258d69cf7a2def9de1400000000000000000000000000000010");
+        let mut non_canonical = signature;
+        let mut carry = 0u16;
+        for (byte, addend) in non_canonical.0[32..].iter_mut().zip(order) {
+            let sum = *byte as u16 + addend as u16 + carry;
+            *byte = sum as u8;
+            carry = sum >> 8;
+        }
+        assert_eq!(
+            public_key.verify(b"\x72", &non_canonical),
+            Err(SignatureError::VerificationFailed)
+        );
+
+        // the weak public key (the identity point) and the invalid public key.
+        let mut identity = [0u8; 32];
+        identity[0] = 1;
+        assert_eq!(
+            PublicKey(identity).verify(b"\x72", &signature),
+            Err(SignatureError::VerificationFailed)
+        );
+        let mut invalid = [0u8; 32];
+        invalid[0] = 2;
+        assert_eq!(
+            PublicKey(invalid).verify(b"\x72", &signature),
+            Err(SignatureError::InvalidPublicKey)
+        );
+    }
+
+    #[cfg(feature = "ed25519")]
+    #[test]
+    fn test_signed_index_entry() {
+        let entry = IndexEntry {
+            name: "sha2".to_owned(),
+            version: EffectiveVersion::from_version_string("1.2.3").unwrap(),
+            checksum: Checksum::compute(ChecksumAlgorithm::Sha256, b""),
+            yanked: false,
+            deprecation: None,
+            metadata: ModuleMetadata {
+                license: Some("MIT".to_owned()),
+                authors: vec![],
+                repository: None,
+            },
+        };
+
+        let (public_key, signature) = sign(&[7; 32], &entry.get_signing_message());
+        let mut signed = SignedIndexEntry { entry, signature };
+        assert_eq!(signed.verify(&public_key), Ok(()));
+
+        let (other_key, _) = sign(&[8; 32], b"");
+        assert_eq!(
+            signed.verify(&other_key),
+            Err(SignatureError::VerificationFailed)
+        );
+
+        // tampered metadata
+        let mut tampered = signed.clone();
+        tampered.entry.version = EffectiveVersion::from_version_string("1.2.4").unwrap();
+        assert_eq!(
+            tampered.verify(&public_key),
+            Err(SignatureError::VerificationFailed)
+        );
+
+        let mut tampered = signed.clone();
+        tampered.entry.metadata.license = Some("GPL-3.0".to_owned());
+        assert_eq!(
+            tampered.verify(&public_key),
+            Err(SignatureError::VerificationFailed)
+        );
+
+        signed.entry.yanked = true;
+        assert_eq!(
+            signed.verify(&public_key),
+            Err(SignatureError::VerificationFailed)
+        );
+    }
+}
@@ -0,0 +1,811 @@
+// Copyright (c) 2025 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions.
+// For more details, see the LICENSE, LICENSE.additional, and CONTRIBUTING files.
+
+// Full Version
+// ------------
+//
+// A full version is an `EffectiveVersion` with optional pre-release tags and build metadata,
+// e.g. "1.0.0-beta.1+20250101", see https://semver.org/#spec-item-9.
+//
+// - The pre-release tags and build metadata are dot-separated identifiers which consist of
+//   ASCII alphanumerics and hyphens, numeric pre-release identifiers must not include leading zeros.
+// - A pre-release version has a lower precedence than the associated normal version,
+//   e.g. "1.0.0-alpha" < "1.0.0-alpha.1" < "1.0.0-beta" < "1.0.0".
+// - Build metadata is ignored when determining the precedence and compatibility.
+//
+// Only the `EffectiveVersion` part is stored in the image, the pre-release tags and build
+// metadata are used by the module registry and the dependency resolver.
+
+// Version Requirement
+// -------------------
+//
+// A version requirement constrains the versions of a dependency, it is one of:
+//
+// - Exact: "=1.2.3", only matches "1.2.3".
+// - Caret: "^1.2.3" or "1.2.3", matches the compatible versions, i.e., ">=1.2.3, <2.0.0".
+//   For the zero major version, each minor version is incompatible, i.e., "^0.2.3"
+//   is ">=0.2.3, <0.3.0".
+// - Tilde: "~1.2.3", matches the patch updates, i.e., ">=1.2.3, <1.3.0".
+// - Range: comma-separated comparators, e.g. ">=1.2.0, <1.5.0",
+//   the operators are ">", ">=", "<", "<=" and "=".
+//
+// The minor and patch may be omitted (e.g., "1.4" and "^1"), the omitted parts are 0.
+// So the version "1.4" of `DependencyShare` is the requirement "^1.4.0", which may resolve
+// to 1.4.0 - 1.99.99 as described in the section "About the Version of Shared Modules".
+//
+// Version Selection
+// -----------------
+//
+// The function `select_version` implements the rule described in the section
+// "Version Conflicts" of the crate root: when a module is required multiple times,
+// the requirements must be compatible with each other (i.e., the same major version,
+// and the same minor version for the zero major version), and the highest available
+// version that satisfies all requirements is selected.
+
+use std::{
+    cmp::Ordering,
+    fmt::Display,
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{DependencyShare, EffectiveVersion, VersionCompatibility, VersionParseError};
+
+/// It is serialized as a string, e.g. "1.0.0-beta.1".
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub struct FullVersion {
+    pub version: EffectiveVersion,
+
+    /// The dot-separated pre-release identifiers, e.g. ["beta", "1"],
+    /// empty for the normal version.
+    pub pre_release: Vec<String>,
+
+    /// The dot-separated build metadata identifiers, e.g. ["20250101"].
+    pub build: Vec<String>,
+}
+
+impl FullVersion {
+    pub fn new(version: EffectiveVersion) -> Self {
+        Self {
+            version,
+            pre_release: vec![],
+            build: vec![],
+        }
+    }
+
+    pub fn is_pre_release(&self) -> bool {
+        !self.pre_release.is_empty()
+    }
+
+    /// Parses a version string in the format "x.y.z[-pre_release][+build]".
+    pub fn from_version_string(version: &str) -> Result<Self, VersionParseError> {
+        let (rest, build) = match version.split_once('+') {
+            Some((rest, build)) => (rest, Some(build)),
+            None => (version, None),
+        };
+
+        let (core, pre_release) = match rest.split_once('-') {
+            Some((core, pre_release)) => (core, Some(pre_release)),
+            None => (rest, None),
+        };
+
+        let effective_version = EffectiveVersion::from_version_string(core)?;
+
+        let pre_release = match pre_release {
+            Some(text) => parse_identifiers(text, true).ok_or_else(|| {
+                VersionParseError::InvalidPreRelease {
+                    version: version.to_owned(),
+                }
+            })?,
+            None => vec![],
+        };
+
+        let build = match build {
+            Some(text) => parse_identifiers(text, false).ok_or_else(|| {
+                VersionParseError::InvalidBuildMetadata {
+                    version: version.to_owned(),
+                }
+            })?,
+            None => vec![],
+        };
+
+        Ok(Self {
+            version: effective_version,
+            pre_release,
+            build,
+        })
+    }
+
+    /// Compares the precedence of two versions, the build metadata is ignored.
+    pub fn cmp_precedence(&self, other: &FullVersion) -> Ordering {
+        self.version
+            .cmp(&other.version)
+            .then_with(|| compare_pre_release(&self.pre_release, &other.pre_release))
+    }
+
+    /// Checks the compatibility of two versions (see `EffectiveVersion::compatible`),
+    /// the pre-release tags are compared only if the effective versions are equal,
+    /// and the build metadata is ignored.
+    pub fn compatible(&self, other: &FullVersion) -> VersionCompatibility {
+        match self.version.compatible(&other.version) {
+            VersionCompatibility::Equals => {
+                match compare_pre_release(&self.pre_release, &other.pre_release) {
+                    Ordering::Less => VersionCompatibility::LessThan,
+                    Ordering::Equal => VersionCompatibility::Equals,
+                    Ordering::Greater => VersionCompatibility::GreaterThan,
+                }
+            }
+            compatibility => compatibility,
+        }
+    }
+}
+
+/// Returns `None` if any identifier is invalid.
+fn parse_identifiers(text: &str, pre_release: bool) -> Option<Vec<String>> {
+    text.split('.')
+        .map(|identifier| {
+            let valid = !identifier.is_empty()
+                && identifier
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-')
+                && !(pre_release
+                    && identifier.len() > 1
+                    && identifier.starts_with('0')
+                    && identifier.chars().all(|c| c.is_ascii_digit()));
+            valid.then(|| identifier.to_owned())
+        })
+        .collect()
+}
+
+fn compare_pre_release(left: &[String], right: &[String]) -> Ordering {
+    match (left.is_empty(), right.is_empty()) {
+        (true, true) => Ordering::Equal,
+        // the normal version has a higher precedence.
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => {
+            for (l, r) in left.iter().zip(right) {
+                let ordering = match (l.parse::<u64>(), r.parse::<u64>()) {
+                    (Ok(l), Ok(r)) => l.cmp(&r),
+                    // numeric identifiers have a lower precedence than alphanumeric ones.
+                    (Ok(_), Err(_)) => Ordering::Less,
+                    (Err(_), Ok(_)) => Ordering::Greater,
+                    (Err(_), Err(_)) => l.cmp(r),
+                };
+
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+            left.len().cmp(&right.len())
+        }
+    }
+}
+
+impl PartialOrd for FullVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Versions are ordered by precedence (see `cmp_precedence`), and then by
+/// the build metadata to keep consistent with `Eq`.
+impl Ord for FullVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cmp_precedence(other)
+            .then_with(|| self.build.cmp(&other.build))
+    }
+}
+
+impl FromStr for FullVersion {
+    type Err = VersionParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        FullVersion::from_version_string(s)
+    }
+}
+
+impl From<EffectiveVersion> for FullVersion {
+    fn from(value: EffectiveVersion) -> Self {
+        FullVersion::new(value)
+    }
+}
+
+impl From<FullVersion> for String {
+    fn from(value: FullVersion) -> Self {
+        value.to_string()
+    }
+}
+
+impl TryFrom<String> for FullVersion {
+    type Error = VersionParseError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        FullVersion::from_version_string(&value)
+    }
+}
+
+impl Display for FullVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.version)?;
+        if !self.pre_release.is_empty() {
+            write!(f, "-{}", self.pre_release.join("."))?;
+        }
+        if !self.build.is_empty() {
+            write!(f, "+{}", self.build.join("."))?;
+        }
+        Ok(())
+    }
+}
+
+/// See the section "Version Requirement".
+///
+/// It is serialized as a string, e.g. "^1.2.3".
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub enum VersionRequirement {
+    Exact(EffectiveVersion),
+    Caret(EffectiveVersion),
+    Tilde(EffectiveVersion),
+    Range(Vec<VersionComparator>),
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct VersionComparator {
+    pub operator: ComparisonOperator,
+    pub version: EffectiveVersion,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ComparisonOperator {
+    Greater,
+    GreaterOrEqual,
+    Less,
+    LessOrEqual,
+    Equal,
+}
+
+impl VersionRequirement {
+    pub fn from_requirement_string(requirement: &str) -> Result<Self, VersionParseError> {
+        let text = requirement.trim();
+        let invalid = || VersionParseError::InvalidRequirement {
+            requirement: requirement.to_owned(),
+        };
+
+        if text.contains(',') || text.starts_with('>') || text.starts_with('<') {
+            let comparators = text
+                .split(',')
+                .map(|item| VersionComparator::from_comparator_string(item.trim()))
+                .collect::<Result<Vec<_>, _>>()?;
+            return Ok(VersionRequirement::Range(comparators));
+        }
+
+        let requirement = if let Some(rest) = text.strip_prefix('=') {
+            VersionRequirement::Exact(EffectiveVersion::from_version_string(rest.trim())?)
+        } else if let Some(rest) = text.strip_prefix('^') {
+            VersionRequirement::Caret(parse_partial_version(rest.trim())?)
+        } else if let Some(rest) = text.strip_prefix('~') {
+            VersionRequirement::Tilde(parse_partial_version(rest.trim())?)
+        } else if text.starts_with(|c: char| c.is_ascii_digit()) {
+            VersionRequirement::Caret(parse_partial_version(text)?)
+        } else {
+            return Err(invalid());
+        };
+
+        Ok(requirement)
+    }
+
+    /// Returns the lowest version allowed by the requirement, or `None`
+    /// if it is unbounded below (e.g. "<2.0.0").
+    fn get_lower_bound(&self) -> Option<EffectiveVersion> {
+        match self {
+            VersionRequirement::Exact(version)
+            | VersionRequirement::Caret(version)
+            | VersionRequirement::Tilde(version) => Some(*version),
+            VersionRequirement::Range(comparators) => comparators
+                .iter()
+                .filter(|comparator| {
+                    matches!(
+                        comparator.operator,
+                        ComparisonOperator::Greater
+                            | ComparisonOperator::GreaterOrEqual
+                            | ComparisonOperator::Equal
+                    )
+                })
+                .map(|comparator| comparator.version)
+                .max(),
+        }
+    }
+
+    /// Checks whether the version satisfies the requirement.
+    pub fn matches(&self, version: &EffectiveVersion) -> bool {
+        match self {
+            VersionRequirement::Exact(expected) => version == expected,
+            VersionRequirement::Caret(base) => {
+                version >= base && version.compatibility_class() == base.compatibility_class()
+            }
+            VersionRequirement::Tilde(base) => {
+                version >= base && version.major == base.major && version.minor == base.minor
+            }
+            VersionRequirement::Range(comparators) => comparators
+                .iter()
+                .all(|comparator| comparator.matches(version)),
+        }
+    }
+}
+
+impl VersionComparator {
+    fn from_comparator_string(text: &str) -> Result<Self, VersionParseError> {
+        let (operator, rest) = if let Some(rest) = text.strip_prefix(">=") {
+            (ComparisonOperator::GreaterOrEqual, rest)
+        } else if let Some(rest) = text.strip_prefix("<=") {
+            (ComparisonOperator::LessOrEqual, rest)
+        } else if let Some(rest) = text.strip_prefix('>') {
+            (ComparisonOperator::Greater, rest)
+        } else if let Some(rest) = text.strip_prefix('<') {
+            (ComparisonOperator::Less, rest)
+        } else if let Some(rest) = text.strip_prefix('=') {
+            (ComparisonOperator::Equal, rest)
+        } else {
+            return Err(VersionParseError::InvalidRequirement {
+                requirement: text.to_owned(),
+            });
+        };
+
+        Ok(Self {
+            operator,
+            version: parse_partial_version(rest.trim())?,
+        })
+    }
+
+    pub fn matches(&self, version: &EffectiveVersion) -> bool {
+        match self.operator {
+            ComparisonOperator::Greater => version > &self.version,
+            ComparisonOperator::GreaterOrEqual => version >= &self.version,
+            ComparisonOperator::Less => version < &self.version,
+            ComparisonOperator::LessOrEqual => version <= &self.version,
+            ComparisonOperator::Equal => version == &self.version,
+        }
+    }
+}
+
+/// Parses "x", "x.y" or "x.y.z", the omitted parts are 0.
+/// The errors report the text as it is written, e.g. "1.x" instead of "1.x.0".
+pub(crate) fn parse_partial_version(text: &str) -> Result<EffectiveVersion, VersionParseError> {
+    let result = match text.split('.').count() {
+        1 => EffectiveVersion::from_version_string(&format!("{}.0.0", text)),
+        2 => EffectiveVersion::from_version_string(&format!("{}.0", text)),
+        _ => return EffectiveVersion::from_version_string(text),
+    };
+
+    result.map_err(|error| match error {
+        VersionParseError::Malformed { .. } => VersionParseError::Malformed {
+            version: text.to_owned(),
+        },
+        VersionParseError::OutOfRange { component, .. } => VersionParseError::OutOfRange {
+            version: text.to_owned(),
+            component,
+        },
+        error => error,
+    })
+}
+
+/// Selects the highest version in `available` which satisfies all `requirements`,
+/// see the section "Version Selection".
+pub fn select_version(
+    requirements: &[VersionRequirement],
+    available: &[EffectiveVersion],
+) -> Result<EffectiveVersion, VersionConflict> {
+    for (index, first) in requirements.iter().enumerate() {
+        for second in &requirements[index + 1..] {
+            if let (Some(left), Some(right)) = (first.get_lower_bound(), second.get_lower_bound()) {
+                if left.compatibility_class() != right.compatibility_class() {
+                    return Err(VersionConflict::IncompatibleRequirements {
+                        first: first.clone(),
+                        second: second.clone(),
+                    });
+                }
+            }
+        }
+    }
+
+    available
+        .iter()
+        .filter(|version| {
+            requirements
+                .iter()
+                .all(|requirement| requirement.matches(version))
+        })
+        .max()
+        .copied()
+        .ok_or_else(|| VersionConflict::NoMatchingVersion {
+            requirements: requirements.to_vec(),
+        })
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum VersionConflict {
+    /// Two requirements have different major versions (or different minor versions
+    /// for the zero major version).
+    IncompatibleRequirements {
+        first: VersionRequirement,
+        second: VersionRequirement,
+    },
+
+    /// None of the available versions satisfies all requirements.
+    NoMatchingVersion {
+        requirements: Vec<VersionRequirement>,
+    },
+}
+
+impl Display for VersionConflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VersionConflict::IncompatibleRequirements { first, second } => write!(
+                f,
+                "Version requirements \"{}\" and \"{}\" are incompatible.",
+                first, second
+            ),
+            VersionConflict::NoMatchingVersion { requirements } => {
+                let items = requirements
+                    .iter()
+                    .map(|requirement| format!("\"{}\"", requirement))
+                    .collect::<Vec<_>>();
+                write!(
+                    f,
+                    "No available version satisfies the requirements {}.",
+                    items.join(" and ")
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for VersionConflict {}
+
+impl DependencyShare {
+    /// Returns the field `version` as a version requirement, e.g. "1.4" is "^1.4.0".
+    pub fn get_version_requirement(&self) -> &VersionRequirement {
+        self.version.get_requirement()
+    }
+}
+
+impl FromStr for VersionRequirement {
+    type Err = VersionParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        VersionRequirement::from_requirement_string(s)
+    }
+}
+
+impl From<VersionRequirement> for String {
+    fn from(value: VersionRequirement) -> Self {
+        value.to_string()
+    }
+}
+
+impl TryFrom<String> for VersionRequirement {
+    type Error = VersionParseError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        VersionRequirement::from_requirement_string(&value)
+    }
+}
+
+/// A version requirement as it is written in the manifest, e.g. the version
+/// of `DependencyShare`.
+///
+/// It is parsed when deserializing, so a malformed requirement fails at the manifest
+/// parsing time, and it is serialized as the original string, e.g. "1.4" (instead of
+/// the normalized form "^1.4.0").
+/// Two declared requirements are equal if their strings are equal.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub struct DeclaredVersionRequirement {
+    text: String,
+    requirement: VersionRequirement,
+}
+
+impl DeclaredVersionRequirement {
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    pub fn get_requirement(&self) -> &VersionRequirement {
+        &self.requirement
+    }
+}
+
+impl PartialEq for DeclaredVersionRequirement {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text
+    }
+}
+
+impl Eq for DeclaredVersionRequirement {}
+
+impl Hash for DeclaredVersionRequirement {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.text.hash(state);
+    }
+}
+
+impl FromStr for DeclaredVersionRequirement {
+    type Err = VersionParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            text: s.to_owned(),
+            requirement: VersionRequirement::from_requirement_string(s)?,
+        })
+    }
+}
+
+impl From<VersionRequirement> for DeclaredVersionRequirement {
+    fn from(value: VersionRequirement) -> Self {
+        Self {
+            text: value.to_string(),
+            requirement: value,
+        }
+    }
+}
+
+impl From<DeclaredVersionRequirement> for String {
+    fn from(value: DeclaredVersionRequirement) -> Self {
+        value.text
+    }
+}
+
+impl TryFrom<String> for DeclaredVersionRequirement {
+    type Error = VersionParseError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Ok(Self {
+            requirement: VersionRequirement::from_requirement_string(&value)?,
+            text: value,
+        })
+    }
+}
+
+impl Display for DeclaredVersionRequirement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+/// Generates the caret requirements, e.g. "1.2.3".
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DeclaredVersionRequirement {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let version: EffectiveVersion = u.arbitrary()?;
+        Ok(Self {
+            text: version.to_string(),
+            requirement: VersionRequirement::Caret(version),
+        })
+    }
+}
+
+impl Display for VersionRequirement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VersionRequirement::Exact(version) => write!(f, "={}", version),
+            VersionRequirement::Caret(version) => write!(f, "^{}", version),
+            VersionRequirement::Tilde(version) => write!(f, "~{}", version),
+            VersionRequirement::Range(comparators) => {
+                let items = comparators
+                    .iter()
+                    .map(|comparator| comparator.to_string())
+                    .collect::<Vec<_>>();
+                f.write_str(&items.join(", "))
+            }
+        }
+    }
+}
+
+impl Display for VersionComparator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let operator = match self.operator {
+            ComparisonOperator::Greater => ">",
+            ComparisonOperator::GreaterOrEqual => ">=",
+            ComparisonOperator::Less => "<",
+            ComparisonOperator::LessOrEqual => "<=",
+            ComparisonOperator::Equal => "=",
+        };
+        write!(f, "{}{}", operator, self.version)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use std::collections::HashMap;
+
+    use crate::{
+        version::{
+            select_version, DeclaredVersionRequirement, FullVersion, VersionConflict,
+            VersionRequirement,
+        },
+        DependencyCondition, DependencyKind, DependencyShare, EffectiveVersion,
+        VersionCompatibility, VersionParseError,
+    };
+
+    fn v(text: &str) -> FullVersion {
+        FullVersion::from_version_string(text).unwrap()
+    }
+
+    #[test]
+    fn test_parse_full_version() {
+        let version = v("1.0.0-beta.1+build.5");
+        assert_eq!(version.version, EffectiveVersion::new(1, 0, 0));
+        assert_eq!(version.pre_release, vec!["beta", "1"]);
+        assert_eq!(version.build, vec!["build", "5"]);
+        assert_eq!(version.to_string(), "1.0.0-beta.1+build.5");
+
+        assert_eq!(v("1.2.3+x-y").pre_release, Vec::<String>::new());
+        assert_eq!(v("1.2.3-rc-1").pre_release, vec!["rc-1"]);
+
+        assert!(matches!(
+            FullVersion::from_version_string("1.0.0-beta..1"),
+            Err(VersionParseError::InvalidPreRelease { .. })
+        ));
+        assert!(matches!(
+            FullVersion::from_version_string("1.0.0-01"),
+            Err(VersionParseError::InvalidPreRelease { .. })
+        ));
+        assert!(matches!(
+            FullVersion::from_version_string("1.0.0+"),
+            Err(VersionParseError::InvalidBuildMetadata { .. })
+        ));
+        assert!(matches!(
+            FullVersion::from_version_string("1.0-beta"),
+            Err(VersionParseError::WrongArity { .. })
+        ));
+    }
+
+    #[test]
+    fn test_full_version_precedence() {
+        // the example from https://semver.org/#spec-item-11
+        let versions = [
+            "1.0.0-alpha",
+            "1.0.0-alpha.1",
+            "1.0.0-alpha.beta",
+            "1.0.0-beta",
+            "1.0.0-beta.2",
+            "1.0.0-beta.11",
+            "1.0.0-rc.1",
+            "1.0.0",
+        ];
+
+        for pair in versions.windows(2) {
+            assert!(v(pair[0]) < v(pair[1]), "{} < {}", pair[0], pair[1]);
+        }
+
+        assert_eq!(
+            v("1.0.0+a").cmp_precedence(&v("1.0.0+b")),
+            std::cmp::Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn test_full_version_compatibility() {
+        assert_eq!(
+            v("1.0.0-beta.1").compatible(&v("1.0.0")),
+            VersionCompatibility::LessThan
+        );
+        assert_eq!(
+            v("1.1.0-beta.1").compatible(&v("1.0.0")),
+            VersionCompatibility::GreaterThan
+        );
+        assert_eq!(
+            v("1.0.0+a").compatible(&v("1.0.0+b")),
+            VersionCompatibility::Equals
+        );
+        assert_eq!(
+            v("2.0.0-rc.1").compatible(&v("1.0.0")),
+            VersionCompatibility::Conflict
+        );
+    }
+
+    fn r(text: &str) -> VersionRequirement {
+        VersionRequirement::from_requirement_string(text).unwrap()
+    }
+
+    fn e(text: &str) -> EffectiveVersion {
+        EffectiveVersion::from_version_string(text).unwrap()
+    }
+
+    #[test]
+    fn test_version_requirement() {
+        // exact
+        assert!(r("=1.2.3").matches(&e("1.2.3")));
+        assert!(!r("=1.2.3").matches(&e("1.2.4")));
+
+        // caret
+        assert!(r("^1.4.0").matches(&e("1.4.0")));
+        assert!(r("1.4").matches(&e("1.99.99")));
+        assert!(!r("1.4").matches(&e("1.3.9")));
+        assert!(!r("1.4").matches(&e("2.0.0")));
+        assert!(r("^0.2.3").matches(&e("0.2.9")));
+        assert!(!r("^0.2.3").matches(&e("0.3.0")));
+
+        // tilde
+        assert!(r("~1.2.3").matches(&e("1.2.9")));
+        assert!(!r("~1.2.3").matches(&e("1.3.0")));
+
+        // range
+        let range = r(">=1.2.0, <1.5");
+        assert!(range.matches(&e("1.2.0")));
+        assert!(range.matches(&e("1.4.99")));
+        assert!(!range.matches(&e("1.5.0")));
+        assert_eq!(range.to_string(), ">=1.2.0, <1.5.0");
+
+        assert_eq!(r("1.4").to_string(), "^1.4.0");
+        assert!(matches!(
+            VersionRequirement::from_requirement_string("*"),
+            Err(VersionParseError::InvalidRequirement { .. })
+        ));
+        assert!(VersionRequirement::from_requirement_string(">=1.x").is_err());
+
+        let dependency = DependencyShare {
+            version: "2.3".parse().unwrap(),
+            checksum: None,
+            parameters: HashMap::new(),
+            condition: DependencyCondition::True,
+            kind: DependencyKind::Normal,
+            optional: false,
+        };
+        assert_eq!(dependency.get_version_requirement(), &r("^2.3.0"));
+        assert_eq!(dependency.version.to_string(), "2.3");
+        assert_eq!(
+            "2.x".parse::<DeclaredVersionRequirement>(),
+            Err(VersionParseError::Malformed {
+                version: "2.x".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn test_select_version() {
+        let available = [e("1.2.0"), e("1.4.2"), e("1.6.0"), e("2.0.0"), e("0.3.1")];
+
+        // same major, the highest minor is selected
+        assert_eq!(
+            select_version(&[r("1.2"), r("1.4")], &available),
+            Ok(e("1.6.0"))
+        );
+        assert_eq!(
+            select_version(&[r("1.2"), r("~1.4.0")], &available),
+            Ok(e("1.4.2"))
+        );
+
+        // differing majors
+        assert_eq!(
+            select_version(&[r("1.2"), r("2.0")], &available),
+            Err(VersionConflict::IncompatibleRequirements {
+                first: r("1.2"),
+                second: r("2.0")
+            })
+        );
+
+        // zero major, differing minors
+        assert!(matches!(
+            select_version(&[r("0.3"), r("0.2")], &available),
+            Err(VersionConflict::IncompatibleRequirements { .. })
+        ));
+
+        // no matching version
+        assert_eq!(
+            select_version(&[r("1.7")], &available),
+            Err(VersionConflict::NoMatchingVersion {
+                requirements: vec![r("1.7")]
+            })
+        );
+    }
+}
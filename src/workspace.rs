@@ -0,0 +1,216 @@
+// Copyright (c) 2025 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions.
+// For more details, see the LICENSE, LICENSE.additional, and CONTRIBUTING files.
+
+// Workspace
+// ---------
+//
+// A workspace is a multi-module project (e.g., a monorepo), the members share
+// a dependency set, the overrides and the output directory, e.g.:
+//
+// ```ason
+// workspace {
+//     members: [
+//         { name: "app", path: "app" }
+//         { name: "utils", path: "libs/utils" }
+//     ]
+//     dependencies: [
+//         "sha2": module::share({
+//             version: "1.2"
+//         })
+//     ]
+//     overrides: []
+//     output_dir: Option::Some("build")
+// }
+// ```
+//
+// - The member paths are relative to the workspace folder, see the module `path`.
+// - The shared dependencies are added to the dependencies of every member,
+//   a dependency declared by a member takes precedence over the shared one
+//   with the same name.
+// - The overrides of the workspace apply to all members, and the overrides
+//   of members are ignored, see `DependencyOverrides`.
+// - The outputs of all members are written to the same output directory, so
+//   the shared dependencies are built only once.
+
+use std::{collections::HashMap, fmt::Display};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{overrides::DependencyOverrides, path::normalize_path, ModuleDependency};
+
+/// The default output directory, relative to the workspace folder.
+pub const DEFAULT_WORKSPACE_OUTPUT_DIR: &str = "output";
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename = "member")]
+pub struct WorkspaceMember {
+    /// The module name of the member.
+    pub name: String,
+
+    /// The path of the member project folder, relative to the workspace folder.
+    pub path: String,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename = "workspace")]
+pub struct Workspace {
+    pub members: Vec<WorkspaceMember>,
+
+    /// Optional.
+    /// The default value is [].
+    #[serde(default)]
+    pub dependencies: HashMap<String, ModuleDependency>,
+
+    /// Optional.
+    /// The default value is [].
+    #[serde(default)]
+    pub overrides: DependencyOverrides,
+
+    /// Optional.
+    /// The output directory relative to the workspace folder,
+    /// the default value is `DEFAULT_WORKSPACE_OUTPUT_DIR`.
+    pub output_dir: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum WorkspaceError {
+    DuplicateMemberName {
+        name: String,
+    },
+
+    /// Two members have the same normalized path.
+    DuplicateMemberPath {
+        path: String,
+    },
+}
+
+impl Display for WorkspaceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WorkspaceError::DuplicateMemberName { name } => {
+                write!(f, "Duplicate workspace member name \"{}\".", name)
+            }
+            WorkspaceError::DuplicateMemberPath { path } => {
+                write!(f, "Duplicate workspace member path \"{}\".", path)
+            }
+        }
+    }
+}
+
+impl std::error::Error for WorkspaceError {}
+
+impl Workspace {
+    pub fn validate(&self) -> Result<(), WorkspaceError> {
+        for (index, member) in self.members.iter().enumerate() {
+            let previous = &self.members[..index];
+
+            if previous.iter().any(|item| item.name == member.name) {
+                return Err(WorkspaceError::DuplicateMemberName {
+                    name: member.name.to_owned(),
+                });
+            }
+
+            let path = normalize_path(&member.path);
+            if previous
+                .iter()
+                .any(|item| normalize_path(&item.path) == path)
+            {
+                return Err(WorkspaceError::DuplicateMemberPath { path });
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn get_member(&self, name: &str) -> Option<&WorkspaceMember> {
+        self.members.iter().find(|item| item.name == name)
+    }
+
+    pub fn get_output_dir(&self) -> &str {
+        self.output_dir
+            .as_deref()
+            .unwrap_or(DEFAULT_WORKSPACE_OUTPUT_DIR)
+    }
+
+    /// Returns the dependencies of the member, i.e., the shared dependencies
+    /// merged with the dependencies declared by the member.
+    pub fn get_member_dependencies(
+        &self,
+        member_dependencies: &HashMap<String, ModuleDependency>,
+    ) -> HashMap<String, ModuleDependency> {
+        let mut dependencies = self.dependencies.clone();
+        dependencies.extend(
+            member_dependencies
+                .iter()
+                .map(|(name, dependency)| (name.to_owned(), dependency.clone())),
+        );
+        dependencies
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        overrides::DependencyOverrides,
+        workspace::{Workspace, WorkspaceError, WorkspaceMember, DEFAULT_WORKSPACE_OUTPUT_DIR},
+        ModuleDependency,
+    };
+
+    fn member(name: &str, path: &str) -> WorkspaceMember {
+        WorkspaceMember {
+            name: name.to_owned(),
+            path: path.to_owned(),
+        }
+    }
+
+    #[test]
+    fn test_workspace() {
+        let mut dependencies = HashMap::new();
+        dependencies.insert("sha2".to_owned(), ModuleDependency::Runtime);
+        dependencies.insert("md5".to_owned(), ModuleDependency::Runtime);
+
+        let mut workspace = Workspace {
+            members: vec![member("app", "app"), member("utils", "libs/utils")],
+            dependencies,
+            overrides: DependencyOverrides::default(),
+            output_dir: None,
+        };
+
+        assert_eq!(workspace.validate(), Ok(()));
+        assert_eq!(
+            workspace.get_member("utils"),
+            Some(&member("utils", "libs/utils"))
+        );
+        assert_eq!(workspace.get_output_dir(), DEFAULT_WORKSPACE_OUTPUT_DIR);
+
+        let mut member_dependencies = HashMap::new();
+        member_dependencies.insert("sha2".to_owned(), ModuleDependency::Current);
+        let merged = workspace.get_member_dependencies(&member_dependencies);
+        assert_eq!(merged["sha2"], ModuleDependency::Current);
+        assert_eq!(merged["md5"], ModuleDependency::Runtime);
+
+        workspace.members.push(member("tools", "./libs/utils/"));
+        assert_eq!(
+            workspace.validate(),
+            Err(WorkspaceError::DuplicateMemberPath {
+                path: "libs/utils".to_owned()
+            })
+        );
+
+        workspace.members.pop();
+        workspace.members.push(member("app", "tools"));
+        assert_eq!(
+            workspace.validate(),
+            Err(WorkspaceError::DuplicateMemberName {
+                name: "app".to_owned()
+            })
+        );
+    }
+}